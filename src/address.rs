@@ -0,0 +1,260 @@
+use std::fmt;
+use std::str::FromStr;
+
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+const ACCOUNT_ID_PREFIX: u8 = 0x00;
+const X_ADDRESS_MAINNET_PREFIX: [u8; 2] = [0x05, 0x44];
+const X_ADDRESS_TESTNET_PREFIX: [u8; 2] = [0x04, 0x93];
+
+/// Errors returned when decoding a classic address or X-address.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum AddressError {
+    /// The text contains characters outside the Ripple base58 alphabet.
+    #[error("Not valid base58")]
+    InvalidBase58,
+    /// The trailing four-byte checksum does not match the payload.
+    #[error("Checksum mismatch")]
+    InvalidChecksum,
+    /// The payload decoded, but its length or prefix is not an address.
+    #[error("Not a {0}")]
+    InvalidFormat(&'static str),
+}
+
+/// The 20-byte account identifier behind a classic address.
+///
+/// Parses from and displays as the classic `r…` form.
+///
+/// # Example
+/// ```rust
+/// use xrpl::address::AccountId;
+///
+/// let id: AccountId = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".parse()?;
+/// assert_eq!(hex::encode_upper(id.as_bytes()), "B5F762798A53D543A014CAF8B297CFF8F2F937E8");
+/// assert_eq!(id.to_string(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+/// # Ok::<(), xrpl::address::AddressError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountId(pub [u8; 20]);
+
+impl AccountId {
+    /// Derives the account of a public key: RIPEMD-160 of SHA-256 of the key
+    /// bytes (33-byte compressed secp256k1, or `ED` + 32-byte Ed25519).
+    pub fn from_public_key(public_key: &[u8]) -> Self {
+        Self(Ripemd160::digest(Sha256::digest(public_key)).into())
+    }
+
+    /// The raw 20 bytes.
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for AccountId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = AddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        match base58check_decode(address)?.as_slice() {
+            [ACCOUNT_ID_PREFIX, id @ ..] => id
+                .try_into()
+                .map(Self)
+                .map_err(|_| AddressError::InvalidFormat("classic address")),
+            _ => Err(AddressError::InvalidFormat("classic address")),
+        }
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let payload = [&[ACCOUNT_ID_PREFIX][..], &self.0].concat();
+        f.write_str(&base58check_encode(&payload))
+    }
+}
+
+/// A decoded X-address: a classic address, an optional destination or
+/// source tag, and the network it is meant for.
+///
+/// # Example
+/// ```rust
+/// use xrpl::address::XAddress;
+///
+/// let x = XAddress::new("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf", Some(1), false)?;
+/// let encoded = x.to_string();
+/// assert!(encoded.starts_with('X'));
+/// assert_eq!(encoded.parse::<XAddress>()?, x);
+/// # Ok::<(), xrpl::address::AddressError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XAddress {
+    /// Account the X-address points to.
+    pub account_id: AccountId,
+    /// Tag carried by the X-address, if any.
+    pub tag: Option<u32>,
+    /// `true` for a test-network (`T…`) address, `false` for mainnet (`X…`).
+    pub test_network: bool,
+}
+
+impl XAddress {
+    /// Combines a classic address with a tag and network flag.
+    pub fn new(
+        classic_address: &str,
+        tag: Option<u32>,
+        test_network: bool,
+    ) -> Result<Self, AddressError> {
+        Ok(Self { account_id: classic_address.parse()?, tag, test_network })
+    }
+
+    /// Classic `r…` form of the account.
+    pub fn classic_address(&self) -> String {
+        self.account_id.to_string()
+    }
+}
+
+impl FromStr for XAddress {
+    type Err = AddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let invalid = || AddressError::InvalidFormat("X-address");
+        let payload = base58check_decode(address)?;
+        let payload: [u8; 31] = payload.try_into().map_err(|_| invalid())?;
+        let test_network = match [payload[0], payload[1]] {
+            X_ADDRESS_MAINNET_PREFIX => false,
+            X_ADDRESS_TESTNET_PREFIX => true,
+            _ => return Err(invalid()),
+        };
+        let tag_bytes: [u8; 8] =
+            payload[23..].try_into().map_err(|_| invalid())?;
+        let tag = u64::from_le_bytes(tag_bytes);
+        // 64-bit tags are reserved; only the low 32 bits may be used.
+        let tag = match payload[22] {
+            0 if tag == 0 => None,
+            1 => Some(u32::try_from(tag).map_err(|_| invalid())?),
+            _ => return Err(invalid()),
+        };
+        let account_id =
+            AccountId(payload[2..22].try_into().map_err(|_| invalid())?);
+        Ok(Self { account_id, tag, test_network })
+    }
+}
+
+impl fmt::Display for XAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if self.test_network {
+            X_ADDRESS_TESTNET_PREFIX
+        } else {
+            X_ADDRESS_MAINNET_PREFIX
+        };
+        let mut payload = Vec::with_capacity(31);
+        payload.extend_from_slice(&prefix);
+        payload.extend_from_slice(&self.account_id.0);
+        payload.push(u8::from(self.tag.is_some()));
+        payload
+            .extend_from_slice(&u64::from(self.tag.unwrap_or(0)).to_le_bytes());
+        f.write_str(&base58check_encode(&payload))
+    }
+}
+
+/// Encodes a classic address and tag as an X-address.
+pub fn classic_to_x_address(
+    classic_address: &str,
+    tag: Option<u32>,
+    test_network: bool,
+) -> Result<String, AddressError> {
+    Ok(XAddress::new(classic_address, tag, test_network)?.to_string())
+}
+
+/// Splits an X-address into its classic address, tag, and test-network flag.
+pub fn x_address_to_classic(
+    x_address: &str,
+) -> Result<(String, Option<u32>, bool), AddressError> {
+    let x: XAddress = x_address.parse()?;
+    Ok((x.classic_address(), x.tag, x.test_network))
+}
+
+/// Whether `address` is a classic address with a valid checksum.
+pub fn is_valid_classic_address(address: &str) -> bool {
+    address.parse::<AccountId>().is_ok()
+}
+
+/// Whether `address` is an X-address with a valid checksum.
+pub fn is_valid_x_address(address: &str) -> bool {
+    address.parse::<XAddress>().is_ok()
+}
+
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let digest = Sha256::digest(Sha256::digest(payload));
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// Encodes `payload` (version prefix included) with a four-byte checksum.
+pub(crate) fn base58check_encode(payload: &[u8]) -> String {
+    let mut bytes = payload.to_vec();
+    bytes.extend_from_slice(&checksum(payload));
+    bs58::encode(bytes).with_alphabet(bs58::Alphabet::RIPPLE).into_string()
+}
+
+/// Decodes base58check text, returning the payload if the checksum matches.
+pub(crate) fn base58check_decode(text: &str) -> Result<Vec<u8>, AddressError> {
+    let mut bytes = bs58::decode(text)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .into_vec()
+        .map_err(|_| AddressError::InvalidBase58)?;
+    if bytes.len() < 5 {
+        return Err(AddressError::InvalidChecksum);
+    }
+    let check = bytes.split_off(bytes.len() - 4);
+    if checksum(&bytes) != check[..] {
+        return Err(AddressError::InvalidChecksum);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLASSIC: &str = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+
+    #[test]
+    fn test_x_address_vectors() {
+        let cases = [
+            (None, false, "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"),
+            (Some(1), false, "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"),
+            (None, true, "TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE"),
+            (Some(1), true, "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"),
+        ];
+        for (tag, test_network, expected) in cases {
+            let x = classic_to_x_address(CLASSIC, tag, test_network).unwrap();
+            assert_eq!(x, expected);
+            assert_eq!(
+                x_address_to_classic(&x).unwrap(),
+                (CLASSIC.to_string(), tag, test_network)
+            );
+        }
+    }
+
+    #[test]
+    fn test_checksum_is_verified() {
+        assert!(is_valid_classic_address(CLASSIC));
+        assert_eq!(
+            "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpg".parse::<AccountId>(),
+            Err(AddressError::InvalidChecksum)
+        );
+        assert_eq!(
+            "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYp0".parse::<AccountId>(),
+            Err(AddressError::InvalidBase58)
+        );
+        assert!(!is_valid_x_address(CLASSIC));
+        assert!(!is_valid_classic_address(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"
+        ));
+    }
+}
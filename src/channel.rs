@@ -0,0 +1,362 @@
+#[cfg(feature = "wallet")]
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::XrplError;
+use crate::types::codec::HASH_PREFIX_PAYMENT_CHANNEL_CLAIM;
+#[cfg(feature = "wallet")]
+use crate::verify::{VerifyError, verify_signature};
+#[cfg(feature = "wallet")]
+use crate::wallet::{Wallet, WalletError};
+#[cfg(feature = "wallet")]
+use crate::{
+    Client,
+    request::ledger_entry::LedgerEntryRequest,
+    types::{AccountObject, PayChannel},
+};
+
+/// Errors returned when building, signing, or accepting a channel claim.
+#[derive(Error, Debug, Clone)]
+pub enum ClaimError {
+    /// The channel ID is not 64 hex characters.
+    #[error("Invalid channel ID: {0}")]
+    InvalidChannelId(String),
+    /// The `ChannelReceiver` does not track this channel.
+    #[error("Unknown channel: {0}")]
+    UnknownChannel(String),
+    /// The channel's `Amount` or `Balance` is not in XRP drops.
+    #[error("Channel amounts must be XRP drops")]
+    NotXrp,
+    /// The claim is for more than the channel holds.
+    #[error("Claim of {amount} drops exceeds the channel's {capacity}")]
+    ExceedsChannel {
+        /// Claimed drops.
+        amount: u64,
+        /// Drops allocated to the channel.
+        capacity: u64,
+    },
+    /// The claim is not above the best one already held.
+    #[error("Claim of {amount} drops does not exceed {best}")]
+    NotIncreasing {
+        /// Claimed drops.
+        amount: u64,
+        /// Best claim (or redeemed balance) so far, in drops.
+        best: u64,
+    },
+    /// The signature does not verify against the channel's public key.
+    #[cfg(feature = "wallet")]
+    #[error(transparent)]
+    Signature(#[from] VerifyError),
+    /// The wallet could not sign the claim.
+    #[cfg(feature = "wallet")]
+    #[error(transparent)]
+    Signing(#[from] WalletError),
+    /// The channel could not be read from the ledger.
+    #[error(transparent)]
+    Xrpl(#[from] XrplError),
+}
+
+/// A signed off-ledger claim: the channel's source authorizes the
+/// destination to receive up to `amount` drops in total.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Claim {
+    /// 64-hex-character channel ID, upper case.
+    pub channel_id: String,
+    /// Cumulative amount authorized, in drops.
+    pub amount: u64,
+    /// Hex signature of [`claim_data`] by the channel's key pair.
+    pub signature: String,
+}
+
+/// Bytes a channel claim signs: `CLM\0`, the 32-byte channel ID, then the
+/// amount in drops as a big-endian `u64`.
+///
+/// # Example
+/// ```rust
+/// use xrpl::channel::claim_data;
+///
+/// let id = "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3";
+/// let data = claim_data(id, 1_000_000)?;
+/// assert_eq!(&data[..4], b"CLM\0");
+/// assert_eq!(data.len(), 4 + 32 + 8);
+/// # Ok::<(), xrpl::channel::ClaimError>(())
+/// ```
+pub fn claim_data(channel_id: &str, drops: u64) -> Result<Vec<u8>, ClaimError> {
+    let id: [u8; 32] = hex::decode(channel_id)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ClaimError::InvalidChannelId(channel_id.to_string()))?;
+    let mut data = HASH_PREFIX_PAYMENT_CHANNEL_CLAIM.to_vec();
+    data.extend_from_slice(&id);
+    data.extend_from_slice(&drops.to_be_bytes());
+    Ok(data)
+}
+
+/// Signs a claim for `drops` on `channel_id` with the channel's key pair.
+///
+/// Requires the `wallet` cargo feature.
+///
+/// # Example
+/// ```rust
+/// use xrpl::channel::{sign_claim, verify_claim};
+/// use xrpl::wallet::Wallet;
+///
+/// let wallet = Wallet::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r")?;
+/// let id = "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3";
+/// let claim = sign_claim(&wallet, id, 1_000_000)?;
+/// verify_claim(wallet.public_key(), &claim)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "wallet")]
+pub fn sign_claim(
+    wallet: &Wallet,
+    channel_id: &str,
+    drops: u64,
+) -> Result<Claim, ClaimError> {
+    let signature = wallet.sign(&claim_data(channel_id, drops)?)?;
+    Ok(Claim {
+        channel_id: channel_id.to_ascii_uppercase(),
+        amount: drops,
+        signature,
+    })
+}
+
+/// Checks `claim` against the channel's hex `public_key`, as recorded in
+/// the `PayChannel` object.
+///
+/// Requires the `wallet` cargo feature.
+#[cfg(feature = "wallet")]
+pub fn verify_claim(public_key: &str, claim: &Claim) -> Result<(), ClaimError> {
+    let data = claim_data(&claim.channel_id, claim.amount)?;
+    Ok(verify_signature(public_key, &data, &claim.signature)?)
+}
+
+/// Keeps the highest valid claim for each payment channel it receives
+/// claims for.
+///
+/// Register channels with [`track`](Self::track) or [`load`](Self::load),
+/// then pass every incoming claim to [`receive`](Self::receive). A claim is
+/// kept only if its signature verifies against the channel's public key,
+/// it does not exceed the channel's funds, and it is above the best claim
+/// so far; the best claim is the one to redeem with
+/// `PaymentChannelClaim`.
+///
+/// Requires the `wallet` cargo feature.
+///
+/// # Example
+/// ```rust,no_run
+/// use xrpl::{Client, channel::{ChannelReceiver, Claim}};
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let incoming: Vec<Claim> = Vec::new();
+/// let client = Client::new("wss://xrplcluster.com");
+/// let channel = "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3";
+/// let mut receiver = ChannelReceiver::new();
+/// receiver.load(&client, channel).await?;
+///
+/// for claim in incoming {
+///     match receiver.receive(claim) {
+///         Ok(increment) => println!("paid {increment} more drops"),
+///         Err(e) => eprintln!("rejected claim: {e}"),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "wallet")]
+#[derive(Debug, Default)]
+pub struct ChannelReceiver {
+    channels: HashMap<String, ChannelState>,
+}
+
+#[cfg(feature = "wallet")]
+#[derive(Debug)]
+struct ChannelState {
+    public_key: String,
+    capacity: u64,
+    redeemed: u64,
+    best: Option<Claim>,
+}
+
+#[cfg(feature = "wallet")]
+impl ChannelState {
+    /// Drops already owed: the best claim, or the ledger balance.
+    fn floor(&self) -> u64 {
+        self.best
+            .as_ref()
+            .map_or(self.redeemed, |c| c.amount.max(self.redeemed))
+    }
+}
+
+#[cfg(feature = "wallet")]
+impl ChannelReceiver {
+    /// Creates a receiver that tracks no channels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts tracking `channel_id` with the key, funds, and balance of its
+    /// `PayChannel` object. Re-tracking a channel updates its funds and
+    /// balance (e.g. after `PaymentChannelFund`) and keeps the best claim.
+    pub fn track(
+        &mut self,
+        channel_id: &str,
+        channel: &PayChannel,
+    ) -> Result<(), ClaimError> {
+        claim_data(channel_id, 0)?;
+        let capacity = channel.amount.to_drops().ok_or(ClaimError::NotXrp)?;
+        let redeemed = channel.balance.to_drops().ok_or(ClaimError::NotXrp)?;
+        let state = self
+            .channels
+            .entry(channel_id.to_ascii_uppercase())
+            .or_insert_with(|| ChannelState {
+                public_key: String::new(),
+                capacity,
+                redeemed,
+                best: None,
+            });
+        state.public_key = channel.public_key.clone();
+        state.capacity = capacity;
+        state.redeemed = redeemed;
+        Ok(())
+    }
+
+    /// Reads `channel_id` from the validated ledger and tracks it.
+    pub async fn load(
+        &mut self,
+        client: &Client,
+        channel_id: &str,
+    ) -> Result<(), ClaimError> {
+        let req = LedgerEntryRequest::for_payment_channel(channel_id)
+            .with_ledger_index("validated");
+        let node = client.request(&req).await?.result()?.node;
+        let Some(AccountObject::PayChannel(channel)) = node else {
+            return Err(XrplError::ParseError(
+                "ledger_entry returned no PayChannel".to_string(),
+            )
+            .into());
+        };
+        self.track(channel_id, &channel)
+    }
+
+    /// Verifies `claim` and keeps it if it beats the best claim so far.
+    /// Returns how many drops it adds over that claim (or over the redeemed
+    /// balance, for the first claim).
+    pub fn receive(&mut self, claim: Claim) -> Result<u64, ClaimError> {
+        let id = claim.channel_id.to_ascii_uppercase();
+        let state = self.channels.get_mut(&id).ok_or_else(|| {
+            ClaimError::UnknownChannel(claim.channel_id.clone())
+        })?;
+        if claim.amount > state.capacity {
+            return Err(ClaimError::ExceedsChannel {
+                amount: claim.amount,
+                capacity: state.capacity,
+            });
+        }
+        let best = state.floor();
+        if claim.amount <= best {
+            return Err(ClaimError::NotIncreasing {
+                amount: claim.amount,
+                best,
+            });
+        }
+        verify_claim(&state.public_key, &claim)?;
+
+        state.best = Some(Claim { channel_id: id, ..claim });
+        Ok(state.best.as_ref().map_or(0, |c| c.amount) - best)
+    }
+
+    /// The highest valid claim received for `channel_id`.
+    pub fn best_claim(&self, channel_id: &str) -> Option<&Claim> {
+        self.channels.get(&channel_id.to_ascii_uppercase())?.best.as_ref()
+    }
+
+    /// Drops the best claim would add over the channel's redeemed balance.
+    pub fn unredeemed(&self, channel_id: &str) -> u64 {
+        self.channels
+            .get(&channel_id.to_ascii_uppercase())
+            .map_or(0, |state| state.floor() - state.redeemed)
+    }
+}
+
+#[cfg(all(test, feature = "wallet"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const SEED: &str = "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r";
+    const CHANNEL: &str =
+        "5db01b7ffed6b67e6b0414ded11e051d2ee2b7619ce0eaa6286d67a3a4d5bdb3";
+
+    fn pay_channel(public_key: &str) -> PayChannel {
+        serde_json::from_value(json!({
+            "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Amount": "1000",
+            "Balance": "100",
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "PublicKey": public_key,
+            "SettleDelay": 60,
+            "Flags": 0,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_claim_data_layout() {
+        let data = claim_data(CHANNEL, 0x0102).unwrap();
+        assert_eq!(&data[..4], b"CLM\0");
+        assert_eq!(hex::encode(&data[4..36]), CHANNEL);
+        assert_eq!(data[36..], [0, 0, 0, 0, 0, 0, 1, 2]);
+        assert!(matches!(
+            claim_data("ABCD", 1),
+            Err(ClaimError::InvalidChannelId(_))
+        ));
+    }
+
+    #[test]
+    fn test_sign_and_verify_claim() {
+        let wallet = Wallet::from_seed(SEED).unwrap();
+        let claim = sign_claim(&wallet, CHANNEL, 500).unwrap();
+        verify_claim(wallet.public_key(), &claim).unwrap();
+
+        let forged = Claim { amount: 900, ..claim };
+        assert!(matches!(
+            verify_claim(wallet.public_key(), &forged),
+            Err(ClaimError::Signature(VerifyError::BadSignature))
+        ));
+    }
+
+    #[test]
+    fn test_receiver_keeps_highest_valid_claim() {
+        let wallet = Wallet::from_seed(SEED).unwrap();
+        let mut receiver = ChannelReceiver::new();
+        receiver.track(CHANNEL, &pay_channel(wallet.public_key())).unwrap();
+        let claim = |drops| sign_claim(&wallet, CHANNEL, drops).unwrap();
+
+        assert!(matches!(
+            receiver.receive(claim(100)),
+            Err(ClaimError::NotIncreasing { amount: 100, best: 100 })
+        ));
+        assert_eq!(receiver.receive(claim(300)).unwrap(), 200);
+        assert_eq!(receiver.receive(claim(450)).unwrap(), 150);
+        assert!(matches!(
+            receiver.receive(claim(400)),
+            Err(ClaimError::NotIncreasing { best: 450, .. })
+        ));
+        assert!(matches!(
+            receiver.receive(claim(1001)),
+            Err(ClaimError::ExceedsChannel { capacity: 1000, .. })
+        ));
+        let forged = Claim { amount: 900, ..claim(500) };
+        assert!(matches!(
+            receiver.receive(forged),
+            Err(ClaimError::Signature(_))
+        ));
+
+        assert_eq!(receiver.best_claim(CHANNEL).unwrap().amount, 450);
+        assert_eq!(receiver.unredeemed(CHANNEL), 350);
+    }
+}
@@ -0,0 +1,362 @@
+use std::fmt;
+use std::str::FromStr;
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Longest preimage rippled accepts in a PREIMAGE-SHA-256 fulfillment.
+pub const MAX_PREIMAGE_LEN: usize = 128;
+
+/// DER tag of a PREIMAGE-SHA-256 condition or fulfillment (`[0]`, constructed).
+const TAG_PREIMAGE_SHA256: u8 = 0xA0;
+/// DER tag of the preimage, or of the fingerprint in a condition.
+const TAG_FIRST_FIELD: u8 = 0x80;
+/// DER tag of the cost in a condition.
+const TAG_COST: u8 = 0x81;
+
+/// Errors returned when creating or decoding crypto-conditions.
+#[derive(Error, Debug)]
+pub enum ConditionError {
+    /// The preimage is longer than [`MAX_PREIMAGE_LEN`].
+    #[error("Preimage of {0} bytes exceeds {MAX_PREIMAGE_LEN} bytes")]
+    PreimageTooLong(usize),
+    /// The encoding is not a PREIMAGE-SHA-256 condition or fulfillment.
+    #[error("Malformed crypto-condition: {0}")]
+    Malformed(&'static str),
+    /// The value is not valid hex.
+    #[error("Invalid hex: {0}")]
+    Hex(#[from] hex::FromHexError),
+    /// The operating system could not provide random bytes.
+    #[error("Failed to generate preimage: {0}")]
+    Entropy(#[from] getrandom::Error),
+}
+
+/// A PREIMAGE-SHA-256 fulfillment, which releases an escrow locked with the
+/// matching [`Condition`].
+///
+/// Keep it secret until the escrow should be finished: anyone holding it
+/// can submit the `EscrowFinish`.
+///
+/// # Example
+/// ```rust
+/// use xrpl::conditions::Fulfillment;
+///
+/// let fulfillment = Fulfillment::random()?;
+/// let condition = fulfillment.condition();
+/// assert!(condition.verify(&fulfillment));
+///
+/// // Hex as used in the `Condition` and `Fulfillment` fields.
+/// println!("{condition}\n{fulfillment}");
+/// # Ok::<(), xrpl::conditions::ConditionError>(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Fulfillment {
+    preimage: Vec<u8>,
+}
+
+impl Fulfillment {
+    /// Creates a fulfillment from a caller-supplied preimage of at most
+    /// [`MAX_PREIMAGE_LEN`] bytes.
+    pub fn from_preimage(
+        preimage: impl Into<Vec<u8>>,
+    ) -> Result<Self, ConditionError> {
+        let preimage = preimage.into();
+        if preimage.len() > MAX_PREIMAGE_LEN {
+            return Err(ConditionError::PreimageTooLong(preimage.len()));
+        }
+        Ok(Self { preimage })
+    }
+
+    /// Creates a fulfillment from a random 32-byte preimage drawn from the
+    /// operating system.
+    pub fn random() -> Result<Self, ConditionError> {
+        let mut preimage = vec![0u8; 32];
+        getrandom::getrandom(&mut preimage)?;
+        Ok(Self { preimage })
+    }
+
+    /// The secret preimage.
+    pub fn preimage(&self) -> &[u8] {
+        &self.preimage
+    }
+
+    /// The condition this fulfillment satisfies.
+    pub fn condition(&self) -> Condition {
+        Condition {
+            fingerprint: Sha256::digest(&self.preimage).into(),
+            cost: self.preimage.len() as u64,
+        }
+    }
+
+    /// DER encoding, as it appears in an `EscrowFinish`.
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut inner = Vec::with_capacity(self.preimage.len() + 3);
+        push_tlv(&mut inner, TAG_FIRST_FIELD, &self.preimage);
+        let mut der = Vec::with_capacity(inner.len() + 3);
+        push_tlv(&mut der, TAG_PREIMAGE_SHA256, &inner);
+        der
+    }
+
+    /// Decodes a DER-encoded fulfillment.
+    pub fn from_der(der: &[u8]) -> Result<Self, ConditionError> {
+        let inner = read_outer(der)?;
+        let (preimage, rest) = read_tlv(inner, TAG_FIRST_FIELD)?;
+        if !rest.is_empty() {
+            return Err(ConditionError::Malformed("trailing fields"));
+        }
+        Self::from_preimage(preimage)
+    }
+
+    /// Reference fee units an `EscrowFinish` carrying this fulfillment
+    /// costs: 33 plus one per 16 bytes of the encoded fulfillment.
+    ///
+    /// [`TransactionBuilder::fill`] charges this automatically.
+    ///
+    /// [`TransactionBuilder::fill`]: crate::types::builders::TransactionBuilder::fill
+    pub fn fee_units(&self) -> u64 {
+        escrow_finish_fee_units(self.to_der().len())
+    }
+
+    /// Fee in drops for an `EscrowFinish` carrying this fulfillment, given
+    /// the reference transaction cost (10 drops on mainnet, giving 330
+    /// drops plus 10 per 16 bytes).
+    pub fn escrow_finish_fee(&self, base_fee: u64) -> u64 {
+        base_fee * self.fee_units()
+    }
+}
+
+impl fmt::Debug for Fulfillment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fulfillment")
+            .field("condition", &self.condition())
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for Fulfillment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode_upper(self.to_der()))
+    }
+}
+
+impl FromStr for Fulfillment {
+    type Err = ConditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_der(&hex::decode(s)?)
+    }
+}
+
+impl From<Fulfillment> for String {
+    fn from(fulfillment: Fulfillment) -> Self {
+        fulfillment.to_string()
+    }
+}
+
+impl From<&Fulfillment> for String {
+    fn from(fulfillment: &Fulfillment) -> Self {
+        fulfillment.to_string()
+    }
+}
+
+/// A PREIMAGE-SHA-256 condition: the SHA-256 fingerprint of a preimage and
+/// the preimage's length as cost. Safe to publish; it is what
+/// `EscrowCreate` locks funds with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    fingerprint: [u8; 32],
+    cost: u64,
+}
+
+impl Condition {
+    /// SHA-256 of the preimage.
+    pub fn fingerprint(&self) -> &[u8; 32] {
+        &self.fingerprint
+    }
+
+    /// Length of the preimage in bytes.
+    pub fn cost(&self) -> u64 {
+        self.cost
+    }
+
+    /// Whether `fulfillment` satisfies this condition.
+    pub fn verify(&self, fulfillment: &Fulfillment) -> bool {
+        *self == fulfillment.condition()
+    }
+
+    /// DER encoding, as it appears in an `EscrowCreate`.
+    pub fn to_der(&self) -> Vec<u8> {
+        // The cost is a DER INTEGER: minimal big-endian, with a leading zero
+        // if the top bit is set.
+        let bytes = self.cost.to_be_bytes();
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(7);
+        let mut cost = Vec::with_capacity(9);
+        if bytes[start] & 0x80 != 0 {
+            cost.push(0);
+        }
+        cost.extend_from_slice(&bytes[start..]);
+
+        let mut inner = Vec::with_capacity(45);
+        push_tlv(&mut inner, TAG_FIRST_FIELD, &self.fingerprint);
+        push_tlv(&mut inner, TAG_COST, &cost);
+        let mut der = Vec::with_capacity(inner.len() + 2);
+        push_tlv(&mut der, TAG_PREIMAGE_SHA256, &inner);
+        der
+    }
+
+    /// Decodes a DER-encoded condition.
+    pub fn from_der(der: &[u8]) -> Result<Self, ConditionError> {
+        let inner = read_outer(der)?;
+        let (fingerprint, rest) = read_tlv(inner, TAG_FIRST_FIELD)?;
+        let fingerprint = fingerprint.try_into().map_err(|_| {
+            ConditionError::Malformed("fingerprint is not 32 bytes")
+        })?;
+        let (cost, rest) = read_tlv(rest, TAG_COST)?;
+        if !rest.is_empty() {
+            return Err(ConditionError::Malformed("trailing fields"));
+        }
+        if cost.is_empty()
+            || cost.len() > 9
+            || (cost.len() == 9 && cost[0] != 0)
+        {
+            return Err(ConditionError::Malformed("cost out of range"));
+        }
+        let cost = cost.iter().fold(0u64, |acc, &b| acc << 8 | u64::from(b));
+        Ok(Self { fingerprint, cost })
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode_upper(self.to_der()))
+    }
+}
+
+impl FromStr for Condition {
+    type Err = ConditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_der(&hex::decode(s)?)
+    }
+}
+
+impl From<Condition> for String {
+    fn from(condition: Condition) -> Self {
+        condition.to_string()
+    }
+}
+
+impl From<&Condition> for String {
+    fn from(condition: &Condition) -> Self {
+        condition.to_string()
+    }
+}
+
+/// Reference fee units for an `EscrowFinish` whose fulfillment is
+/// `fulfillment_len` bytes long.
+pub(crate) fn escrow_finish_fee_units(fulfillment_len: usize) -> u64 {
+    33 + fulfillment_len as u64 / 16
+}
+
+fn push_tlv(out: &mut Vec<u8>, tag: u8, value: &[u8]) {
+    out.push(tag);
+    let len = value.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes = len.to_be_bytes();
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(0);
+        out.push(0x80 | (bytes.len() - start) as u8);
+        out.extend_from_slice(&bytes[start..]);
+    }
+    out.extend_from_slice(value);
+}
+
+/// Splits one TLV with `tag` off the front of `der`.
+fn read_tlv(der: &[u8], tag: u8) -> Result<(&[u8], &[u8]), ConditionError> {
+    let malformed = ConditionError::Malformed;
+    let (&found, rest) = der.split_first().ok_or(malformed("truncated"))?;
+    if found != tag {
+        return Err(malformed("unexpected tag"));
+    }
+    let (&first, mut rest) =
+        rest.split_first().ok_or(malformed("truncated"))?;
+    let len = if first < 0x80 {
+        usize::from(first)
+    } else {
+        let count = usize::from(first & 0x7F);
+        if count == 0 || count > 2 || rest.len() < count {
+            return Err(malformed("bad length"));
+        }
+        let (bytes, tail) = rest.split_at(count);
+        rest = tail;
+        bytes.iter().fold(0, |acc, &b| acc << 8 | usize::from(b))
+    };
+    if rest.len() < len {
+        return Err(malformed("truncated"));
+    }
+    Ok(rest.split_at(len))
+}
+
+fn read_outer(der: &[u8]) -> Result<&[u8], ConditionError> {
+    let (inner, rest) = read_tlv(der, TAG_PREIMAGE_SHA256)?;
+    if !rest.is_empty() {
+        return Err(ConditionError::Malformed("trailing bytes"));
+    }
+    Ok(inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Empty preimage, from the crypto-conditions specification.
+    const EMPTY_CONDITION: &str = "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100";
+    const EMPTY_FULFILLMENT: &str = "A0028000";
+
+    #[test]
+    fn test_encodes_known_vector() {
+        let fulfillment = Fulfillment::from_preimage([]).unwrap();
+        assert_eq!(fulfillment.to_string(), EMPTY_FULFILLMENT);
+        assert_eq!(fulfillment.condition().to_string(), EMPTY_CONDITION);
+
+        let condition: Condition = EMPTY_CONDITION.parse().unwrap();
+        let fulfillment: Fulfillment = EMPTY_FULFILLMENT.parse().unwrap();
+        assert!(condition.verify(&fulfillment));
+    }
+
+    #[test]
+    fn test_round_trips_and_verifies() {
+        for len in [32, 127, 128] {
+            let fulfillment = Fulfillment::from_preimage(vec![7; len]).unwrap();
+            let condition = fulfillment.condition();
+            assert_eq!(condition.cost(), len as u64);
+
+            let decoded: Fulfillment = fulfillment.to_string().parse().unwrap();
+            assert_eq!(decoded, fulfillment);
+            let decoded: Condition = condition.to_string().parse().unwrap();
+            assert_eq!(decoded, condition);
+        }
+        // A cost with the top bit set gets a leading zero.
+        let condition =
+            Fulfillment::from_preimage(vec![0; 128]).unwrap().condition();
+        assert!(condition.to_string().ends_with("81020080"));
+
+        let other = Fulfillment::from_preimage(*b"other").unwrap();
+        assert!(!Fulfillment::random().unwrap().condition().verify(&other));
+        assert!(matches!(
+            Fulfillment::from_preimage(vec![0; 129]),
+            Err(ConditionError::PreimageTooLong(129))
+        ));
+        assert!("A0028100".parse::<Fulfillment>().is_err());
+        assert!(format!("{EMPTY_CONDITION}00").parse::<Condition>().is_err());
+    }
+
+    #[test]
+    fn test_escrow_finish_fee() {
+        // 32-byte preimage: 36-byte fulfillment.
+        let fulfillment = Fulfillment::from_preimage([1; 32]).unwrap();
+        assert_eq!(fulfillment.to_der().len(), 36);
+        assert_eq!(fulfillment.escrow_finish_fee(10), 350);
+    }
+}
@@ -15,6 +15,12 @@ use std::time::Duration;
 /// On disconnect the client reconnects with exponential backoff: `initial_backoff`
 /// after the first failure, doubled each attempt up to `max_backoff`.
 ///
+/// ## Failover
+///
+/// `health_check_interval` - interval between `server_info` probes of every
+/// endpoint when the client was built with [`crate::Client::with_endpoints`]
+/// and more than one endpoint (default: 30 s).
+///
 /// ## Channel sizes
 ///
 /// `cmd_channel_size` - buffer depth for outgoing requests (default: 32).
@@ -50,6 +56,8 @@ pub struct ClientConfig {
     pub initial_backoff: Duration,
     /// Maximum backoff duration for reconnection attempts (default: 30 seconds)
     pub max_backoff: Duration,
+    /// Interval between endpoint health checks (default: 30 seconds)
+    pub health_check_interval: Duration,
 }
 
 impl Default for ClientConfig {
@@ -61,6 +69,7 @@ impl Default for ClientConfig {
             keepalive_interval: Duration::from_secs(20),
             initial_backoff: Duration::from_secs(1),
             max_backoff: Duration::from_secs(30),
+            health_check_interval: Duration::from_secs(30),
         }
     }
 }
@@ -130,4 +139,19 @@ impl ClientConfig {
         self.max_backoff = Duration::from_secs(backoff_secs);
         self
     }
+
+    /// Set the endpoint health check interval
+    pub fn with_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = interval;
+        self
+    }
+
+    /// Set the endpoint health check interval in seconds (convenience method)
+    pub fn with_health_check_interval_secs(
+        mut self,
+        interval_secs: u64,
+    ) -> Self {
+        self.health_check_interval = Duration::from_secs(interval_secs);
+        self
+    }
 }
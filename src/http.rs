@@ -0,0 +1,206 @@
+use std::time::Duration;
+
+use serde_json::{Value, json};
+
+use crate::config::ClientConfig;
+use crate::error::XrplError;
+use crate::request::XrplRequest;
+
+/// Client for the rippled JSON-RPC interface over HTTP(S).
+///
+/// Sends any [`XrplRequest`] as a single `POST` and parses the same
+/// [`XrplResponse`](crate::request::XrplResponse) envelope as [`crate::Client`],
+/// so every request type works unchanged. No connection is kept open between
+/// calls, which suits short-lived jobs. Subscriptions need a WebSocket and are
+/// not available here.
+///
+/// Requires the `http` cargo feature.
+///
+/// # Example
+/// ```no_run
+/// use xrpl::http::HttpClient;
+/// use xrpl::request::account_info::AccountInfoRequest;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = HttpClient::new("https://s1.ripple.com:51234")?;
+///     let req = AccountInfoRequest::new("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
+///     let info = client.request(&req).await?.result()?;
+///     println!("balance: {}", info.account_data.balance);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct HttpClient {
+    /// JSON-RPC endpoint URL.
+    pub url: String,
+    http: reqwest::Client,
+    request_timeout: Duration,
+}
+
+impl HttpClient {
+    /// Create a new HTTP client with the default configuration.
+    ///
+    /// Fails with [`XrplError::ConnectionError`] if the HTTP client cannot
+    /// be initialized (e.g. the TLS backend fails to load).
+    pub fn new(url: impl AsRef<str>) -> Result<Self, XrplError> {
+        Self::with_config(url, ClientConfig::default())
+    }
+
+    /// Create a new HTTP client with a custom configuration.
+    ///
+    /// Only [`ClientConfig::request_timeout`] applies to HTTP requests.
+    /// Fails with [`XrplError::ConnectionError`] if the HTTP client cannot
+    /// be initialized.
+    pub fn with_config(
+        url: impl AsRef<str>,
+        config: ClientConfig,
+    ) -> Result<Self, XrplError> {
+        let http = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .build()
+            .map_err(|e| XrplError::ConnectionError(e.to_string()))?;
+        Ok(Self {
+            url: url.as_ref().to_string(),
+            http,
+            request_timeout: config.request_timeout,
+        })
+    }
+
+    /// Send a request to the XRP Ledger and return the response.
+    pub async fn request<T: XrplRequest>(
+        &self,
+        req: &T,
+    ) -> Result<T::Response, XrplError> {
+        let body = rpc_body(req.to_value()?);
+
+        let response = self
+            .http
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_string())
+            .send()
+            .await
+            .map_err(|e| self.transport_error(e))?;
+
+        let status = response.status();
+        let text =
+            response.text().await.map_err(|e| self.transport_error(e))?;
+        let value: Value = serde_json::from_str(&text).map_err(|e| {
+            XrplError::ParseError(format!("HTTP {status}: {e} - Raw: {text}"))
+        })?;
+
+        serde_json::from_value(envelope(value)?)
+            .map_err(|e| XrplError::ParseError(e.to_string()))
+    }
+
+    fn transport_error(&self, e: reqwest::Error) -> XrplError {
+        if e.is_timeout() {
+            XrplError::Timeout(self.request_timeout.as_millis() as u64)
+        } else {
+            XrplError::ConnectionError(e.to_string())
+        }
+    }
+}
+
+/// Moves `command` out of a WebSocket-style request into the JSON-RPC `method`.
+fn rpc_body(mut request: Value) -> Value {
+    let method = request
+        .as_object_mut()
+        .and_then(|map| map.remove("command"))
+        .unwrap_or_default();
+    json!({ "method": method, "params": [request] })
+}
+
+/// Reshapes a JSON-RPC reply (`{"result": {..., "status": ...}}`) into the
+/// WebSocket envelope that [`crate::request::XrplResponse`] deserializes.
+fn envelope(mut value: Value) -> Result<Value, XrplError> {
+    let Some(result) = value.get_mut("result").map(Value::take) else {
+        return Err(XrplError::ParseError(format!(
+            "JSON-RPC response has no result: {value}"
+        )));
+    };
+
+    if result.get("error").is_some() {
+        let mut error = result;
+        error["type"] = "response".into();
+        error["status"] = "error".into();
+        return Ok(error);
+    }
+
+    Ok(json!({ "type": "response", "status": "success", "result": result }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::XrplResponse;
+    use crate::request::account_info::AccountInfoRequest;
+
+    /// The command becomes the JSON-RPC method; everything else, including
+    /// `api_version`, travels as the single params object.
+    #[test]
+    fn request_is_wrapped_as_json_rpc() {
+        let req = AccountInfoRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        let body = rpc_body(req.to_value().unwrap());
+
+        assert_eq!(body["method"], "account_info");
+        assert_eq!(body["params"][0]["account"], req.account);
+        assert_eq!(body["params"][0]["api_version"], 2);
+        assert!(body["params"][0].get("command").is_none());
+    }
+
+    /// A JSON-RPC error reply maps onto `XrplResponse::Error`, so `result()`
+    /// surfaces it as the same `ApiError` the WebSocket client returns.
+    #[test]
+    fn error_result_maps_to_api_error() {
+        let reply = json!({ "result": {
+            "error": "actNotFound",
+            "error_code": 19,
+            "error_message": "Account not found.",
+            "status": "error",
+            "request": { "command": "account_info" }
+        }});
+
+        let response: XrplResponse<Value> =
+            serde_json::from_value(envelope(reply).unwrap()).unwrap();
+
+        match response.result() {
+            Err(XrplError::ApiError { error, error_code, .. }) => {
+                assert_eq!(error, "actNotFound");
+                assert_eq!(error_code, Some(19));
+            }
+            other => panic!("expected ApiError, got {other:?}"),
+        }
+    }
+
+    /// The configured request timeout is applied to the HTTP client, so a
+    /// server that never replies fails with `Timeout` instead of hanging.
+    #[tokio::test]
+    async fn silent_server_times_out() {
+        let listener =
+            tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let _server = tokio::spawn(async move {
+            let _conn = listener.accept().await;
+            std::future::pending::<()>().await;
+        });
+        let config = ClientConfig::new()
+            .with_request_timeout(Duration::from_millis(200));
+        let client = HttpClient::with_config(url, config).unwrap();
+
+        let req = AccountInfoRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        let err = client.request(&req).await.expect_err("must time out");
+        assert!(matches!(err, XrplError::Timeout(200)), "got {err:?}");
+    }
+
+    #[test]
+    fn success_result_maps_to_success() {
+        let reply = json!({ "result": { "ledger_current_index": 7, "status": "success" } });
+
+        let response: XrplResponse<Value> =
+            serde_json::from_value(envelope(reply).unwrap()).unwrap();
+
+        assert_eq!(response.result().unwrap()["ledger_current_index"], 7);
+    }
+}
@@ -1,8 +1,8 @@
 //! # XRPL Client Library
 //!
 //! Lightweight async WebSocket client for the XRP Ledger. Supports requests,
-//! subscriptions, and automatic reconnection. Transaction signing and
-//! serialization is delegated to external libraries.
+//! subscriptions, and automatic reconnection. Transactions are serialized and
+//! hashed in-crate; seed-based signing is available behind the `wallet` feature.
 //!
 //! ## Installation
 //!
@@ -255,22 +255,20 @@
 //!
 //! ## Signing
 //!
-//! Signing and binary serialization are outside the scope of this library and
-//! are intentionally delegated to purpose-built crates (e.g. `ripple-keypairs`,
-//! `xrpl-mithril`). Implement the [`types::SigningContext`] trait on your wallet
-//! type to bridge the two.
+//! With the `wallet` feature, `wallet::Wallet` derives keys from a family seed
+//! and implements [`types::SigningContext`] and [`types::MultiSigningContext`].
+//! Otherwise, key handling is delegated to purpose-built crates (e.g.
+//! `ripple-keypairs`): implement [`types::SigningContext`] on your wallet type
+//! to bridge the two.
 //!
-//! The process follows the XRPL signing protocol: serialize the transaction to
-//! binary (excluding the signature fields), prepend `HASH_PREFIX_TRANSACTION_SIGN`
-//! (the "STX" prefix), sign the bytes, attach the signature, then serialize the
-//! final blob for submission.
+//! The process follows the XRPL signing protocol: [`types::codec::signing_data`]
+//! serializes the transaction to binary (excluding the signature fields) behind
+//! the `STX` prefix; sign those bytes, attach the signature, then
+//! [`types::codec::encode`] the final blob for submission.
 //!
 //! ```ignore
-//! use anyhow::Context;
 //! use ripple_keypairs::{PrivateKey, PublicKey};
-//! use xrpl_mithril::codec::serializer::serialize_json_object;
-//! use xrpl_mithril::codec::signing::HASH_PREFIX_TRANSACTION_SIGN;
-//! use xrpl::types::{Transaction, SigningContext};
+//! use xrpl::types::{Transaction, SigningContext, codec};
 //!
 //! struct Wallet {
 //!     public_key: PublicKey,
@@ -284,24 +282,10 @@
 //!         let mut tx_json = serde_json::to_value(tx)?;
 //!         tx_json["SigningPubKey"] = self.public_key.to_string().into();
 //!
-//!         let buf = {
-//!             let map = tx_json.as_object().context("Transaction should be JSON object")?;
-//!             let mut buf = Vec::new();
-//!             serialize_json_object(map, &mut buf, true)?;
-//!             buf
-//!         };
-//!
-//!         let mut signing_bytes = Vec::with_capacity(4 + buf.len());
-//!         signing_bytes.extend_from_slice(&HASH_PREFIX_TRANSACTION_SIGN);
-//!         signing_bytes.extend_from_slice(&buf);
-//!         let signature = self.private_key.sign(&signing_bytes);
+//!         let signature = self.private_key.sign(&codec::signing_data(&tx_json)?);
 //!         tx_json["TxnSignature"] = signature.to_string().into();
 //!
-//!         let map = tx_json.as_object().context("Transaction should be JSON object")?;
-//!         let mut buf = Vec::new();
-//!         serialize_json_object(map, &mut buf, false)?;
-//!
-//!         Ok(hex::encode(buf).to_uppercase())
+//!         Ok(hex::encode_upper(codec::encode(&tx_json)?))
 //!     }
 //! }
 //! ```
@@ -331,23 +315,55 @@
 //! assert_eq!(result.result()?.engine_result, "tesSUCCESS");
 //! ```
 
+/// Classic and X-address encoding, checksum verification, and AccountIDs.
+pub mod address;
+/// Off-ledger payment channel claims: build, sign, verify, and track.
+pub mod channel;
+/// PREIMAGE-SHA-256 crypto-conditions for conditional escrows.
+pub mod conditions;
 /// Client configuration (timeouts, channel sizes, reconnect backoff).
 pub mod config;
 /// Error types returned by the client.
 pub mod error;
+/// JSON-RPC over HTTP client for environments without persistent WebSockets.
+#[cfg(feature = "http")]
+pub mod http;
+/// Multi-signature coordination against an account's `SignerList`.
+pub mod multisig;
+/// In-memory order book kept current from a `books` subscription.
+pub mod order_book;
+/// Streams that follow `marker` through every page of a paginated request.
+pub mod pagination;
+/// Endpoint health tracking for clients connected to several nodes.
+pub mod pool;
 /// Request types and response envelopes for all XRPL JSON-RPC commands.
 pub mod request;
+/// Local sequence-number allocation for pipelined submission from one account.
+pub mod sequence;
 /// Subscription session for receiving streamed messages.
 pub mod session;
 pub(crate) mod socket;
+/// Reliable submission: sign, submit, and wait for a validated outcome.
+pub mod submission;
 /// Subscription request types and streamed message types.
 pub mod subscriptions;
+/// In-process mock rippled server for offline tests.
+#[cfg(feature = "testing")]
+pub mod testing;
+/// Ticket pool: load, lease, settle, and replenish an account's Tickets.
+pub mod tickets;
 /// Ripple-epoch time conversion utilities.
 pub mod time;
 /// Transaction, account-object, amount, and builder types.
 pub mod types;
 /// Account utility helpers (balance, sequence, existence, flags).
 pub mod util;
+/// Signature verification for single- and multi-signed transactions.
+#[cfg(feature = "wallet")]
+pub mod verify;
+/// Keypair derivation from family seeds and a built-in signing wallet.
+#[cfg(feature = "wallet")]
+pub mod wallet;
 
 // Public re-exports
 pub use error::XrplError;
@@ -355,14 +371,20 @@ pub use config::ClientConfig;
 pub use session::{SubscriptionEvent, SubscriptionSession, SubscriptionStream};
 
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use serde_json::Value;
 use tokio::sync::{mpsc, oneshot};
 use tokio::time::timeout;
 
+use pool::{EndpointHealth, EndpointPool, spawn_health_checks};
 use socket::{request, subscribe, SocketRequest};
-use request::XrplRequest;
+use pagination::Pagination;
+use request::{Paginated, XrplRequest};
+use submission::{ReliableSubmission, SubmissionError, SubmissionOutcome};
+use types::{SigningContext, Transaction};
 
 /// Extracts an application-level error from a raw rippled response, if present.
 fn rippled_error(response: &Value) -> Option<XrplError> {
@@ -459,10 +481,26 @@ fn rippled_error(response: &Value) -> Option<XrplError> {
 ///     Ok(())
 /// }
 /// ```
+///
+/// ## Failing over between several nodes
+/// ```no_run
+/// use xrpl::{Client, ClientConfig};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::with_endpoints(
+///         ["wss://xrplcluster.com", "wss://s1.ripple.com", "wss://s2.ripple.com"],
+///         ClientConfig::default().with_health_check_interval_secs(15),
+///     );
+/// }
+/// ```
 #[derive(Clone)]
 pub struct Client {
+    /// URL of the first (preferred) endpoint.
     pub url: String,
     config: ClientConfig,
+    pool: Arc<EndpointPool>,
+    endpoint: Arc<AtomicUsize>,
     connection: mpsc::Sender<SocketRequest>,
     request_timeout: Duration,
 }
@@ -481,21 +519,82 @@ impl Client {
     /// Spawns a shared background task that maintains a persistent WebSocket
     /// connection for all one-shot requests. Requires an active Tokio runtime.
     pub fn with_config(url: impl AsRef<str>, config: ClientConfig) -> Self {
+        Self::with_endpoints([url], config)
+    }
+
+    /// Create a client that fails over between several endpoints.
+    ///
+    /// Endpoints are listed in order of preference. Each is probed with
+    /// `server_info` every [`ClientConfig::health_check_interval`]; requests
+    /// and subscriptions use the healthiest one and move to the next when it
+    /// disconnects, times out, or falls out of sync. Active subscriptions are
+    /// replayed on the new endpoint. Requires an active Tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `urls` is empty.
+    pub fn with_endpoints<I, S>(urls: I, config: ClientConfig) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let urls: Vec<String> =
+            urls.into_iter().map(|u| u.as_ref().to_string()).collect();
+        assert!(!urls.is_empty(), "Client requires at least one endpoint");
+
+        let url = urls[0].clone();
+        let pool = EndpointPool::new(urls);
+        if pool.len() > 1 {
+            spawn_health_checks(Arc::downgrade(&pool), config.clone());
+        }
+
         let request_timeout = config.request_timeout;
-        let url = url.as_ref().to_string();
-        let connection = request(url.clone(), config.clone());
-        Self { url, config, connection, request_timeout }
+        let (connection, endpoint) = request(pool.clone(), config.clone());
+        Self { url, config, pool, endpoint, connection, request_timeout }
+    }
+
+    /// Last known health of every endpoint, in the order they were given.
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.pool.snapshot()
     }
 
     /// Send a request to the XRP Ledger and return the response.
     ///
     /// All requests from this client share a single persistent WebSocket
     /// connection. Concurrent calls are multiplexed by request ID.
+    ///
+    /// With several endpoints, a request that times out or is cut off by a
+    /// disconnect is retried on the next healthy endpoint, at most once per
+    /// endpoint. Resubmitting the same signed blob is safe: it has the same
+    /// hash and can only be applied once.
     pub async fn request<T: XrplRequest>(
         &self,
         req: &T,
     ) -> Result<T::Response, XrplError> {
         let request = req.to_value()?;
+        let mut attempts = self.pool.len();
+
+        let response = loop {
+            attempts -= 1;
+            match self.send(request.clone()).await {
+                Err(XrplError::Timeout(_)) if attempts > 0 => {
+                    // The driver only notices disconnects on its own.
+                    let endpoint = self.endpoint.load(Ordering::Relaxed);
+                    self.pool.report_failure(endpoint);
+                }
+                Err(XrplError::Disconnected) if attempts > 0 => {}
+                result => break result?,
+            }
+        };
+
+        let result: T::Response = serde_json::from_value(response)
+            .map_err(|e| XrplError::ParseError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    /// Sends a raw request over the shared connection and awaits its response.
+    async fn send(&self, request: Value) -> Result<Value, XrplError> {
         let (responder, rx) = oneshot::channel();
 
         self.connection
@@ -510,10 +609,28 @@ impl Client {
             })?
             .map_err(|_| XrplError::Disconnected)??;
 
-        let result: T::Response = serde_json::from_value(response)
-            .map_err(|e| XrplError::ParseError(e.to_string()))?;
+        Ok(response)
+    }
 
-        Ok(result)
+    /// Streams the items of every page of a paginated request.
+    ///
+    /// See [`Pagination`] for how pages are followed and pinned to a single
+    /// ledger. Nothing is requested until the stream is first polled.
+    pub fn paginate<R: Paginated>(&self, req: R) -> Pagination<R> {
+        Pagination::new(self.clone(), req)
+    }
+
+    /// Signs and submits a transaction, then waits until it is validated or expired.
+    ///
+    /// Shorthand for [`ReliableSubmission::new`] followed by
+    /// [`ReliableSubmission::submit`]. See [`SubmissionOutcome`] for the
+    /// possible results.
+    pub async fn submit_and_wait<W: SigningContext>(
+        &self,
+        tx: &Transaction,
+        wallet: &W,
+    ) -> Result<SubmissionOutcome, SubmissionError<W::Error>> {
+        ReliableSubmission::new(tx, wallet).submit(self).await
     }
 
     /// Opens the shared connection backing one or more subscription streams.
@@ -525,7 +642,7 @@ impl Client {
         &self,
     ) -> Result<SubscriptionSession<SubscriptionEvent>, XrplError> {
         let (connection, receiver) =
-            subscribe(self.url.clone(), self.config.clone());
+            subscribe(self.pool.clone(), self.config.clone());
 
         let stream = SubscriptionStream {
             id: None,
@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use crate::address::AccountId;
+use crate::request::account_info::AccountInfoRequest;
+use crate::request::submit_multisigned::SubmitMultisignedResponse;
+use crate::types::builders::{
+    BuildError, SubmitMultisignedRequestBuilder, TransactionBuilder,
+    TransactionTypeBuilder,
+};
+use crate::types::{Signer, SignerList, Transaction, TransactionType};
+use crate::{Client, XrplError};
+
+/// Errors returned by [`MultisigSession`].
+#[derive(Error, Debug)]
+pub enum MultisigError {
+    /// The account has no `SignerList` in the validated ledger.
+    #[error("Account {0} has no signer list")]
+    NoSignerList(String),
+    /// The signature is from an account that is not on the signer list.
+    #[error("{0} is not on the signer list")]
+    UnknownSigner(String),
+    /// The account has already signed.
+    #[error("{0} has already signed")]
+    DuplicateSigner(String),
+    /// The collected weight does not reach the quorum yet.
+    #[error("Signer weight {weight} is below the quorum of {quorum}")]
+    QuorumNotReached {
+        /// Weight of the signatures collected so far.
+        weight: u32,
+        /// Weight the signer list requires.
+        quorum: u32,
+    },
+    /// The transaction could not be built.
+    #[error(transparent)]
+    Build(#[from] BuildError),
+    /// The assembled transaction could not be serialized.
+    #[error("Failed to serialize transaction: {0}")]
+    Serialize(#[from] serde_json::Error),
+    /// A request failed.
+    #[error(transparent)]
+    Xrpl(#[from] XrplError),
+}
+
+/// Collects signatures for one multi-signed transaction against the
+/// account's `SignerList`.
+///
+/// [`prepare`](Self::prepare) reads the signer list, fills the transaction
+/// with a fee for `1 + N` signatures (N = every entry on the list, so any
+/// subset that reaches quorum is covered) and builds it. Hand
+/// [`transaction`](Self::transaction) to each party to sign with
+/// [`MultiSignable::sign_as`](crate::types::MultiSignable::sign_as), feed
+/// the results to [`add_signer`](Self::add_signer), and
+/// [`submit`](Self::submit) once [`has_quorum`](Self::has_quorum).
+///
+/// # Example
+/// ```rust,no_run
+/// # use xrpl::types::{MultiSigningContext, SignerWrapper, Transaction};
+/// # struct Party;
+/// # impl MultiSigningContext for Party {
+/// #     type Error = anyhow::Error;
+/// #     fn sign_as_signer(&self, _tx: &Transaction) -> anyhow::Result<SignerWrapper> { todo!() }
+/// # }
+/// use xrpl::{Client, multisig::MultisigSession, xrp};
+/// use xrpl::types::MultiSignable;
+/// use xrpl::types::builders::PaymentBuilder;
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let (alice, bob) = (Party, Party);
+/// let client = Client::new("wss://xrplcluster.com");
+/// let payment = PaymentBuilder::new(
+///     "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+///     "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+///     xrp!(5),
+/// );
+/// let mut session = MultisigSession::prepare(&client, payment).await?;
+///
+/// for party in [&alice, &bob] {
+///     session.add_signer(session.transaction().sign_as(party)?)?;
+/// }
+/// println!("weight {}/{}", session.weight(), session.quorum());
+/// let result = session.submit(&client).await?;
+/// println!("{}", result.engine_result);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultisigSession {
+    tx: Transaction,
+    quorum: u32,
+    weights: BTreeMap<AccountId, u16>,
+    signers: BTreeMap<AccountId, Signer>,
+}
+
+impl MultisigSession {
+    /// Reads the signer list of the builder's account from the validated
+    /// ledger, then fills and builds the transaction for multi-signing.
+    pub async fn prepare<T>(
+        client: &Client,
+        builder: TransactionBuilder<T>,
+    ) -> Result<Self, MultisigError>
+    where
+        T: TransactionTypeBuilder<TransactionType = TransactionType>,
+    {
+        let req = AccountInfoRequest::new(builder.account())
+            .with_ledger_index("validated")
+            .with_signer_lists(true);
+        let signer_list = client
+            .request(&req)
+            .await?
+            .result()?
+            .signer_lists
+            .and_then(|lists| lists.into_iter().next())
+            .ok_or_else(|| {
+                MultisigError::NoSignerList(builder.account().to_string())
+            })?;
+
+        let entries = signer_list.signer_entries.len() as u32;
+        let tx =
+            builder.with_signer_count(entries).fill(client).await?.build()?;
+        Ok(Self::new(tx, &signer_list))
+    }
+
+    /// Starts a session for an already built transaction, checked against
+    /// `signer_list`. The transaction's fee must already cover the
+    /// signatures; see [`TransactionBuilder::with_signer_count`].
+    pub fn new(mut tx: Transaction, signer_list: &SignerList) -> Self {
+        tx.signers = None;
+        tx.signing_pub_key = Some(String::new());
+        let weights = signer_list
+            .signer_entries
+            .iter()
+            .filter_map(|wrapper| {
+                let entry = &wrapper.signer_entry;
+                Some((entry.account.parse().ok()?, entry.signer_weight))
+            })
+            .collect();
+        Self {
+            tx,
+            quorum: signer_list.signer_quorum,
+            weights,
+            signers: BTreeMap::new(),
+        }
+    }
+
+    /// The unsigned transaction every party signs.
+    pub fn transaction(&self) -> &Transaction {
+        &self.tx
+    }
+
+    /// Adds one party's signature and returns the weight collected so far.
+    ///
+    /// Rejects accounts that are not on the signer list or have already
+    /// signed. The signature itself is checked by the server on submission.
+    pub fn add_signer(
+        &mut self,
+        signer: impl Into<Signer>,
+    ) -> Result<u32, MultisigError> {
+        let signer = signer.into();
+        let Some(id) = signer
+            .account
+            .parse::<AccountId>()
+            .ok()
+            .filter(|id| self.weights.contains_key(id))
+        else {
+            return Err(MultisigError::UnknownSigner(signer.account));
+        };
+        if self.signers.contains_key(&id) {
+            return Err(MultisigError::DuplicateSigner(signer.account));
+        }
+        self.signers.insert(id, signer);
+        Ok(self.weight())
+    }
+
+    /// Accounts that have signed, in AccountID order.
+    pub fn signers(&self) -> impl Iterator<Item = &Signer> {
+        self.signers.values()
+    }
+
+    /// Total weight of the signatures collected so far.
+    pub fn weight(&self) -> u32 {
+        self.signers.keys().map(|id| u32::from(self.weights[id])).sum()
+    }
+
+    /// Weight the signer list requires.
+    pub fn quorum(&self) -> u32 {
+        self.quorum
+    }
+
+    /// Whether the collected weight reaches the quorum.
+    pub fn has_quorum(&self) -> bool {
+        self.weight() >= self.quorum
+    }
+
+    /// The transaction with every collected signature attached, sorted by
+    /// AccountID. Fails until the quorum is reached.
+    pub fn assemble(&self) -> Result<Transaction, MultisigError> {
+        if !self.has_quorum() {
+            return Err(MultisigError::QuorumNotReached {
+                weight: self.weight(),
+                quorum: self.quorum,
+            });
+        }
+        let mut tx = self.tx.clone();
+        tx.add_signatures(self.signers.values());
+        Ok(tx)
+    }
+
+    /// Assembles the transaction and submits it with `submit_multisigned`.
+    pub async fn submit(
+        &self,
+        client: &Client,
+    ) -> Result<SubmitMultisignedResponse, MultisigError> {
+        let tx = self.assemble()?;
+        let req = SubmitMultisignedRequestBuilder::new(&tx).build()?;
+        Ok(client.request(&req).await?.result()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::builders::PaymentBuilder;
+
+    const OWNER: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+    // Base58 order differs from AccountID order for these two.
+    const ALICE: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+    const BOB: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+    const CAROL: &str = "rrrrrrrrrrrrrrrrrrrrrhoLvTp";
+
+    fn session() -> MultisigSession {
+        let list: SignerList = serde_json::from_value(json!({
+            "SignerEntries": [
+                { "SignerEntry": { "Account": ALICE, "SignerWeight": 1 } },
+                { "SignerEntry": { "Account": BOB, "SignerWeight": 2 } },
+            ],
+            "SignerListID": 0,
+            "SignerQuorum": 3,
+            "Flags": 0,
+        }))
+        .unwrap();
+        let tx = PaymentBuilder::new(OWNER, ALICE, crate::xrp!(1))
+            .with_fee(crate::types::Amount::drops("30").unwrap())
+            .with_sequence(1)
+            .build()
+            .unwrap();
+        MultisigSession::new(tx, &list)
+    }
+
+    fn signer(account: &str) -> Signer {
+        Signer::new(account, "AB", "CD")
+    }
+
+    #[test]
+    fn test_collects_signers_until_quorum() {
+        let mut session = session();
+        assert_eq!(session.add_signer(signer(BOB)).unwrap(), 2);
+        assert!(matches!(
+            session.assemble(),
+            Err(MultisigError::QuorumNotReached { weight: 2, quorum: 3 })
+        ));
+        assert!(matches!(
+            session.add_signer(signer(CAROL)),
+            Err(MultisigError::UnknownSigner(_))
+        ));
+        assert!(matches!(
+            session.add_signer(signer(BOB)),
+            Err(MultisigError::DuplicateSigner(_))
+        ));
+        assert_eq!(session.add_signer(signer(ALICE)).unwrap(), 3);
+        assert!(session.has_quorum());
+    }
+
+    #[test]
+    fn test_assemble_sorts_by_account_id() {
+        let mut session = session();
+        session.add_signer(signer(ALICE)).unwrap();
+        session.add_signer(signer(BOB)).unwrap();
+
+        let tx = session.assemble().unwrap();
+        let accounts: Vec<_> =
+            tx.signers.unwrap().into_iter().map(|s| s.signer.account).collect();
+        let mut by_id = [ALICE, BOB];
+        by_id.sort_by_key(|a| a.parse::<AccountId>().unwrap());
+        assert_eq!(accounts, by_id);
+        assert_eq!(tx.signing_pub_key.as_deref(), Some(""));
+    }
+}
@@ -0,0 +1,667 @@
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use tokio::sync::broadcast;
+
+use crate::request::book_offers::{BookOffer, BookOffersRequest};
+use crate::subscriptions::{
+    AccountTransactionMessage, Book, BookSubscription, BookSubscriptionResponse,
+};
+use crate::types::builders::BuildError;
+use crate::types::{
+    AccountObject, AffectedNode, Amount, ArithmeticError, Asset, XrplDecimal,
+};
+use crate::session::Received;
+use crate::{Client, SubscriptionSession, SubscriptionStream, XrplError};
+
+/// Capacity of the [`OrderBook::events`] channel.
+const EVENT_CHANNEL_SIZE: usize = 1024;
+
+/// Side of an [`OrderBook`] an offer rests on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BookSide {
+    /// Offers selling the base asset (`TakerGets` is base).
+    Ask,
+    /// Offers buying the base asset (`TakerPays` is base).
+    Bid,
+}
+
+/// A change to an [`OrderBook`], broadcast on [`OrderBook::events`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BookEvent {
+    /// A new offer entered the book.
+    Added {
+        /// Side the offer rests on.
+        side: BookSide,
+        /// The offer as it now stands.
+        offer: BookOffer,
+    },
+    /// A resting offer was partially consumed or otherwise changed.
+    Updated {
+        /// Side the offer rests on.
+        side: BookSide,
+        /// The offer as it now stands.
+        offer: BookOffer,
+    },
+    /// An offer was filled, cancelled, or removed as unfunded or expired.
+    Removed {
+        /// Side the offer rested on.
+        side: BookSide,
+        /// The offer as it last stood.
+        offer: BookOffer,
+    },
+    /// The whole book was replaced from a snapshot or resync.
+    Reloaded,
+}
+
+/// The offers at one quality on one side of an [`OrderBook`], summed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceLevel {
+    /// `TakerPays / TakerGets` shared by every offer in the level, in drops
+    /// for XRP.
+    pub quality: XrplDecimal,
+    /// Total funded `TakerGets` of the level.
+    pub taker_gets: Amount,
+    /// Total funded `TakerPays` of the level.
+    pub taker_pays: Amount,
+    /// Number of offers in the level.
+    pub offers: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    quality: XrplDecimal,
+    offer: BookOffer,
+}
+
+/// An in-memory order book for one asset pair, kept current from a `books`
+/// subscription.
+///
+/// Asks are offers selling `base` for `quote`, bids are offers selling
+/// `quote` for `base`. Each side is ordered best first by quality (the
+/// offer's `TakerPays / TakerGets`, lowest first), with offers of equal
+/// quality in arrival order, the order in which they are consumed.
+///
+/// The book is seeded from the subscribe snapshot, then kept current by
+/// [`apply`](Self::apply)ing the affected `Offer` nodes of every validated
+/// transaction the subscription pushes. Pushes missed while the stream lagged
+/// or reconnected cannot be replayed, so [`update`](Self::update) reloads
+/// the book with `book_offers` after [`XrplError::MessageDropped`] and after
+/// every reconnect.
+///
+/// Funding is only as fresh as the last snapshot: offers whose owner has
+/// since run out of funds stay in the book until a transaction crosses or
+/// cancels them.
+///
+/// # Example
+/// ```no_run
+/// use xrpl::Client;
+/// use xrpl::order_book::OrderBook;
+/// use xrpl::types::Asset;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Client::new("wss://xrplcluster.com");
+///     let usd = Asset::token("USD", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B")?;
+///     let mut book = OrderBook::new(Asset::xrp(), usd)?;
+///
+///     let mut session = client.subscription().await?;
+///     let mut stream = book.subscribe(&mut session).await?;
+///     loop {
+///         book.update(&client, &mut stream).await?;
+///         if let (Some(bid), Some(ask)) = (book.best_bid(), book.best_ask()) {
+///             println!("{} / {}", bid.taker_gets, ask.taker_gets);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OrderBook {
+    base: Asset,
+    quote: Asset,
+    book: Book,
+    limit: Option<u32>,
+    asks: Vec<Entry>,
+    bids: Vec<Entry>,
+    /// Validated ledger the last resync read; pushes from it or earlier are
+    /// already reflected in the book.
+    synced_through: Option<u32>,
+    events: broadcast::Sender<BookEvent>,
+}
+
+impl OrderBook {
+    /// Creates an empty book of `base` against `quote`.
+    ///
+    /// Fails for assets a `books` subscription cannot name, such as MPTs.
+    pub fn new(
+        base: impl Into<Asset>,
+        quote: impl Into<Asset>,
+    ) -> Result<Self, BuildError> {
+        let base = base.into();
+        let quote = quote.into();
+        let book = Book::currency_pair(
+            base.currency(),
+            base.issuer(),
+            quote.currency(),
+            quote.issuer(),
+            true,
+            true,
+        )?;
+        Ok(Self {
+            base,
+            quote,
+            book,
+            limit: None,
+            asks: Vec::new(),
+            bids: Vec::new(),
+            synced_through: None,
+            events: broadcast::channel(EVENT_CHANNEL_SIZE).0,
+        })
+    }
+
+    /// Caps the offers per side requested by [`resync`](Self::resync).
+    /// Without it rippled applies its own default.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The asset asks sell and bids buy.
+    pub fn base(&self) -> &Asset {
+        &self.base
+    }
+
+    /// The asset asks are priced in.
+    pub fn quote(&self) -> &Asset {
+        &self.quote
+    }
+
+    /// A two-sided `books` subscription with a snapshot, for this pair.
+    pub fn subscription(&self) -> BookSubscription {
+        BookSubscription::new().with_book(self.book.clone())
+    }
+
+    /// Subscribes to this pair on `session`, loads the snapshot from the
+    /// response, and returns the stream to pass to [`update`](Self::update).
+    pub async fn subscribe<T>(
+        &mut self,
+        session: &mut SubscriptionSession<T>,
+    ) -> Result<SubscriptionStream<AccountTransactionMessage>, XrplError>
+    where
+        T: Clone + Send + DeserializeOwned + Debug + 'static,
+    {
+        let (response, stream) =
+            session.subscribe(&self.subscription()).await?;
+        self.load(&response.result()?);
+        Ok(stream)
+    }
+
+    /// Replaces the book with the snapshot of a subscribe response.
+    pub fn load(&mut self, response: &BookSubscriptionResponse) {
+        let offers = [&response.offers, &response.asks, &response.bids]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .cloned();
+        self.reload(offers, None);
+    }
+
+    /// Replaces the book with both sides read by `book_offers` from one
+    /// validated ledger.
+    pub async fn resync(&mut self, client: &Client) -> Result<(), XrplError> {
+        let request = |gets: &Asset, pays: &Asset| {
+            let request = BookOffersRequest::new(gets, pays);
+            match self.limit {
+                Some(limit) => request.with_limit(limit),
+                None => request,
+            }
+        };
+        let asks = client
+            .request(
+                &request(&self.base, &self.quote)
+                    .with_ledger_index("validated"),
+            )
+            .await?
+            .result()?;
+        let ledger_index = asks.ledger_index.ok_or_else(|| {
+            XrplError::ParseError(
+                "book_offers response did not include ledger_index".to_string(),
+            )
+        })?;
+        let bids = client
+            .request(
+                &request(&self.quote, &self.base)
+                    .with_ledger_index(ledger_index),
+            )
+            .await?
+            .result()?;
+        self.reload(
+            asks.offers.into_iter().chain(bids.offers),
+            Some(ledger_index),
+        );
+        Ok(())
+    }
+
+    /// Applies the `Offer` nodes of a validated transaction to the book.
+    ///
+    /// Unvalidated messages, messages without metadata, and messages from
+    /// ledgers already covered by the last [`resync`](Self::resync) are
+    /// ignored. Returns whether the book changed.
+    pub fn apply(&mut self, message: &AccountTransactionMessage) -> bool {
+        let Some(meta) = message.meta.as_ref().filter(|_| message.validated)
+        else {
+            return false;
+        };
+        if let (Some(synced), Some(ledger)) =
+            (self.synced_through, message.ledger_index)
+            && ledger <= synced
+        {
+            return false;
+        }
+        let mut changed = false;
+        for node in &meta.affected_nodes {
+            if node.ledger_entry_type() != "Offer" {
+                continue;
+            }
+            changed |= match node {
+                AffectedNode::DeletedNode(_) => {
+                    self.remove(node.ledger_index())
+                }
+                _ => self.upsert(node),
+            };
+        }
+        changed
+    }
+
+    /// Waits for the next push on `stream` and applies it, resyncing from
+    /// `client` instead when the stream reports that pushes were missed.
+    pub async fn update(
+        &mut self,
+        client: &Client,
+        stream: &mut SubscriptionStream<AccountTransactionMessage>,
+    ) -> Result<(), XrplError> {
+        match stream.recv_or_reconnected().await {
+            Ok(Received::Message(message)) => {
+                self.apply(&message);
+                Ok(())
+            }
+            Ok(Received::Reconnected) | Err(XrplError::MessageDropped(_)) => {
+                self.resync(client).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Asks, best (lowest quality) first.
+    pub fn asks(&self) -> impl Iterator<Item = &BookOffer> {
+        self.asks.iter().map(|entry| &entry.offer)
+    }
+
+    /// Bids, best (lowest quality) first.
+    pub fn bids(&self) -> impl Iterator<Item = &BookOffer> {
+        self.bids.iter().map(|entry| &entry.offer)
+    }
+
+    /// The first ask to be consumed.
+    pub fn best_ask(&self) -> Option<&BookOffer> {
+        self.asks().next()
+    }
+
+    /// The first bid to be consumed.
+    pub fn best_bid(&self) -> Option<&BookOffer> {
+        self.bids().next()
+    }
+
+    /// The best `levels` price levels of `side`, with funded amounts summed.
+    pub fn depth(
+        &self,
+        side: BookSide,
+        levels: usize,
+    ) -> Result<Vec<PriceLevel>, ArithmeticError> {
+        let mut depth: Vec<PriceLevel> = Vec::new();
+        for entry in self.side(side) {
+            let offer = &entry.offer;
+            let gets =
+                offer.taker_gets_funded.as_ref().unwrap_or(&offer.taker_gets);
+            let pays =
+                offer.taker_pays_funded.as_ref().unwrap_or(&offer.taker_pays);
+            if let Some(level) = depth.last_mut()
+                && level.quality == entry.quality
+            {
+                level.taker_gets = level.taker_gets.checked_add(gets)?;
+                level.taker_pays = level.taker_pays.checked_add(pays)?;
+                level.offers += 1;
+                continue;
+            }
+            if depth.len() == levels {
+                break;
+            }
+            depth.push(PriceLevel {
+                quality: entry.quality,
+                taker_gets: gets.clone(),
+                taker_pays: pays.clone(),
+                offers: 1,
+            });
+        }
+        Ok(depth)
+    }
+
+    /// Validated ledger the last [`resync`](Self::resync) read, if any.
+    pub fn synced_through(&self) -> Option<u32> {
+        self.synced_through
+    }
+
+    /// Stream of changes to the book, from now on.
+    pub fn events(&self) -> broadcast::Receiver<BookEvent> {
+        self.events.subscribe()
+    }
+
+    fn side(&self, side: BookSide) -> &Vec<Entry> {
+        match side {
+            BookSide::Ask => &self.asks,
+            BookSide::Bid => &self.bids,
+        }
+    }
+
+    fn side_mut(&mut self, side: BookSide) -> &mut Vec<Entry> {
+        match side {
+            BookSide::Ask => &mut self.asks,
+            BookSide::Bid => &mut self.bids,
+        }
+    }
+
+    /// Side of this book `offer` belongs to, if any.
+    fn side_of(&self, offer: &BookOffer) -> Option<BookSide> {
+        let gets = Asset::try_from(&offer.taker_gets).ok()?;
+        let pays = Asset::try_from(&offer.taker_pays).ok()?;
+        if gets == self.base && pays == self.quote {
+            Some(BookSide::Ask)
+        } else if gets == self.quote && pays == self.base {
+            Some(BookSide::Bid)
+        } else {
+            None
+        }
+    }
+
+    fn reload(
+        &mut self,
+        offers: impl IntoIterator<Item = BookOffer>,
+        synced_through: Option<u32>,
+    ) {
+        self.asks.clear();
+        self.bids.clear();
+        for offer in offers {
+            self.insert(offer);
+        }
+        self.synced_through = synced_through;
+        let _ = self.events.send(BookEvent::Reloaded);
+    }
+
+    /// Inserts `offer` behind any offers of equal quality.
+    fn insert(&mut self, offer: BookOffer) -> Option<BookSide> {
+        let side = self.side_of(&offer)?;
+        let quality = quality(&offer)?;
+        let entries = self.side_mut(side);
+        let at = entries.partition_point(|entry| entry.quality <= quality);
+        entries.insert(at, Entry { quality, offer });
+        Some(side)
+    }
+
+    fn upsert(&mut self, node: &AffectedNode) -> bool {
+        let Some(AccountObject::Offer(offer)) = node.fields() else {
+            return false;
+        };
+        let mut offer = BookOffer::from(offer);
+        offer.index = Some(node.ledger_index().to_string());
+        let Some(side) = self.side_of(&offer) else {
+            return false;
+        };
+        let existing = self
+            .side_mut(side)
+            .iter_mut()
+            .find(|entry| entry.offer.index == offer.index);
+        let event = match existing {
+            Some(entry) => {
+                // Partial fills keep the offer's place; funding snapshots
+                // are stale once its amounts move.
+                entry.offer =
+                    BookOffer { quality: entry.offer.quality.take(), ..offer };
+                BookEvent::Updated { side, offer: entry.offer.clone() }
+            }
+            None => {
+                if self.insert(offer.clone()).is_none() {
+                    return false;
+                }
+                BookEvent::Added { side, offer }
+            }
+        };
+        let _ = self.events.send(event);
+        true
+    }
+
+    fn remove(&mut self, index: &str) -> bool {
+        for side in [BookSide::Ask, BookSide::Bid] {
+            let entries = self.side_mut(side);
+            if let Some(at) = entries
+                .iter()
+                .position(|entry| entry.offer.index.as_deref() == Some(index))
+            {
+                let offer = entries.remove(at).offer;
+                let _ = self.events.send(BookEvent::Removed { side, offer });
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// `TakerPays / TakerGets` of `offer`, read from the low 64 bits of its book
+/// directory (top byte exponent + 100, low 56 bits mantissa) when present.
+fn quality(offer: &BookOffer) -> Option<XrplDecimal> {
+    if let Some(raw) = offer
+        .book_directory
+        .as_deref()
+        .filter(|dir| dir.len() == 64)
+        .and_then(|dir| u64::from_str_radix(&dir[48..], 16).ok())
+    {
+        let mantissa = (raw & ((1 << 56) - 1)) as i64;
+        let exponent = (raw >> 56) as i32 - 100;
+        return XrplDecimal::new(mantissa, exponent).ok();
+    }
+    let pays = offer.taker_pays.to_xrpl_decimal().ok()?;
+    let gets = offer.taker_gets.to_xrpl_decimal().ok()?;
+    pays.checked_div(gets).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const ISSUER: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+    const OWNER: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+
+    fn book() -> OrderBook {
+        OrderBook::new(Asset::xrp(), Asset::token("USD", ISSUER).unwrap())
+            .unwrap()
+    }
+
+    fn usd(value: &str) -> serde_json::Value {
+        json!({ "currency": "USD", "issuer": ISSUER, "value": value })
+    }
+
+    /// Ledger fields of an offer. The directory is a placeholder, so the
+    /// quality comes from `pays / gets`.
+    fn fields(
+        gets: serde_json::Value,
+        pays: serde_json::Value,
+    ) -> serde_json::Value {
+        json!({
+            "Account": OWNER,
+            "BookDirectory": "DIR",
+            "Sequence": 7,
+            "TakerGets": gets,
+            "TakerPays": pays,
+        })
+    }
+
+    fn offer(
+        index: &str,
+        gets: serde_json::Value,
+        pays: serde_json::Value,
+    ) -> serde_json::Value {
+        let mut value = fields(gets, pays);
+        value["index"] = json!(index);
+        value
+    }
+
+    fn message(
+        nodes: serde_json::Value,
+        ledger_index: u32,
+    ) -> AccountTransactionMessage {
+        serde_json::from_value(json!({
+            "engine_result": "tesSUCCESS",
+            "engine_result_code": 0,
+            "engine_result_message": "",
+            "hash": "AB",
+            "ledger_index": ledger_index,
+            "meta": {
+                "AffectedNodes": nodes,
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS",
+            },
+            "tx_json": {
+                "TransactionType": "OfferCreate",
+                "Account": OWNER,
+                "Fee": "10",
+                "Sequence": 7,
+                "TakerGets": "1000000",
+                "TakerPays": usd("1"),
+            },
+            "validated": true,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_snapshot_is_sorted_by_quality() {
+        let mut book = book();
+        let response: BookSubscriptionResponse =
+            serde_json::from_value(json!({
+                "asks": [
+                    offer("A2", json!("2000000"), usd("3")),
+                    offer("A1", json!("2000000"), usd("2")),
+                ],
+                "bids": [offer("B1", usd("1"), json!("1000000"))],
+            }))
+            .unwrap();
+        book.load(&response);
+
+        let asks: Vec<_> = book.asks().map(|o| o.index.as_deref()).collect();
+        assert_eq!(asks, [Some("A1"), Some("A2")]);
+        assert_eq!(book.best_bid().unwrap().index.as_deref(), Some("B1"));
+    }
+
+    #[test]
+    fn test_quality_from_book_directory() {
+        let mut ask: BookOffer =
+            serde_json::from_value(offer("A", json!("1000000"), usd("1")))
+                .unwrap();
+        ask.book_directory = Some(
+            "DFA3B6DDAB58C7E8E5D944E736DA4B7046C30E4F460FD9DE4E1566CB6CB17B6A"
+                .to_string(),
+        );
+        // 0x4E - 100 = -22; mantissa 0x1566CB6CB17B6A.
+        assert_eq!(
+            quality(&ask).unwrap(),
+            XrplDecimal::new(0x1566CB6CB17B6A, -22).unwrap()
+        );
+        ask.book_directory = None;
+        assert_eq!(quality(&ask).unwrap(), "0.000001".parse().unwrap());
+    }
+
+    #[test]
+    fn test_apply_offer_nodes() {
+        let mut book = book();
+        let mut events = book.events();
+        book.load(
+            &serde_json::from_value(json!({
+                "asks": [
+                    offer("A1", json!("1000000"), usd("1")),
+                    offer("A2", json!("1000000"), usd("2")),
+                ],
+            }))
+            .unwrap(),
+        );
+        assert_eq!(events.try_recv().unwrap(), BookEvent::Reloaded);
+
+        let nodes = json!([
+            { "ModifiedNode": {
+                "LedgerEntryType": "Offer",
+                "LedgerIndex": "A1",
+                "FinalFields": fields(json!("500000"), usd("0.5")),
+            }},
+            { "DeletedNode": {
+                "LedgerEntryType": "Offer",
+                "LedgerIndex": "A2",
+                "FinalFields": fields(json!("1000000"), usd("2")),
+            }},
+            { "CreatedNode": {
+                "LedgerEntryType": "Offer",
+                "LedgerIndex": "A3",
+                "NewFields": fields(json!("1000000"), usd("1")),
+            }},
+            { "CreatedNode": {
+                "LedgerEntryType": "Offer",
+                "LedgerIndex": "B1",
+                "NewFields": fields(usd("3"), json!("1000000")),
+            }},
+            { "ModifiedNode": {
+                "LedgerEntryType": "AccountRoot",
+                "LedgerIndex": "C0",
+                "FinalFields": { "Balance": "1" },
+            }},
+        ]);
+        assert!(book.apply(&message(nodes, 10)));
+
+        let asks: Vec<_> = book
+            .asks()
+            .map(|o| (o.index.as_deref().unwrap(), o.taker_gets.value()))
+            .collect();
+        // A3 joins behind A1, which keeps its place after the partial fill.
+        assert_eq!(asks, [("A1", "500000"), ("A3", "1000000")]);
+        assert_eq!(book.best_bid().unwrap().index.as_deref(), Some("B1"));
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            BookEvent::Updated { side: BookSide::Ask, .. }
+        ));
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            BookEvent::Removed { side: BookSide::Ask, .. }
+        ));
+
+        let depth = book.depth(BookSide::Ask, 5).unwrap();
+        assert_eq!(depth.len(), 1);
+        assert_eq!(depth[0].offers, 2);
+        assert_eq!(depth[0].taker_gets.value(), "1500000");
+        assert_eq!(depth[0].taker_pays.value(), "1.5");
+    }
+
+    #[test]
+    fn test_apply_skips_synced_and_unvalidated() {
+        let mut book = book();
+        book.reload(Vec::new(), Some(10));
+        let nodes = json!([{ "CreatedNode": {
+            "LedgerEntryType": "Offer",
+            "LedgerIndex": "A1",
+            "NewFields": fields(json!("1000000"), usd("1")),
+        }}]);
+
+        assert!(!book.apply(&message(nodes.clone(), 10)));
+        let mut pending = message(nodes.clone(), 11);
+        pending.validated = false;
+        assert!(!book.apply(&pending));
+        assert!(book.apply(&message(nodes, 11)));
+    }
+}
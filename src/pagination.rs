@@ -0,0 +1,181 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::stream::{self, BoxStream, Stream, StreamExt};
+
+use crate::request::Paginated;
+use crate::{Client, XrplError};
+
+/// Stream of items from every page of a [`Paginated`] request.
+///
+/// Created by [`Client::paginate`]. Pages are requested lazily as the stream
+/// is polled: the marker from each response is carried into the next request,
+/// and every page after the first is pinned to the ledger the first page was
+/// read from, so entries do not shift or repeat between pages. The stream
+/// ends after the last page, after [`with_max_items`](Self::with_max_items)
+/// items, or after yielding the first error.
+///
+/// # Example
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use xrpl::Client;
+/// use xrpl::request::account_lines::AccountLinesRequest;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Client::new("wss://xrplcluster.com");
+///     let req = AccountLinesRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")
+///         .with_limit(400);
+///     let mut lines = client.paginate(req).with_max_items(1000);
+///
+///     while let Some(line) = lines.next().await {
+///         let line = line?;
+///         println!("{} {}", line.currency, line.balance);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct Pagination<R: Paginated> {
+    pages: BoxStream<'static, Result<R::Item, XrplError>>,
+    remaining: Option<usize>,
+}
+
+impl<R: Paginated> Pagination<R> {
+    pub(crate) fn new(client: Client, request: R) -> Self {
+        let state = PageState {
+            client,
+            request,
+            items: VecDeque::new(),
+            pinned: false,
+            done: false,
+        };
+        Self {
+            pages: stream::unfold(state, next_item).boxed(),
+            remaining: None,
+        }
+    }
+
+    /// Stops the stream after `max` items; no further pages are requested.
+    pub fn with_max_items(mut self, max: usize) -> Self {
+        self.remaining = Some(max);
+        self
+    }
+}
+
+impl<R: Paginated> Stream for Pagination<R> {
+    type Item = Result<R::Item, XrplError>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        if self.remaining == Some(0) {
+            return Poll::Ready(None);
+        }
+        let item = std::task::ready!(self.pages.poll_next_unpin(cx));
+        if let (Some(Ok(_)), Some(remaining)) = (&item, &mut self.remaining) {
+            *remaining -= 1;
+        }
+        Poll::Ready(item)
+    }
+}
+
+struct PageState<R: Paginated> {
+    client: Client,
+    request: R,
+    items: VecDeque<R::Item>,
+    pinned: bool,
+    done: bool,
+}
+
+/// Yields buffered items, fetching the next page whenever the buffer runs dry.
+async fn next_item<R: Paginated>(
+    mut state: PageState<R>,
+) -> Option<(Result<R::Item, XrplError>, PageState<R>)> {
+    loop {
+        if let Some(item) = state.items.pop_front() {
+            return Some((Ok(item), state));
+        }
+        if state.done {
+            return None;
+        }
+        if let Err(e) = fetch_page(&mut state).await {
+            state.done = true;
+            return Some((Err(e), state));
+        }
+    }
+}
+
+async fn fetch_page<R: Paginated>(
+    state: &mut PageState<R>,
+) -> Result<(), XrplError> {
+    let response = state.client.request(&state.request).await?;
+    let page = R::into_page(response)?;
+
+    if !state.pinned {
+        if let Some(ledger_index) = page.ledger_index {
+            state.request.pin_ledger(ledger_index);
+        }
+        state.pinned = true;
+    }
+    match page.marker {
+        Some(marker) => state.request.set_marker(marker),
+        None => state.done = true,
+    }
+    state.items.extend(page.items);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::request::account_lines::AccountLinesRequest;
+    use crate::request::account_tx::AccountTxRequest;
+    use crate::request::{Paginated, XrplResponse};
+
+    /// `account_tx` has no single ledger, so pinning caps the searched range
+    /// at the `ledger_index_max` the first page reported.
+    #[test]
+    fn account_tx_pins_upper_bound_of_range() {
+        let response: XrplResponse<_> = serde_json::from_value(json!({
+            "type": "response",
+            "status": "success",
+            "result": {
+                "account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                "ledger_index_min": 1,
+                "ledger_index_max": 500,
+                "marker": { "ledger": 480, "seq": 3 },
+                "transactions": [],
+            }
+        }))
+        .unwrap();
+        let page = AccountTxRequest::into_page(response).unwrap();
+        assert_eq!(page.ledger_index, Some(500));
+
+        let mut req =
+            AccountTxRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        req.pin_ledger(500);
+        req.set_marker(page.marker.unwrap());
+        assert_eq!(req.ledger_index_max, Some(500));
+        assert_eq!(req.marker, Some(json!({ "ledger": 480, "seq": 3 })));
+    }
+
+    /// A request that names its ledger by hash is already consistent and is
+    /// left unchanged.
+    #[test]
+    fn ledger_hash_is_not_overridden() {
+        let mut req =
+            AccountLinesRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")
+                .with_ledger_hash("AB".repeat(32));
+        req.pin_ledger(100);
+        assert_eq!(req.ledger_index, None);
+
+        let mut req =
+            AccountLinesRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")
+                .with_ledger_index("validated");
+        req.pin_ledger(100);
+        assert_eq!(req.ledger_index, Some(json!(100)));
+    }
+}
@@ -0,0 +1,340 @@
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::watch;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::config::ClientConfig;
+use crate::error::XrplError;
+use crate::request::server_info::ServerInfoRequest;
+use crate::request::{XrplRequest, XrplResponse};
+
+/// Validated ledgers an endpoint may trail the most advanced one before it
+/// is considered unhealthy.
+const MAX_LEDGER_LAG: u32 = 3;
+
+/// Server states in which rippled is synced with the network.
+const SYNCED_STATES: [&str; 3] = ["full", "proposing", "validating"];
+
+/// Last known health of one endpoint, as seen by [`crate::Client::endpoint_health`].
+///
+/// Updated by the periodic `server_info` probe and by request failures.
+///
+/// # Example
+/// ```no_run
+/// use xrpl::{Client, ClientConfig};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::with_endpoints(
+///         ["wss://xrplcluster.com", "wss://s2.ripple.com"],
+///         ClientConfig::default(),
+///     );
+///     for endpoint in client.endpoint_health() {
+///         println!("{} healthy={}", endpoint.url, endpoint.healthy);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EndpointHealth {
+    /// WebSocket URL of the endpoint.
+    pub url: String,
+    /// Whether the last probe succeeded and the server reported a synced state.
+    pub healthy: bool,
+    /// Round-trip time of the last successful `server_info` probe.
+    pub latency: Option<Duration>,
+    /// Sequence of the latest validated ledger reported by the endpoint.
+    pub validated_ledger: Option<u32>,
+    /// Consecutive failures since the last successful probe.
+    pub failures: u32,
+}
+
+impl EndpointHealth {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            healthy: true,
+            latency: None,
+            validated_ledger: None,
+            failures: 0,
+        }
+    }
+}
+
+/// Shared list of endpoints and their health, consulted by every connection
+/// driver on (re)connect to pick the endpoint to use.
+pub(crate) struct EndpointPool {
+    health: Mutex<Vec<EndpointHealth>>,
+    failover: watch::Sender<u64>,
+}
+
+impl EndpointPool {
+    pub(crate) fn new(urls: Vec<String>) -> Arc<Self> {
+        let health = urls.into_iter().map(EndpointHealth::new).collect();
+        let (failover, _) = watch::channel(0);
+        Arc::new(Self { health: Mutex::new(health), failover })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub(crate) fn url(&self, index: usize) -> String {
+        self.lock()[index].url.clone()
+    }
+
+    pub(crate) fn snapshot(&self) -> Vec<EndpointHealth> {
+        self.lock().clone()
+    }
+
+    /// Notified whenever an endpoint is marked unhealthy.
+    pub(crate) fn watch(&self) -> watch::Receiver<u64> {
+        self.failover.subscribe()
+    }
+
+    /// Index of the healthiest endpoint. Ties go to the endpoint listed first.
+    pub(crate) fn best(&self) -> usize {
+        let health = self.lock();
+        let tip = health.iter().filter_map(|h| h.validated_ledger).max();
+        health
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, h)| {
+                (
+                    !is_usable(h, tip),
+                    h.failures,
+                    h.latency.unwrap_or(Duration::MAX),
+                )
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn is_healthy(&self, index: usize) -> bool {
+        let health = self.lock();
+        let tip = health.iter().filter_map(|h| h.validated_ledger).max();
+        is_usable(&health[index], tip)
+    }
+
+    pub(crate) fn report_success(
+        &self,
+        index: usize,
+        latency: Duration,
+        synced: bool,
+        validated_ledger: Option<u32>,
+    ) {
+        let mut health = self.lock();
+        let endpoint = &mut health[index];
+        endpoint.healthy = synced;
+        endpoint.latency = Some(latency);
+        endpoint.validated_ledger = validated_ledger;
+        endpoint.failures = 0;
+        drop(health);
+        if !synced {
+            self.notify();
+        }
+    }
+
+    /// A driver connected to `index`: it is reachable again. Drivers on
+    /// other endpoints have nothing to react to, so nobody is notified.
+    pub(crate) fn report_connected(&self, index: usize) {
+        let mut health = self.lock();
+        let endpoint = &mut health[index];
+        endpoint.healthy = true;
+        endpoint.failures = 0;
+    }
+
+    pub(crate) fn report_failure(&self, index: usize) {
+        let mut health = self.lock();
+        let endpoint = &mut health[index];
+        endpoint.healthy = false;
+        endpoint.failures = endpoint.failures.saturating_add(1);
+        drop(health);
+        self.notify();
+    }
+
+    /// Only meaningful with an alternative to fail over to.
+    fn notify(&self) {
+        if self.len() > 1 {
+            self.failover.send_modify(|generation| *generation += 1);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<EndpointHealth>> {
+        self.health.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn is_usable(health: &EndpointHealth, tip: Option<u32>) -> bool {
+    let lagging = match (health.validated_ledger, tip) {
+        (Some(seq), Some(tip)) => tip - seq > MAX_LEDGER_LAG,
+        _ => false,
+    };
+    health.healthy && !lagging
+}
+
+/// Probes every endpoint with `server_info` on `health_check_interval`
+/// until the last [`EndpointPool`] reference is dropped.
+pub(crate) fn spawn_health_checks(
+    pool: Weak<EndpointPool>,
+    config: ClientConfig,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(config.health_check_interval);
+        loop {
+            interval.tick().await;
+            let Some(pool) = pool.upgrade() else { break };
+            for index in 0..pool.len() {
+                let url = pool.url(index);
+                match probe(&url, config.request_timeout).await {
+                    Ok((latency, info)) => pool.report_success(
+                        index,
+                        latency,
+                        info.is_synced(),
+                        info.validated_ledger.map(|l| l.seq),
+                    ),
+                    Err(e) => {
+                        eprintln!("Health check failed for {url}: {e}");
+                        pool.report_failure(index);
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[derive(Debug, Deserialize)]
+struct ProbeResult {
+    info: ProbeInfo,
+}
+
+/// The subset of `server_info` needed to judge health. Clio omits
+/// `server_state`, so only the validated ledger is required there.
+#[derive(Debug, Deserialize)]
+struct ProbeInfo {
+    server_state: Option<String>,
+    validated_ledger: Option<ProbeLedger>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProbeLedger {
+    seq: u32,
+}
+
+impl ProbeInfo {
+    fn is_synced(&self) -> bool {
+        let state_ok = match &self.server_state {
+            Some(state) => SYNCED_STATES.contains(&state.as_str()),
+            None => true,
+        };
+        state_ok && self.validated_ledger.is_some()
+    }
+}
+
+/// Opens a short-lived connection, sends `server_info`, and measures the round trip.
+async fn probe(
+    url: &str,
+    deadline: Duration,
+) -> Result<(Duration, ProbeInfo), XrplError> {
+    let mut request = ServerInfoRequest::new().to_value()?;
+    request["id"] = 1.into();
+    let start = Instant::now();
+
+    let exchange = async {
+        let (mut ws, _) = connect_async(url).await?;
+        ws.send(Message::Text(request.to_string().into())).await?;
+        while let Some(msg) = ws.next().await {
+            let Message::Text(json) = msg? else { continue };
+            let value: Value = serde_json::from_str(&json)
+                .map_err(|e| XrplError::ParseError(e.to_string()))?;
+            if value["id"] == 1 {
+                let _ = ws.close(None).await;
+                return Ok(value);
+            }
+        }
+        Err(XrplError::Disconnected)
+    };
+
+    let value = tokio::time::timeout(deadline, exchange)
+        .await
+        .map_err(|_| XrplError::Timeout(deadline.as_millis() as u64))??;
+    let response: XrplResponse<ProbeResult> = serde_json::from_value(value)
+        .map_err(|e| XrplError::ParseError(e.to_string()))?;
+
+    Ok((start.elapsed(), response.result()?.info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(n: usize) -> Arc<EndpointPool> {
+        EndpointPool::new((0..n).map(|i| format!("wss://node{i}")).collect())
+    }
+
+    /// With no health information yet, the first listed endpoint wins.
+    #[test]
+    fn best_prefers_first_endpoint_by_default() {
+        assert_eq!(pool(3).best(), 0);
+    }
+
+    /// A failed endpoint is skipped in favour of the next healthy one, and
+    /// comes back once a probe succeeds again.
+    #[test]
+    fn failure_moves_best_to_next_endpoint() {
+        let pool = pool(2);
+        pool.report_failure(0);
+        assert_eq!(pool.best(), 1);
+        assert!(!pool.is_healthy(0));
+
+        pool.report_success(0, Duration::from_millis(5), true, Some(100));
+        pool.report_success(1, Duration::from_millis(50), true, Some(100));
+        assert_eq!(pool.best(), 0);
+    }
+
+    /// An endpoint whose validated ledger trails the others is unhealthy
+    /// even if its probe succeeded quickly.
+    #[test]
+    fn lagging_endpoint_is_not_preferred() {
+        let pool = pool(2);
+        pool.report_success(0, Duration::from_millis(5), true, Some(90));
+        pool.report_success(1, Duration::from_millis(50), true, Some(100));
+        assert_eq!(pool.best(), 1);
+        assert!(!pool.is_healthy(0));
+    }
+
+    /// Marking an endpoint unhealthy wakes drivers watching for failover.
+    #[test]
+    fn failure_notifies_watchers() {
+        let pool = pool(2);
+        let mut rx = pool.watch();
+        pool.report_failure(1);
+        assert!(rx.has_changed().unwrap());
+        let _ = rx.borrow_and_update();
+
+        // A single endpoint has nowhere to fail over to.
+        let single = self::pool(1);
+        let rx = single.watch();
+        single.report_failure(0);
+        assert!(!rx.has_changed().unwrap());
+    }
+
+    /// Reconnecting to a failed endpoint restores its health without waking
+    /// the other drivers, so one failure cannot cascade into teardowns.
+    #[test]
+    fn reconnect_restores_health_silently() {
+        let pool = pool(2);
+        let mut rx = pool.watch();
+        pool.report_failure(0);
+        pool.report_failure(0);
+        let _ = rx.borrow_and_update();
+
+        pool.report_connected(0);
+        assert!(pool.is_healthy(0));
+        assert_eq!(pool.snapshot()[0].failures, 0);
+        assert!(!rx.has_changed().unwrap());
+    }
+}
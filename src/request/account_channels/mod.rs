@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 
-use super::{XrplRequest, XrplResponse};
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
 
 /// Retrieves all open payment channels where the specified account is the source.
 ///
@@ -84,6 +85,31 @@ impl XrplRequest for AccountChannelsRequest {
     const COMMAND: &str = "account_channels";
 }
 
+impl Paginated for AccountChannelsRequest {
+    type Item = AccountChannel;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() {
+            self.ledger_index = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<AccountChannel>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp.ledger_index,
+            marker: resp.marker,
+            items: resp.channels,
+        })
+    }
+}
+
 /// Response payload for an [`AccountChannelsRequest`].
 ///
 /// Contains the list of open payment channels owned by the queried account
@@ -3,7 +3,7 @@ use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use super::{XrplRequest, XrplResponse};
-use crate::types::AccountFlags;
+use crate::types::{AccountFlags, SignerList};
 
 /// Retrieves core account state: XRP balance, sequence number, flags, and owner count.
 ///
@@ -101,7 +101,7 @@ pub struct AccountInfoResponse {
     /// The account's ledger object containing balance, flags, and sequence number.
     pub account_data: AccountRoot,
     /// Signer lists attached to the account; populated when `signer_lists` was `true`.
-    pub signer_lists: Option<Vec<String>>,
+    pub signer_lists: Option<Vec<SignerList>>,
     /// Sequence number of the current open ledger (present when querying the open ledger).
     pub ledger_current_index: Option<u32>,
     /// Sequence number of the validated ledger used to answer the request.
@@ -118,6 +118,13 @@ pub struct AccountInfoResponse {
 /// XRP balance, current sequence number, and owner count used to calculate reserves.
 /// Fields are PascalCase on the wire (`Account`, `Balance`, `Flags`, ...).
 ///
+/// Also returned as [`AccountObject::AccountRoot`] by the ledger object
+/// requests and in transaction metadata. Metadata omits fields that hold
+/// their default value and those carried on the affected node; they
+/// deserialize as zero or empty.
+///
+/// [`AccountObject::AccountRoot`]: crate::types::AccountObject::AccountRoot
+///
 /// # Examples
 ///
 /// ```rust
@@ -127,7 +134,8 @@ pub struct AccountInfoResponse {
 ///     root.balance.parse::<u64>().unwrap_or(0).saturating_sub(reserve_drops)
 /// }
 /// ```
-#[derive(Debug, Clone, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AccountRoot {
     /// XRPL account address (r-address, base58check encoded). Wire: `Account`.
@@ -135,20 +143,25 @@ pub struct AccountRoot {
     /// XRP balance in drops as a string. Wire: `Balance`.
     pub balance: String,
     /// Active account flags. Wire: `Flags`.
+    #[serde(default)]
     pub flags: AccountFlags,
     /// Always `"AccountRoot"`. Wire: `LedgerEntryType`.
     pub ledger_entry_type: String,
     /// Number of objects the account owns (affects reserve). Wire: `OwnerCount`.
+    #[serde(default)]
     pub owner_count: u32,
     /// Transaction ID of the last transaction that modified this account. Wire: `PreviousTxnID`.
-    #[serde(rename = "PreviousTxnID")]
+    #[serde(rename = "PreviousTxnID", default)]
     pub previous_txn_id: String,
     /// Ledger sequence containing the last modifying transaction. Wire: `PreviousTxnLgrSeq`.
+    #[serde(default)]
     pub previous_txn_lgr_seq: u32,
+    /// Address of the account's regular key pair, when one is set. Wire: `RegularKey`.
+    pub regular_key: Option<String>,
     /// Next valid sequence number for transactions from this account. Wire: `Sequence`.
     pub sequence: u32,
     /// Ledger object index (SHA-512Half of account ID). Wire: `index`.
-    #[serde(rename = "index")]
+    #[serde(rename = "index", default)]
     pub index: String,
 }
 
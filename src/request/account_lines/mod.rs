@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 
-use super::{XrplRequest, XrplResponse};
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
 
 /// Retrieves trust lines (IOU balances) for an account.
 ///
@@ -90,6 +91,31 @@ impl XrplRequest for AccountLinesRequest {
     const COMMAND: &str = "account_lines";
 }
 
+impl Paginated for AccountLinesRequest {
+    type Item = Trustline;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() {
+            self.ledger_index = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<Trustline>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp.ledger_index,
+            marker: resp.marker,
+            items: resp.lines,
+        })
+    }
+}
+
 /// Response payload for an [`AccountLinesRequest`].
 ///
 /// Contains the page of trust lines for the queried account along with ledger
@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 
-use super::{XrplRequest, XrplResponse};
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
 
 /// Retrieves NFTokens owned by an account (XLS-20).
 ///
@@ -74,6 +75,31 @@ impl XrplRequest for AccountNftsRequest {
     const COMMAND: &str = "account_nfts";
 }
 
+impl Paginated for AccountNftsRequest {
+    type Item = AccountNFToken;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() {
+            self.ledger_index = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<AccountNFToken>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp.ledger_index,
+            marker: resp.marker,
+            items: resp.account_nfts,
+        })
+    }
+}
+
 /// Response payload for an [`AccountNftsRequest`].
 ///
 /// Contains the page of NFTokens owned by the queried account along with ledger
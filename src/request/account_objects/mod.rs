@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 
-use super::{XrplRequest, XrplResponse};
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
 use crate::types::AccountObject;
 
 /// Retrieves all ledger objects owned by an account.
@@ -97,6 +98,31 @@ impl XrplRequest for AccountObjectsRequest {
     const COMMAND: &str = "account_objects";
 }
 
+impl Paginated for AccountObjectsRequest {
+    type Item = AccountObject;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() {
+            self.ledger_index = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<AccountObject>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp.ledger_index,
+            marker: resp.marker,
+            items: resp.account_objects,
+        })
+    }
+}
+
 /// Filter for [`AccountObjectsRequest`] restricting results to one ledger object type.
 ///
 /// Wire values are snake_case strings (e.g. `"offer"`, `"payment_channel"`).
@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 
-use super::{XrplRequest, XrplResponse};
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
 use crate::types::Amount;
 
 /// Retrieves open DEX limit orders (offers) placed by an account.
@@ -74,6 +75,31 @@ impl XrplRequest for AccountOffersRequest {
     const COMMAND: &str = "account_offers";
 }
 
+impl Paginated for AccountOffersRequest {
+    type Item = AccountOffer;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() {
+            self.ledger_index = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<AccountOffer>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp.ledger_index,
+            marker: resp.marker,
+            items: resp.offers,
+        })
+    }
+}
+
 /// Response payload for an [`AccountOffersRequest`].
 ///
 /// Contains the page of open DEX offers for the queried account along with ledger
@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 
-use super::{XrplRequest, XrplResponse};
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
 use crate::types::{HasTransactionMeta, TransactionMeta};
 
 /// Retrieves the transaction history for an account.
@@ -119,6 +120,35 @@ impl XrplRequest for AccountTxRequest {
     const COMMAND: &str = "account_tx";
 }
 
+impl Paginated for AccountTxRequest {
+    type Item = AccountTransaction;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    /// Caps `ledger_index_max` so transactions validated after the first
+    /// page do not shift later pages.
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() && self.ledger_index.is_none() {
+            self.ledger_index_max = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<AccountTransaction>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp
+                .ledger_index_max
+                .and_then(|i| u32::try_from(i).ok()),
+            marker: resp.marker,
+            items: resp.transactions,
+        })
+    }
+}
+
 /// Response payload for an [`AccountTxRequest`].
 ///
 /// Contains the page of transactions for the queried account along with the actual
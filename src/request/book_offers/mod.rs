@@ -3,7 +3,7 @@ use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use super::{XrplRequest, XrplResponse};
-use crate::types::{Amount, Asset};
+use crate::types::{Amount, Asset, Offer};
 
 /// Retrieves a list of offers between two assets from the order book.
 ///
@@ -131,12 +131,13 @@ pub struct BookOffersResponse {
 // core fields, while rippled adds computed extras (quality, owner_funds, etc.)
 // in snake_case.
 /// A single offer entry from an order book, including rippled-computed quality fields.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BookOffer {
     /// Account that placed the offer.
     pub account: String,
-    /// Offer flags bit field.
+    /// Offer flags bit field. Metadata omits it when zero.
+    #[serde(default)]
     pub flags: u32,
     /// Sequence number that identifies the offer on the ledger.
     pub sequence: u32,
@@ -150,6 +151,9 @@ pub struct BookOffer {
     pub book_node: Option<String>,
     /// Ripple epoch timestamp after which the offer expires.
     pub expiration: Option<u32>,
+    /// Ledger entry ID of the offer.
+    #[serde(alias = "LedgerIndex", rename = "index")]
+    pub index: Option<String>,
     // Computed extras returned in snake_case
     /// Exchange rate (taker_pays / taker_gets), higher is better for the taker.
     #[serde(rename = "quality")]
@@ -164,3 +168,24 @@ pub struct BookOffer {
     #[serde(rename = "taker_pays_funded")]
     pub taker_pays_funded: Option<Amount>,
 }
+
+/// An `Offer` ledger object as a book entry, without the computed fields.
+impl From<&Offer> for BookOffer {
+    fn from(offer: &Offer) -> Self {
+        Self {
+            account: offer.account.clone(),
+            flags: offer.common.flags,
+            sequence: offer.sequence,
+            taker_gets: offer.taker_gets.clone(),
+            taker_pays: offer.taker_pays.clone(),
+            book_directory: Some(offer.book_directory.clone()),
+            book_node: Some(offer.book_node.clone()),
+            expiration: offer.expiration,
+            index: offer.common.index.clone(),
+            quality: None,
+            owner_funds: None,
+            taker_gets_funded: None,
+            taker_pays_funded: None,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use super::{XrplRequest, XrplResponse};
+use crate::channel::Claim;
+
+/// Asks the server to check the signature on a payment channel claim.
+///
+/// With the `wallet` feature, `channel::verify_claim` does the same check
+/// locally.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ChannelVerifyRequest {
+    /// Cumulative amount the claim authorizes, in drops.
+    pub amount: String,
+    /// 64-character hex ID of the channel.
+    pub channel_id: String,
+    /// Public key of the channel's key pair, hex or base58.
+    pub public_key: String,
+    /// Hex signature of the claim.
+    pub signature: String,
+}
+
+impl ChannelVerifyRequest {
+    /// Creates a new request for the given claim fields.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::channel_verify::ChannelVerifyRequest;
+    /// let req = ChannelVerifyRequest::new(
+    ///     "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3",
+    ///     1_000_000,
+    ///     "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3",
+    ///     "304402204EF0AFB78AC23ED1C472E74F4299C0C21F1B21D07EFC0A3838A420F76D783A400220154FB11B6F54320666E4C36CA7F686C16A3A0456800BBC43746F34AF50290064",
+    /// );
+    /// ```
+    pub fn new(
+        channel_id: impl AsRef<str>,
+        drops: u64,
+        public_key: impl AsRef<str>,
+        signature: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            amount: drops.to_string(),
+            channel_id: channel_id.as_ref().to_string(),
+            public_key: public_key.as_ref().to_string(),
+            signature: signature.as_ref().to_string(),
+        }
+    }
+
+    /// Creates a request that checks `claim` against the channel's
+    /// `public_key`.
+    pub fn from_claim(claim: &Claim, public_key: impl AsRef<str>) -> Self {
+        Self::new(&claim.channel_id, claim.amount, public_key, &claim.signature)
+    }
+}
+
+impl XrplRequest for ChannelVerifyRequest {
+    type Response = XrplResponse<ChannelVerifyResponse>;
+    const COMMAND: &str = "channel_verify";
+}
+
+/// Response to a `channel_verify` request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChannelVerifyResponse {
+    /// Whether the signature is valid for the claimed amount and channel.
+    pub signature_verified: bool,
+}
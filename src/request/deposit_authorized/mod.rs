@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use super::{XrplRequest, XrplResponse};
+
+/// Checks whether one account may send payments directly to another, which
+/// only fails when the destination has `DepositAuth` set.
+///
+/// Pass the credentials the payment will carry with
+/// [`with_credential`](Self::with_credential); the server then also checks
+/// they exist, are accepted, unexpired and held by the source.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DepositAuthorizedRequest {
+    /// Account that would send the payment (r-address).
+    pub source_account: String,
+    /// Account that would receive the payment (r-address).
+    pub destination_account: String,
+    /// 64-character hex IDs of `Credential` entries the payment would carry.
+    pub credentials: Option<Vec<String>>,
+    /// Ledger hash to target a specific ledger version.
+    pub ledger_hash: Option<String>,
+    /// Ledger index or shortcut ("validated", "closed", "current").
+    pub ledger_index: Option<Value>,
+}
+
+impl DepositAuthorizedRequest {
+    /// Creates a new request for the given source and destination.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::deposit_authorized::DepositAuthorizedRequest;
+    /// let req = DepositAuthorizedRequest::new(
+    ///     "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+    ///     "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+    /// )
+    /// .with_credential("A182EFBD154C9E80195082F86C1FF8A6D8C7D6D38C87A28FB2E6C91CFD9DCB5A")
+    /// .with_ledger_index("validated");
+    /// ```
+    pub fn new(
+        source_account: impl AsRef<str>,
+        destination_account: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            source_account: source_account.as_ref().to_string(),
+            destination_account: destination_account.as_ref().to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds the ID of a `Credential` entry the payment would carry.
+    pub fn with_credential(mut self, credential_id: impl AsRef<str>) -> Self {
+        self.credentials
+            .get_or_insert_with(Vec::new)
+            .push(credential_id.as_ref().to_string());
+        self
+    }
+
+    /// Sets the ledger index or shortcut to query ("validated", "closed", "current", or a number).
+    pub fn with_ledger_index(mut self, index: impl Into<Value>) -> Self {
+        self.ledger_index = Some(index.into());
+        self
+    }
+
+    /// Targets a specific ledger version by its 64-character hex hash.
+    pub fn with_ledger_hash(mut self, hash: impl AsRef<str>) -> Self {
+        self.ledger_hash = Some(hash.as_ref().to_string());
+        self
+    }
+}
+
+impl XrplRequest for DepositAuthorizedRequest {
+    type Response = XrplResponse<DepositAuthorizedResponse>;
+    const COMMAND: &str = "deposit_authorized";
+}
+
+/// Response to a `deposit_authorized` request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DepositAuthorizedResponse {
+    /// Whether the source may send payments to the destination.
+    pub deposit_authorized: bool,
+    /// Account that would send the payment.
+    pub source_account: String,
+    /// Account that would receive the payment.
+    pub destination_account: String,
+    /// Credential IDs the check was made with, echoed from the request.
+    pub credentials: Option<Vec<String>>,
+    /// Hash of the ledger version used.
+    pub ledger_hash: Option<String>,
+    /// Sequence number of the ledger version used.
+    pub ledger_index: Option<u32>,
+    /// Sequence number of the current open ledger (unvalidated results).
+    pub ledger_current_index: Option<u32>,
+    /// Whether the data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use super::{XrplRequest, XrplResponse};
+
+/// Retrieves the total obligations of an issuing account, and the balances
+/// its operational (hot) wallets hold.
+///
+/// Balances held by the accounts passed to
+/// [`with_hotwallet`](Self::with_hotwallet) are reported separately and do
+/// not count as obligations.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GatewayBalancesRequest {
+    /// Issuing account (r-address).
+    pub account: String,
+    /// When `true`, only accept an address or public key for `account`.
+    pub strict: Option<bool>,
+    /// Operational addresses whose balances are excluded from obligations.
+    pub hotwallet: Option<Vec<String>>,
+    /// Ledger hash to target a specific ledger version.
+    pub ledger_hash: Option<String>,
+    /// Ledger index or shortcut ("validated", "closed", "current").
+    pub ledger_index: Option<Value>,
+}
+
+impl GatewayBalancesRequest {
+    /// Creates a new request for the given issuer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::gateway_balances::GatewayBalancesRequest;
+    /// let req = GatewayBalancesRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")
+    ///     .with_hotwallet("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe")
+    ///     .with_ledger_index("validated");
+    /// ```
+    pub fn new(account: impl AsRef<str>) -> Self {
+        Self { account: account.as_ref().to_string(), ..Default::default() }
+    }
+
+    /// Sets whether only an address or public key is accepted for `account`.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+
+    /// Adds an operational address whose balances are excluded from
+    /// obligations.
+    pub fn with_hotwallet(mut self, address: impl AsRef<str>) -> Self {
+        self.hotwallet
+            .get_or_insert_with(Vec::new)
+            .push(address.as_ref().to_string());
+        self
+    }
+
+    /// Sets the ledger index or shortcut to query ("validated", "closed", "current", or a number).
+    pub fn with_ledger_index(mut self, index: impl Into<Value>) -> Self {
+        self.ledger_index = Some(index.into());
+        self
+    }
+
+    /// Targets a specific ledger version by its 64-character hex hash.
+    pub fn with_ledger_hash(mut self, hash: impl AsRef<str>) -> Self {
+        self.ledger_hash = Some(hash.as_ref().to_string());
+        self
+    }
+}
+
+impl XrplRequest for GatewayBalancesRequest {
+    type Response = XrplResponse<GatewayBalancesResponse>;
+    const COMMAND: &str = "gateway_balances";
+}
+
+/// Response to a `gateway_balances` request.
+///
+/// Maps are empty when the issuer has nothing to report in them.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GatewayBalancesResponse {
+    /// Issuing account the balances are for.
+    pub account: String,
+    /// Total issued per currency code, excluding hot wallet balances.
+    #[serde(default)]
+    pub obligations: BTreeMap<String, String>,
+    /// Balances held by each requested hot wallet, by address.
+    #[serde(default)]
+    pub balances: BTreeMap<String, Vec<GatewayBalance>>,
+    /// Frozen balances held by other accounts, by address.
+    #[serde(default)]
+    pub frozen_balances: BTreeMap<String, Vec<GatewayBalance>>,
+    /// Tokens of other issuers held by the account, by issuer address.
+    #[serde(default)]
+    pub assets: BTreeMap<String, Vec<GatewayBalance>>,
+    /// Hash of the ledger version used.
+    pub ledger_hash: Option<String>,
+    /// Sequence number of the ledger version used.
+    pub ledger_index: Option<u32>,
+    /// Sequence number of the current open ledger (unvalidated results).
+    pub ledger_current_index: Option<u32>,
+    /// Whether the data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+/// An amount of one currency in a [`GatewayBalancesResponse`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GatewayBalance {
+    /// Currency code.
+    pub currency: String,
+    /// Amount as a decimal string.
+    pub value: String,
+}
@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 
-use super::{XrplRequest, XrplResponse};
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
+use crate::types::AccountObject;
 
 /// Returns all ledger objects in a given ledger version, paginated by marker.
 ///
@@ -77,6 +79,31 @@ impl XrplRequest for LedgerDataRequest {
     const COMMAND: &str = "ledger_data";
 }
 
+impl Paginated for LedgerDataRequest {
+    type Item = AccountObject;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() {
+            self.ledger_index = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<AccountObject>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: Some(resp.ledger_index),
+            marker: resp.marker,
+            items: resp.state,
+        })
+    }
+}
+
 /// Response to a `ledger_data` request.
 #[derive(Debug, Deserialize)]
 pub struct LedgerDataResponse {
@@ -88,6 +115,7 @@ pub struct LedgerDataResponse {
     pub ledger_index: u32,
     /// Marker for the next page. Absent when the last page has been returned.
     pub marker: Option<Value>,
-    /// Ledger entry objects. Each entry includes an `index` field with the entry hash.
-    pub state: Vec<Value>,
+    /// Ledger entry objects, each with its hash in `index`. Entries returned
+    /// in binary form are [`AccountObject::Unknown`] with an empty type.
+    pub state: Vec<AccountObject>,
 }
@@ -3,7 +3,7 @@ use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use super::{XrplRequest, XrplResponse};
-use crate::types::Asset;
+use crate::types::{AccountObject, Asset};
 
 /// Retrieves a single ledger entry by its identifying key.
 ///
@@ -281,7 +281,7 @@ pub struct LedgerEntryResponse {
     /// Hash of the ledger version used.
     pub ledger_hash: Option<String>,
     /// The ledger entry in JSON format. `None` when `binary` is `true`.
-    pub node: Option<Value>,
+    pub node: Option<AccountObject>,
     /// The ledger entry in binary format. `None` when `binary` is `false`.
     pub node_binary: Option<String>,
     /// Whether the data comes from a validated ledger.
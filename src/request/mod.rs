@@ -18,8 +18,14 @@ pub mod account_tx;
 pub mod amm_info;
 /// Request and response types for the `book_offers` command.
 pub mod book_offers;
+/// Request and response types for the `channel_verify` command.
+pub mod channel_verify;
+/// Request and response types for the `deposit_authorized` command.
+pub mod deposit_authorized;
 /// Request and response types for the `fee` command.
 pub mod fee;
+/// Request and response types for the `gateway_balances` command.
+pub mod gateway_balances;
 /// Request and response types for the `ledger` command.
 pub mod ledger;
 /// Request and response types for the `ledger_closed` command.
@@ -30,10 +36,20 @@ pub mod ledger_current;
 pub mod ledger_data;
 /// Request and response types for the `ledger_entry` command.
 pub mod ledger_entry;
+/// Request and response types for the `mpt_holders` command (Clio only).
+pub mod mpt_holders;
 /// Request and response types for the `nft_buy_offers` command.
 pub mod nft_buy_offers;
+/// Request and response types for the `nft_history` command (Clio only).
+pub mod nft_history;
+/// Request and response types for the `nft_info` command (Clio only).
+pub mod nft_info;
 /// Request and response types for the `nft_sell_offers` command.
 pub mod nft_sell_offers;
+/// Request and response types for the `nfts_by_issuer` command (Clio only).
+pub mod nfts_by_issuer;
+/// Request and response types for the `noripple_check` command.
+pub mod noripple_check;
 /// Request and response types for the `ripple_path_find` command.
 pub mod ripple_path_find;
 /// Request and response types for the `server_info` command.
@@ -113,6 +129,50 @@ pub trait XrplSubscription: XrplRequest {
     const MESSAGE_TYPE: &'static str;
 }
 
+/// Implemented by requests whose results span several pages linked by a `marker`.
+///
+/// [`crate::Client::paginate`] uses it to carry the marker from each response
+/// into the next request and to pin every page to the ledger of the first one.
+///
+/// # Examples
+///
+/// ```rust
+/// use xrpl::request::Paginated;
+/// use xrpl::request::account_lines::AccountLinesRequest;
+///
+/// let mut req = AccountLinesRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+/// req.pin_ledger(90_000_000);
+/// assert_eq!(req.ledger_index, Some(90_000_000.into()));
+/// ```
+pub trait Paginated: XrplRequest + Send + Sync + 'static {
+    /// A single entry of a page (e.g. a trust line, an object, a transaction).
+    type Item: Send + 'static;
+
+    /// Sets the marker returned by the previous page.
+    fn set_marker(&mut self, marker: Value);
+
+    /// Restricts later pages to the ledger the first page was read from.
+    ///
+    /// Does nothing when the request already names a ledger by hash.
+    fn pin_ledger(&mut self, ledger_index: u32);
+
+    /// Splits a response into its items, the next marker, and its ledger.
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<Self::Item>, XrplError>;
+}
+
+/// One page of a [`Paginated`] response.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// Entries on this page.
+    pub items: Vec<T>,
+    /// Cursor for the next page; `None` on the last page.
+    pub marker: Option<Value>,
+    /// Sequence of the ledger the page was read from, if the server reported one.
+    pub ledger_index: Option<u32>,
+}
+
 /// Top-level envelope for every response from the rippled server.
 ///
 /// Deserializes into either [`XrplResponse::Success`] (carrying the typed result) or
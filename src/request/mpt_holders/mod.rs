@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
+
+/// Retrieves every account that holds a given MPT issuance. *Clio server only.*
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MptHoldersRequest {
+    /// 48-character hex ID of the MPT issuance.
+    pub mpt_issuance_id: String,
+    /// Ledger hash to target a specific ledger version.
+    pub ledger_hash: Option<String>,
+    /// Ledger index or shortcut ("validated", "closed", "current").
+    pub ledger_index: Option<Value>,
+    /// Maximum number of holders per page.
+    pub limit: Option<u32>,
+    /// Opaque pagination cursor from a previous response; omit for the first page.
+    pub marker: Option<Value>,
+}
+
+impl MptHoldersRequest {
+    /// Creates a new request for the given MPT issuance ID.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::mpt_holders::MptHoldersRequest;
+    /// let req = MptHoldersRequest::new("0000012FFD9EE5DA93AC614B4DB94D7E0FCE415CA51BED47")
+    ///     .with_ledger_index("validated")
+    ///     .with_limit(200);
+    /// ```
+    pub fn new(mpt_issuance_id: impl AsRef<str>) -> Self {
+        Self {
+            mpt_issuance_id: mpt_issuance_id.as_ref().to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the ledger index or shortcut to query ("validated", "closed", "current", or a number).
+    pub fn with_ledger_index(mut self, index: impl Into<Value>) -> Self {
+        self.ledger_index = Some(index.into());
+        self
+    }
+
+    /// Targets a specific ledger version by its 64-character hex hash.
+    pub fn with_ledger_hash(mut self, hash: impl AsRef<str>) -> Self {
+        self.ledger_hash = Some(hash.as_ref().to_string());
+        self
+    }
+
+    /// Sets the maximum number of holders to return per page.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the opaque pagination cursor from a previous response.
+    pub fn with_marker(mut self, marker: impl Into<Value>) -> Self {
+        self.marker = Some(marker.into());
+        self
+    }
+}
+
+impl XrplRequest for MptHoldersRequest {
+    type Response = XrplResponse<MptHoldersResponse>;
+    const COMMAND: &str = "mpt_holders";
+}
+
+impl Paginated for MptHoldersRequest {
+    type Item = MptHolder;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() {
+            self.ledger_index = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<MptHolder>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp.ledger_index,
+            marker: resp.marker,
+            items: resp.mptokens,
+        })
+    }
+}
+
+/// Response to an `mpt_holders` request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MptHoldersResponse {
+    /// MPT issuance the holders are for.
+    pub mpt_issuance_id: String,
+    /// Holders of the issuance on this page.
+    pub mptokens: Vec<MptHolder>,
+    /// Sequence number of the ledger version used.
+    pub ledger_index: Option<u32>,
+    /// Whether the data comes from a validated ledger.
+    pub validated: Option<bool>,
+    /// Effective page size applied by the server.
+    pub limit: Option<u32>,
+    /// Opaque pagination cursor; present when more pages are available.
+    pub marker: Option<Value>,
+}
+
+/// One account's `MPToken` for the issuance, returned by `mpt_holders`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MptHolder {
+    /// Account holding the tokens (r-address).
+    pub account: String,
+    /// `MPToken` flags bit field (e.g. `lsfMPTLocked`, `lsfMPTAuthorized`).
+    pub flags: u32,
+    /// Balance held by the account (string-encoded u64).
+    pub mpt_amount: String,
+    /// Part of the balance locked in escrow (string-encoded u64), if any.
+    pub locked_amount: Option<String>,
+    /// Ledger index (ID) of the `MPToken` object.
+    pub mptoken_index: String,
+}
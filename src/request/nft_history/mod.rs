@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use super::account_tx::AccountTransaction;
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
+
+/// Retrieves every transaction that affected one NFToken, from mint to burn.
+/// *Clio server only.*
+///
+/// Use `ledger_index_min`/`ledger_index_max` to constrain the search window,
+/// `forward` to control chronological order, and `limit`/`marker` to paginate.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NftHistoryRequest {
+    /// 64-character hex NFToken ID whose history is queried.
+    pub nft_id: String,
+    /// Earliest ledger sequence to include; `-1` means the oldest available.
+    pub ledger_index_min: Option<i64>,
+    /// Latest ledger sequence to include; `-1` means the most recent validated ledger.
+    pub ledger_index_max: Option<i64>,
+    /// 64-hex-character hash identifying a single specific ledger to search.
+    pub ledger_hash: Option<String>,
+    /// Ledger to query: a sequence number, or a shortcut such as `"validated"`.
+    pub ledger_index: Option<Value>,
+    /// When `true`, return transactions as raw hex instead of decoded JSON.
+    pub binary: Option<bool>,
+    /// When `true`, return oldest transactions first (ascending order).
+    pub forward: Option<bool>,
+    /// Maximum number of transactions to return in a single response.
+    pub limit: Option<u32>,
+    /// Pagination cursor returned by a previous response; pass back to fetch the next page.
+    pub marker: Option<Value>,
+}
+
+impl NftHistoryRequest {
+    /// Creates a new request for the given NFToken ID.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::nft_history::NftHistoryRequest;
+    /// let req = NftHistoryRequest::new("00080000B4F4AFC5FBCBD76873F18006173D2193467D3EE70000099B00000000")
+    ///     .with_forward(true)
+    ///     .with_limit(50);
+    /// ```
+    pub fn new(nft_id: impl AsRef<str>) -> Self {
+        Self { nft_id: nft_id.as_ref().to_string(), ..Default::default() }
+    }
+
+    /// Sets the minimum ledger index to search.
+    pub fn with_ledger_index_min(mut self, min: i64) -> Self {
+        self.ledger_index_min = Some(min);
+        self
+    }
+
+    /// Sets the maximum ledger index to search.
+    pub fn with_ledger_index_max(mut self, max: i64) -> Self {
+        self.ledger_index_max = Some(max);
+        self
+    }
+
+    /// Sets the ledger hash to query.
+    pub fn with_ledger_hash(mut self, hash: impl AsRef<str>) -> Self {
+        self.ledger_hash = Some(hash.as_ref().to_string());
+        self
+    }
+
+    /// Sets the ledger index or shortcut to query.
+    pub fn with_ledger_index(mut self, index: impl Into<Value>) -> Self {
+        self.ledger_index = Some(index.into());
+        self
+    }
+
+    /// Sets whether to return transactions in binary format.
+    pub fn with_binary(mut self, binary: bool) -> Self {
+        self.binary = Some(binary);
+        self
+    }
+
+    /// Sets the direction of traversal.
+    pub fn with_forward(mut self, forward: bool) -> Self {
+        self.forward = Some(forward);
+        self
+    }
+
+    /// Sets the maximum number of transactions to return.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the pagination marker.
+    pub fn with_marker(mut self, marker: impl Into<Value>) -> Self {
+        self.marker = Some(marker.into());
+        self
+    }
+}
+
+impl XrplRequest for NftHistoryRequest {
+    type Response = XrplResponse<NftHistoryResponse>;
+    const COMMAND: &str = "nft_history";
+}
+
+impl Paginated for NftHistoryRequest {
+    type Item = AccountTransaction;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    /// Caps `ledger_index_max` so transactions validated after the first
+    /// page do not shift later pages.
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() && self.ledger_index.is_none() {
+            self.ledger_index_max = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(
+        response: Self::Response,
+    ) -> Result<Page<AccountTransaction>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp
+                .ledger_index_max
+                .and_then(|i| u32::try_from(i).ok()),
+            marker: resp.marker,
+            items: resp.transactions,
+        })
+    }
+}
+
+/// Response payload for an [`NftHistoryRequest`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct NftHistoryResponse {
+    /// NFToken ID whose history is returned.
+    pub nft_id: String,
+    /// Earliest ledger sequence actually searched (may differ from the requested value).
+    pub ledger_index_min: Option<i64>,
+    /// Latest ledger sequence actually searched (may differ from the requested value).
+    pub ledger_index_max: Option<i64>,
+    /// Pagination cursor; present when more transactions remain on the next page.
+    pub marker: Option<Value>,
+    /// Transactions affecting the NFToken within the searched ledger range.
+    pub transactions: Vec<AccountTransaction>,
+    /// `true` when the response is based on validated (immutable) ledgers only.
+    pub validated: Option<bool>,
+    /// Effective page size applied by the server.
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use super::{XrplRequest, XrplResponse};
+
+/// Retrieves the current state of one NFToken, including burned ones.
+/// *Clio server only.*
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NftInfoRequest {
+    /// 64-character hex NFToken ID to look up.
+    pub nft_id: String,
+    /// Ledger hash to target a specific ledger version.
+    pub ledger_hash: Option<String>,
+    /// Ledger index or shortcut ("validated", "closed", "current").
+    pub ledger_index: Option<Value>,
+}
+
+impl NftInfoRequest {
+    /// Creates a new request for the given NFToken ID.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::nft_info::NftInfoRequest;
+    /// let req = NftInfoRequest::new("00080000B4F4AFC5FBCBD76873F18006173D2193467D3EE70000099B00000000")
+    ///     .with_ledger_index("validated");
+    /// ```
+    pub fn new(nft_id: impl AsRef<str>) -> Self {
+        Self { nft_id: nft_id.as_ref().to_string(), ..Default::default() }
+    }
+
+    /// Sets the ledger index or shortcut to query ("validated", "closed", "current", or a number).
+    pub fn with_ledger_index(mut self, index: impl Into<Value>) -> Self {
+        self.ledger_index = Some(index.into());
+        self
+    }
+
+    /// Targets a specific ledger version by its 64-character hex hash.
+    pub fn with_ledger_hash(mut self, hash: impl AsRef<str>) -> Self {
+        self.ledger_hash = Some(hash.as_ref().to_string());
+        self
+    }
+}
+
+impl XrplRequest for NftInfoRequest {
+    type Response = XrplResponse<NftInfoResponse>;
+    const COMMAND: &str = "nft_info";
+}
+
+/// Response to an `nft_info` request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NftInfoResponse {
+    /// State of the NFToken.
+    #[serde(flatten)]
+    pub nft: NftInfo,
+    /// Whether the data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
+
+/// State of one NFToken as tracked by Clio, returned by `nft_info` and
+/// `nfts_by_issuer`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NftInfo {
+    /// 64-character hex NFToken ID.
+    pub nft_id: String,
+    /// Sequence number of the ledger the state was read from.
+    pub ledger_index: Option<u32>,
+    /// Account that holds the NFToken; the last holder if it was burned.
+    pub owner: String,
+    /// Whether the NFToken has been burned.
+    pub is_burned: bool,
+    /// NFToken flags bit field (e.g. `tfTransferable`).
+    pub flags: u32,
+    /// Fee charged on secondary sales, in units of 1/100,000.
+    pub transfer_fee: u16,
+    /// Account that minted the NFToken.
+    pub issuer: String,
+    /// Issuer-defined taxon that groups related tokens.
+    pub nft_taxon: u32,
+    /// Per-issuer serial number assigned at mint time.
+    pub nft_serial: u32,
+    /// Hex-encoded URI of the token's metadata; empty if none was set.
+    #[serde(default)]
+    pub uri: String,
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use super::nft_info::NftInfo;
+use super::{Page, Paginated, XrplRequest, XrplResponse};
+use crate::error::XrplError;
+
+/// Retrieves every NFToken minted by an account, optionally limited to one
+/// taxon. Burned tokens are included. *Clio server only.*
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NftsByIssuerRequest {
+    /// Account that minted the NFTokens (r-address).
+    pub issuer: String,
+    /// Return only NFTokens with this taxon.
+    pub nft_taxon: Option<u32>,
+    /// Ledger hash to target a specific ledger version.
+    pub ledger_hash: Option<String>,
+    /// Ledger index or shortcut ("validated", "closed", "current").
+    pub ledger_index: Option<Value>,
+    /// Maximum number of NFTokens per page.
+    pub limit: Option<u32>,
+    /// Opaque pagination cursor from a previous response; omit for the first page.
+    pub marker: Option<Value>,
+}
+
+impl NftsByIssuerRequest {
+    /// Creates a new request for the given issuer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::nfts_by_issuer::NftsByIssuerRequest;
+    /// let req = NftsByIssuerRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")
+    ///     .with_nft_taxon(0)
+    ///     .with_limit(100);
+    /// ```
+    pub fn new(issuer: impl AsRef<str>) -> Self {
+        Self { issuer: issuer.as_ref().to_string(), ..Default::default() }
+    }
+
+    /// Restricts the results to NFTokens with the given taxon.
+    pub fn with_nft_taxon(mut self, taxon: u32) -> Self {
+        self.nft_taxon = Some(taxon);
+        self
+    }
+
+    /// Sets the ledger index or shortcut to query ("validated", "closed", "current", or a number).
+    pub fn with_ledger_index(mut self, index: impl Into<Value>) -> Self {
+        self.ledger_index = Some(index.into());
+        self
+    }
+
+    /// Targets a specific ledger version by its 64-character hex hash.
+    pub fn with_ledger_hash(mut self, hash: impl AsRef<str>) -> Self {
+        self.ledger_hash = Some(hash.as_ref().to_string());
+        self
+    }
+
+    /// Sets the maximum number of NFTokens to return per page.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the opaque pagination cursor from a previous response.
+    pub fn with_marker(mut self, marker: impl Into<Value>) -> Self {
+        self.marker = Some(marker.into());
+        self
+    }
+}
+
+impl XrplRequest for NftsByIssuerRequest {
+    type Response = XrplResponse<NftsByIssuerResponse>;
+    const COMMAND: &str = "nfts_by_issuer";
+}
+
+impl Paginated for NftsByIssuerRequest {
+    type Item = NftInfo;
+
+    fn set_marker(&mut self, marker: Value) {
+        self.marker = Some(marker);
+    }
+
+    fn pin_ledger(&mut self, ledger_index: u32) {
+        if self.ledger_hash.is_none() {
+            self.ledger_index = Some(ledger_index.into());
+        }
+    }
+
+    fn into_page(response: Self::Response) -> Result<Page<NftInfo>, XrplError> {
+        let resp = response.result()?;
+        Ok(Page {
+            ledger_index: resp.ledger_index,
+            marker: resp.marker,
+            items: resp.nfts,
+        })
+    }
+}
+
+/// Response to an `nfts_by_issuer` request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NftsByIssuerResponse {
+    /// Account that minted the NFTokens.
+    pub issuer: String,
+    /// NFTokens minted by the issuer on this page.
+    pub nfts: Vec<NftInfo>,
+    /// Taxon the results were restricted to, if any.
+    pub nft_taxon: Option<u32>,
+    /// Sequence number of the ledger version used.
+    pub ledger_index: Option<u32>,
+    /// Whether the data comes from a validated ledger.
+    pub validated: Option<bool>,
+    /// Effective page size applied by the server.
+    pub limit: Option<u32>,
+    /// Opaque pagination cursor; present when more pages are available.
+    pub marker: Option<Value>,
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use super::{XrplRequest, XrplResponse};
+use crate::types::Transaction;
+
+/// Role the account plays, which decides the rippling settings
+/// `noripple_check` recommends.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoRippleRole {
+    /// An issuer: `DefaultRipple` on, `NoRipple` off on its trust lines.
+    Gateway,
+    /// A holder: `DefaultRipple` off, `NoRipple` on its trust lines.
+    #[default]
+    User,
+}
+
+/// Compares an account's `DefaultRipple` and `NoRipple` settings with the
+/// recommendations for its role and lists what to change.
+///
+/// With [`with_transactions`](Self::with_transactions) the response also
+/// carries the fix-up transactions; turn them into builders with
+/// [`TransactionBuilder::from_transaction`] to fill, sign and submit them.
+///
+/// [`TransactionBuilder::from_transaction`]: crate::types::builders::TransactionBuilder::from_transaction
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NoRippleCheckRequest {
+    /// Account to check (r-address).
+    pub account: String,
+    /// Role the account plays.
+    pub role: NoRippleRole,
+    /// When `true`, include suggested transactions that fix the problems.
+    pub transactions: Option<bool>,
+    /// Maximum number of trust line problems to report.
+    pub limit: Option<u32>,
+    /// Ledger hash to target a specific ledger version.
+    pub ledger_hash: Option<String>,
+    /// Ledger index or shortcut ("validated", "closed", "current").
+    pub ledger_index: Option<Value>,
+}
+
+impl NoRippleCheckRequest {
+    /// Creates a new request for the given account and role.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::noripple_check::{NoRippleCheckRequest, NoRippleRole};
+    /// let req = NoRippleCheckRequest::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh", NoRippleRole::Gateway)
+    ///     .with_transactions(true)
+    ///     .with_ledger_index("current");
+    /// ```
+    pub fn new(account: impl AsRef<str>, role: NoRippleRole) -> Self {
+        Self {
+            account: account.as_ref().to_string(),
+            role,
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether to include suggested fix-up transactions.
+    pub fn with_transactions(mut self, transactions: bool) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// Sets the maximum number of trust line problems to report.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the ledger index or shortcut to query ("validated", "closed", "current", or a number).
+    pub fn with_ledger_index(mut self, index: impl Into<Value>) -> Self {
+        self.ledger_index = Some(index.into());
+        self
+    }
+
+    /// Targets a specific ledger version by its 64-character hex hash.
+    pub fn with_ledger_hash(mut self, hash: impl AsRef<str>) -> Self {
+        self.ledger_hash = Some(hash.as_ref().to_string());
+        self
+    }
+}
+
+impl XrplRequest for NoRippleCheckRequest {
+    type Response = XrplResponse<NoRippleCheckResponse>;
+    const COMMAND: &str = "noripple_check";
+}
+
+/// Response to a `noripple_check` request.
+///
+/// # Examples
+///
+/// ```rust
+/// use xrpl::request::noripple_check::NoRippleCheckResponse;
+/// use xrpl::types::builders::TrustSetBuilder;
+///
+/// fn trust_line_fixes(resp: NoRippleCheckResponse) -> Vec<TrustSetBuilder> {
+///     resp.transactions.into_iter().filter_map(TrustSetBuilder::from_transaction).collect()
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct NoRippleCheckResponse {
+    /// Human-readable description of each problem found.
+    pub problems: Vec<String>,
+    /// Suggested `AccountSet` and `TrustSet` transactions that fix the
+    /// problems, with consecutive sequence numbers from the account's
+    /// current one. Empty unless requested.
+    #[serde(default)]
+    pub transactions: Vec<Transaction>,
+    /// Sequence number of the ledger version used.
+    pub ledger_index: Option<u32>,
+    /// Sequence number of the current open ledger (unvalidated results).
+    pub ledger_current_index: Option<u32>,
+    /// Whether the data comes from a validated ledger.
+    pub validated: Option<bool>,
+}
@@ -3,7 +3,7 @@ use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use super::{XrplRequest, XrplResponse};
-use crate::types::{Amount, Asset};
+use crate::types::{Amount, Asset, PathStep};
 
 /// Finds a payment path between a source and destination account (single-shot).
 ///
@@ -127,11 +127,12 @@ pub struct RipplePathFindResponse {
     pub validated: Option<bool>,
 }
 
-/// A single payment path alternative returned by `ripple_path_find`.
-#[derive(Debug, Deserialize)]
+/// A single payment path alternative returned by `ripple_path_find` or a
+/// `path_find` session.
+#[derive(Debug, Clone, Deserialize)]
 pub struct PathAlternative {
-    /// Computed payment paths in XRPL path format.
-    pub paths_computed: Value,
+    /// Computed payment paths, ready for a Payment's `Paths` field.
+    pub paths_computed: Vec<Vec<PathStep>>,
     /// Amount the source account must send along this path.
     pub source_amount: Amount,
 }
@@ -0,0 +1,340 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use crate::request::account_info::AccountInfoRequest;
+use crate::request::submit::SubmitResponse;
+use crate::util::next_queued_sequence;
+use crate::{Client, XrplError};
+
+/// Hands out sequence numbers for one account locally, so many transactions
+/// can be filled and submitted concurrently without racing on `account_info`.
+///
+/// The first [`next`](Self::next) reads the account's next free sequence
+/// (including queued transactions) from the server; later calls count up
+/// from there without a round-trip. Feed every submit response to
+/// [`observe`](Self::observe) so the manager follows the server's view:
+/// sequences of transactions that were not applied are handed out again, and
+/// `tefPAST_SEQ` / `terPRE_SEQ` force a resync from `account_info`.
+///
+/// Transactions that are accepted but never validated leave a gap that
+/// blocks every later sequence. Call [`expire`](Self::expire) after each
+/// validated ledger to find them; their sequences are reused first.
+///
+/// The manager is cheap to clone; clones share state. Use one per account.
+///
+/// # Example
+/// ```rust,no_run
+/// # use xrpl::types::{Transaction, SigningContext};
+/// # struct MyWallet;
+/// # impl SigningContext for MyWallet {
+/// #     type Error = anyhow::Error;
+/// #     fn sign_transaction(&self, _tx: &Transaction) -> anyhow::Result<String> { Ok(String::new()) }
+/// # }
+/// use xrpl::{Client, sequence::SequenceManager, xrp};
+/// use xrpl::types::builders::{PaymentBuilder, SubmitRequestBuilder};
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let wallet = MyWallet;
+/// let client = Client::new("wss://xrplcluster.com");
+/// let account = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+/// let sequences = SequenceManager::new(account);
+///
+/// let tx = PaymentBuilder::new(account, "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", xrp!(1))
+///     .with_sequence_manager(&sequences)
+///     .fill(&client)
+///     .await?
+///     .build()?;
+/// let req = SubmitRequestBuilder::new(&tx, &wallet).build()?;
+/// sequences.observe(&client.request(&req).await?.result()?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceManager {
+    account: Arc<str>,
+    state: Arc<Mutex<State>>,
+}
+
+impl SequenceManager {
+    /// Creates a manager for `account`. Nothing is fetched until the first
+    /// [`next`](Self::next).
+    pub fn new(account: impl AsRef<str>) -> Self {
+        Self {
+            account: account.as_ref().into(),
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    /// The account this manager allocates sequences for.
+    pub fn account(&self) -> &str {
+        &self.account
+    }
+
+    /// Next sequence number to use: the lowest released one, or the next
+    /// unused one. Fetches the account's sequence first if the manager has
+    /// not synced yet or was invalidated.
+    ///
+    /// The sequence counts as used until [`observe`](Self::observe),
+    /// [`release`](Self::release) or [`expire`](Self::expire) says otherwise;
+    /// release it if the transaction is never submitted.
+    pub async fn next(&self, client: &Client) -> Result<u32, XrplError> {
+        if let Some(sequence) = self.lock().allocate() {
+            return Ok(sequence);
+        }
+        let next = next_queued_sequence(client, &self.account).await?;
+        let mut state = self.lock();
+        // Another task may have synced while this one was fetching.
+        if state.next.is_none() {
+            state.sync(next);
+        }
+        Ok(state.allocate().expect("state was just synced"))
+    }
+
+    /// Records that the transaction using `sequence` expires after
+    /// `last_ledger_sequence`, so [`expire`](Self::expire) can reclaim it.
+    /// [`TransactionBuilder::fill`] calls this for you.
+    ///
+    /// [`TransactionBuilder::fill`]: crate::types::builders::TransactionBuilder::fill
+    pub fn track(&self, sequence: u32, last_ledger_sequence: u32) {
+        self.lock().in_flight.insert(sequence, last_ledger_sequence);
+    }
+
+    /// Learns from a submit response for a transaction from this account.
+    ///
+    /// `tes`, `tec` and `terQUEUED` results keep the sequence used, as does
+    /// `tefALREADY`: an earlier submission of the same transaction holds it.
+    /// `tefPAST_SEQ` and `terPRE_SEQ` mean the local count is wrong, so the
+    /// next [`next`](Self::next) resyncs. Any other result never consumed the
+    /// sequence, which is released for reuse. `account_sequence_next` and
+    /// `account_sequence_available` move the count forward when another
+    /// client used sequences from the same account.
+    pub fn observe(&self, resp: &SubmitResponse) {
+        let sequence = resp
+            .tx_json
+            .as_ref()
+            .and_then(|tx| tx.get("Sequence"))
+            .and_then(|s| s.as_u64())
+            .and_then(|s| u32::try_from(s).ok())
+            .filter(|&s| s != 0);
+        let learned =
+            resp.account_sequence_next.max(resp.account_sequence_available);
+        self.lock().observe(&resp.engine_result, sequence, learned);
+    }
+
+    /// Returns `sequence` to the pool, e.g. when the transaction was never
+    /// submitted or [`ReliableSubmission`] reported it
+    /// [`Rejected`](crate::submission::SubmissionOutcome::Rejected).
+    ///
+    /// [`ReliableSubmission`]: crate::submission::ReliableSubmission
+    pub fn release(&self, sequence: u32) {
+        self.lock().release(sequence);
+    }
+
+    /// Forgets the local count; the next [`next`](Self::next) reads the
+    /// account's sequence from the server again.
+    pub fn invalidate(&self) {
+        self.lock().next = None;
+    }
+
+    /// Reclaims sequences of transactions whose `LastLedgerSequence` is below
+    /// `validated_ledger_index` but that never made it into a validated
+    /// ledger. Returns the released sequences, lowest first.
+    ///
+    /// Only queries the server (`account_info` on the validated ledger) when
+    /// some tracked transaction has passed its `LastLedgerSequence`.
+    pub async fn expire(
+        &self,
+        client: &Client,
+        validated_ledger_index: u32,
+    ) -> Result<Vec<u32>, XrplError> {
+        if !self.lock().has_expired(validated_ledger_index) {
+            return Ok(Vec::new());
+        }
+        let req = AccountInfoRequest {
+            account: self.account.to_string(),
+            ledger_index: Some(serde_json::json!(validated_ledger_index)),
+            ..Default::default()
+        };
+        let validated =
+            client.request(&req).await?.result()?.account_data.sequence;
+        Ok(self.lock().expire(validated_ledger_index, validated))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Allocation state, kept free of I/O so it can be tested directly.
+#[derive(Debug, Default)]
+struct State {
+    /// Next never-allocated sequence; `None` until synced.
+    next: Option<u32>,
+    /// Allocated sequences handed back for reuse.
+    released: BTreeSet<u32>,
+    /// Allocated sequences by the `LastLedgerSequence` of their transaction.
+    in_flight: BTreeMap<u32, u32>,
+}
+
+impl State {
+    fn sync(&mut self, next: u32) {
+        self.next = Some(next);
+        self.released.clear();
+        // Anything at or above the server's next sequence never got in.
+        self.in_flight.retain(|&sequence, _| sequence < next);
+    }
+
+    fn allocate(&mut self) -> Option<u32> {
+        let next = self.next.as_mut()?;
+        if let Some(sequence) = self.released.pop_first() {
+            return Some(sequence);
+        }
+        let sequence = *next;
+        *next += 1;
+        Some(sequence)
+    }
+
+    fn release(&mut self, sequence: u32) {
+        self.in_flight.remove(&sequence);
+        let Some(next) = self.next.as_mut() else {
+            return;
+        };
+        if sequence >= *next {
+            return;
+        }
+        self.released.insert(sequence);
+        // Fold a released tail back into the counter.
+        while let Some(&last) = self.released.last()
+            && last + 1 == *next
+        {
+            self.released.pop_last();
+            *next = last;
+        }
+    }
+
+    fn observe(
+        &mut self,
+        engine_result: &str,
+        sequence: Option<u32>,
+        learned: Option<u32>,
+    ) {
+        match engine_result {
+            "tefPAST_SEQ" | "terPRE_SEQ" => {
+                if let Some(sequence) = sequence {
+                    self.in_flight.remove(&sequence);
+                }
+                self.next = None;
+                return;
+            }
+            r if r.starts_with("tes")
+                || r.starts_with("tec")
+                || r == "terQUEUED"
+                || r == "tefALREADY" => {}
+            _ => {
+                if let Some(sequence) = sequence {
+                    self.release(sequence);
+                }
+                return;
+            }
+        }
+        if let (Some(next), Some(learned)) = (self.next.as_mut(), learned)
+            && learned > *next
+        {
+            *next = learned;
+        }
+    }
+
+    fn has_expired(&self, validated_ledger_index: u32) -> bool {
+        self.in_flight.values().any(|&lls| lls < validated_ledger_index)
+    }
+
+    /// `validated` is the account's sequence in the validated ledger: every
+    /// sequence below it was consumed.
+    fn expire(
+        &mut self,
+        validated_ledger_index: u32,
+        validated: u32,
+    ) -> Vec<u32> {
+        self.in_flight.retain(|&sequence, _| sequence >= validated);
+        let expired: Vec<u32> = self
+            .in_flight
+            .iter()
+            .filter(|&(_, &lls)| lls < validated_ledger_index)
+            .map(|(&sequence, _)| sequence)
+            .collect();
+        for &sequence in &expired {
+            self.release(sequence);
+        }
+        expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn synced(next: u32) -> State {
+        let mut state = State::default();
+        state.sync(next);
+        state
+    }
+
+    #[test]
+    fn test_allocates_released_sequences_first() {
+        let mut state = synced(10);
+        assert_eq!(State::default().allocate(), None);
+        assert_eq!(state.allocate(), Some(10));
+        assert_eq!(state.allocate(), Some(11));
+        assert_eq!(state.allocate(), Some(12));
+
+        state.release(11);
+        assert_eq!(state.allocate(), Some(11));
+        assert_eq!(state.allocate(), Some(13));
+
+        // Releasing the newest sequences rewinds the counter.
+        state.release(12);
+        state.release(13);
+        assert_eq!(state.next, Some(12));
+        assert!(state.released.is_empty());
+    }
+
+    #[test]
+    fn test_observe_submit_results() {
+        let mut state = synced(10);
+        let (a, b) = (state.allocate(), state.allocate());
+
+        state.observe("tesSUCCESS", a, Some(11));
+        assert_eq!(state.next, Some(12));
+        state.observe("telINSUF_FEE_P", b, Some(11));
+        assert_eq!(state.allocate(), Some(11));
+
+        // Another client used sequences 12..15.
+        state.observe("terQUEUED", Some(11), Some(15));
+        assert_eq!(state.allocate(), Some(15));
+
+        // A resubmission of 15, which is already queued, doesn't free it.
+        state.observe("tefALREADY", Some(15), None);
+        assert_eq!(state.allocate(), Some(16));
+
+        state.observe("tefPAST_SEQ", Some(16), None);
+        assert_eq!(state.allocate(), None);
+    }
+
+    #[test]
+    fn test_expire_releases_unvalidated_sequences() {
+        let mut state = synced(10);
+        for lls in [100, 100, 104] {
+            let sequence = state.allocate().unwrap();
+            state.in_flight.insert(sequence, lls);
+        }
+        assert!(!state.has_expired(100));
+        assert!(state.has_expired(101));
+
+        // 10 was validated; 11 expired and now blocks 12.
+        assert_eq!(state.expire(101, 11), vec![11]);
+        assert_eq!(state.in_flight.keys().collect::<Vec<_>>(), [&12]);
+        assert_eq!(state.allocate(), Some(11));
+        assert_eq!(state.allocate(), Some(13));
+    }
+}
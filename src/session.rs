@@ -8,7 +8,8 @@ use crate::config::ClientConfig;
 use crate::error::XrplError;
 use crate::request::XrplSubscription;
 use crate::socket::{
-    SubscribeRequest, SubscriptionSessionRequest, UnsubscribeRequest,
+    RECONNECTED, SubscribeRequest, SubscriptionSessionRequest,
+    UnsubscribeRequest,
 };
 
 /// Unified event over every subscription stream type, dispatched on the wire
@@ -25,6 +26,12 @@ pub enum SubscriptionEvent {
     Ledger(crate::subscriptions::LedgerMessage),
     BookChanges(crate::subscriptions::BookChangesMessage),
     Transaction(crate::subscriptions::AccountTransactionMessage),
+    Validation(crate::subscriptions::ValidationMessage),
+    Consensus(crate::subscriptions::ConsensusMessage),
+    ServerStatus(crate::subscriptions::ServerStatusMessage),
+    PeerStatus(crate::subscriptions::PeerStatusMessage),
+    Manifest(crate::subscriptions::ManifestMessage),
+    PathFind(crate::subscriptions::PathFindMessage),
     Unknown { message_type: String, value: serde_json::Value },
 }
 
@@ -49,6 +56,24 @@ impl<'de> serde::Deserialize<'de> for SubscriptionEvent {
             "bookChanges" => serde_json::from_value(value)
                 .map(SubscriptionEvent::BookChanges)
                 .map_err(D::Error::custom),
+            "validationReceived" => serde_json::from_value(value)
+                .map(SubscriptionEvent::Validation)
+                .map_err(D::Error::custom),
+            "consensusPhase" => serde_json::from_value(value)
+                .map(SubscriptionEvent::Consensus)
+                .map_err(D::Error::custom),
+            "serverStatus" => serde_json::from_value(value)
+                .map(SubscriptionEvent::ServerStatus)
+                .map_err(D::Error::custom),
+            "peerStatusChange" => serde_json::from_value(value)
+                .map(SubscriptionEvent::PeerStatus)
+                .map_err(D::Error::custom),
+            "manifestReceived" => serde_json::from_value(value)
+                .map(SubscriptionEvent::Manifest)
+                .map_err(D::Error::custom),
+            "path_find" => serde_json::from_value(value)
+                .map(SubscriptionEvent::PathFind)
+                .map_err(D::Error::custom),
             other => Ok(SubscriptionEvent::Unknown {
                 message_type: other.to_string(),
                 value,
@@ -57,6 +82,15 @@ impl<'de> serde::Deserialize<'de> for SubscriptionEvent {
     }
 }
 
+/// What [`SubscriptionStream::recv_or_reconnected`] received.
+pub(crate) enum Received<T> {
+    /// A message pushed by the server.
+    Message(T),
+    /// The subscription was replayed after a reconnect; pushes sent while
+    /// disconnected were missed.
+    Reconnected,
+}
+
 /// A type-scoped receiver over a shared subscription connection, scoped to
 /// one subscription's message type (`SubscriptionStream<LedgerMessage>`) or
 /// unified over all of a session's subscriptions (`SubscriptionStream<SubscriptionEvent>`).
@@ -87,12 +121,29 @@ where
     ///
     /// Deserialization failures are logged via `eprintln!` and skipped.
     /// Returns [`XrplError::MessageDropped`] if this stream fell behind, or
-    /// [`XrplError::Disconnected`] once the connection is closed.
+    /// [`XrplError::Disconnected`] once the connection is closed. A reconnect
+    /// is transparent: the subscription is replayed and messages resume.
     pub async fn recv(&mut self) -> Result<T, XrplError> {
+        loop {
+            if let Received::Message(msg) = self.recv_or_reconnected().await? {
+                return Ok(msg);
+            }
+        }
+    }
+
+    /// Like [`recv`](Self::recv), but also reports when the subscription
+    /// has been replayed after a reconnect, for consumers that must resync
+    /// state derived from the pushes missed while disconnected.
+    pub(crate) async fn recv_or_reconnected(
+        &mut self,
+    ) -> Result<Received<T>, XrplError> {
         loop {
             match self.receiver.recv().await {
+                Ok(value) if value["type"] == RECONNECTED => {
+                    return Ok(Received::Reconnected);
+                }
                 Ok(value) => match serde_json::from_value::<T>(value.clone()) {
-                    Ok(msg) => return Ok(msg),
+                    Ok(msg) => return Ok(Received::Message(msg)),
                     Err(e) => {
                         eprintln!(
                             "Failed to deserialize subscription message: {e} - Raw: {value}"
@@ -261,19 +312,79 @@ mod tests {
     /// `Unknown`, preserving the type string and the full raw message.
     #[test]
     fn unrecognized_type_deserializes_into_unknown() {
-        let value = json!({ "type": "peerStatusChange", "foo": "bar" });
+        let value = json!({ "type": "futureStream", "foo": "bar" });
         let event: SubscriptionEvent =
             serde_json::from_value(value.clone()).expect("must not error");
 
         match event {
             SubscriptionEvent::Unknown { message_type, value: raw } => {
-                assert_eq!(message_type, "peerStatusChange");
+                assert_eq!(message_type, "futureStream");
                 assert_eq!(raw, value);
             }
             other => panic!("expected Unknown, got {other:?}"),
         }
     }
 
+    /// Each server-status stream dispatches to its own typed variant.
+    #[test]
+    fn status_streams_deserialize_into_typed_variants() {
+        let event = |value| serde_json::from_value(value).unwrap();
+
+        assert!(matches!(
+            event(
+                json!({ "type": "consensusPhase", "consensus": "establish" })
+            ),
+            SubscriptionEvent::Consensus(
+                crate::subscriptions::ConsensusMessage {
+                    consensus: crate::subscriptions::ConsensusPhase::Establish,
+                }
+            )
+        ));
+        assert!(matches!(
+            event(json!({
+                "type": "serverStatus",
+                "base_fee": 10,
+                "load_base": 256,
+                "load_factor": 256,
+                "server_status": "full",
+            })),
+            SubscriptionEvent::ServerStatus(_)
+        ));
+        assert!(matches!(
+            event(json!({
+                "type": "peerStatusChange",
+                "action": "CLOSING_LEDGER",
+                "date": 508546525,
+                "ledger_index": 18851530,
+            })),
+            SubscriptionEvent::PeerStatus(msg) if msg.ledger_index == Some(18851530)
+        ));
+        assert!(matches!(
+            event(json!({
+                "type": "manifestReceived",
+                "master_key": "nHUFE9prPXPrHcG3SkwP1UzAQbSphqyQkQK9ATXLZsfkezhhda3p",
+                "master_signature": "AB",
+                "seq": 3,
+                "signature": "CD",
+                "signing_key": "n9LRZXPh1XZaJr5kVpdciN76WCCcb5ZRwjvHywd4Vc4fxyfGEDJA",
+            })),
+            SubscriptionEvent::Manifest(msg) if msg.seq == 3
+        ));
+        assert!(matches!(
+            event(json!({
+                "type": "validationReceived",
+                "flags": 2147483649u32,
+                "full": true,
+                "ledger_hash": "EC02890710AAA2B71221B0D560CFB22D64317C07B7406B02959AD84BAD33E602",
+                "ledger_index": "6",
+                "signature": "3045",
+                "signing_time": 515115322,
+                "validation_public_key": "n94Gnc6svmaPPRHUAyyib1gQUov8sYbjLoEwUBYPH39qHZXuo8ZT",
+            })),
+            SubscriptionEvent::Validation(msg) if msg.full
+        ));
+    }
+
     /// A recognized `"type"` whose body doesn't match that variant's shape
     /// is a schema mismatch, not an unmodelled stream, so it surfaces as a
     /// deserialization error rather than `Unknown`.
@@ -328,6 +439,32 @@ mod tests {
         assert!(matches!(err, XrplError::Disconnected));
     }
 
+    /// The replay marker pushed after a reconnect is only reported by
+    /// `recv_or_reconnected`; `recv` skips it and keeps delivering.
+    #[tokio::test]
+    async fn replay_marker_is_only_reported_on_request() {
+        let (tx, rx) = broadcast::channel(8);
+        let mut stream = stream::<SubscriptionEvent>(rx);
+
+        let _ = tx.send(json!({ "type": RECONNECTED }));
+        let _ = tx.send(json!({ "type": "futureStream" }));
+        let _ = tx.send(json!({ "type": RECONNECTED }));
+        let _ = tx.send(json!({ "type": "futureStream" }));
+
+        assert!(matches!(
+            stream.recv().await,
+            Ok(SubscriptionEvent::Unknown { .. })
+        ));
+        assert!(matches!(
+            stream.recv_or_reconnected().await,
+            Ok(Received::Reconnected)
+        ));
+        assert!(matches!(
+            stream.recv_or_reconnected().await,
+            Ok(Received::Message(SubscriptionEvent::Unknown { .. }))
+        ));
+    }
+
     /// A stream returned by `subscribe()` is independently owned and holds
     /// its own connection reference, so it must keep receiving messages
     /// even after the session that created it is dropped.
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 use std::ops::ControlFlow;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use futures_util::{SinkExt, StreamExt, stream::SplitSink};
 use serde_json::Value;
 use tokio::net::TcpStream;
-use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
 use tokio_tungstenite::{
     connect_async,
     tungstenite::{Message, Error as WsError},
@@ -14,6 +16,7 @@ use tokio_tungstenite::{
 
 use crate::config::ClientConfig;
 use crate::error::XrplError;
+use crate::pool::EndpointPool;
 
 #[cfg(feature = "jsondump")]
 macro_rules! json_dump {
@@ -35,6 +38,11 @@ macro_rules! json_dump {
     ($label:expr, $value:expr) => {};
 }
 
+/// `"type"` of the marker pushed onto a subscription's channel when the
+/// server acknowledges its replay after a reconnect. Never sent by rippled;
+/// `SubscriptionStream::recv` skips it, and `OrderBook` resyncs on it.
+pub(crate) const RECONNECTED: &str = "xrpl-ws:reconnected";
+
 type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 type WsSink = SplitSink<WsStream, Message>;
 
@@ -214,12 +222,26 @@ trait SessionHandler: Send + 'static {
     fn on_disconnect(&mut self);
 }
 
+/// Why a [`ConnectionDriver`] session ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SessionEnd {
+    /// The connection failed or could not be established.
+    Lost,
+    /// The driver left an endpoint that was marked unhealthy.
+    FailedOver,
+}
+
 /// Drives a persistent, auto-reconnecting WebSocket connection.
 ///
-/// Owns the connection lifecycle: connects, delegates session events to an
-/// [`SessionHandler`], and reconnects with exponential backoff on failure.
+/// Owns the connection lifecycle: connects to the healthiest endpoint of the
+/// [`EndpointPool`], delegates session events to an [`SessionHandler`], and
+/// reconnects with exponential backoff on failure. Because the handler
+/// outlives each session, whatever [`SessionHandler::on_connect`] replays
+/// follows the driver to whichever endpoint it fails over to.
 struct ConnectionDriver<H: SessionHandler> {
-    url: String,
+    pool: Arc<EndpointPool>,
+    endpoint: Arc<AtomicUsize>,
+    failover: watch::Receiver<u64>,
     config: ClientConfig,
     req_rx: mpsc::Receiver<H::Message>,
     handler: H,
@@ -227,33 +249,42 @@ struct ConnectionDriver<H: SessionHandler> {
 
 impl<H: SessionHandler> ConnectionDriver<H> {
     fn new(
-        url: String,
+        pool: Arc<EndpointPool>,
         config: ClientConfig,
         req_rx: mpsc::Receiver<H::Message>,
         handler: H,
     ) -> Self {
-        Self { url, config, req_rx, handler }
+        let failover = pool.watch();
+        let endpoint = Arc::new(AtomicUsize::new(pool.best()));
+        Self { pool, endpoint, failover, config, req_rx, handler }
     }
 
     async fn run(mut self) {
         let mut backoff = self.config.initial_backoff;
 
         loop {
-            match connect_async(&self.url).await {
+            let endpoint = self.pool.best();
+            self.endpoint.store(endpoint, Ordering::Relaxed);
+            let url = self.pool.url(endpoint);
+
+            let end = match connect_async(&url).await {
                 Ok((ws_stream, _)) => {
                     backoff = self.config.initial_backoff;
-                    self.run_session(ws_stream).await;
+                    self.pool.report_connected(endpoint);
+                    self.failover.mark_unchanged();
+                    self.run_session(ws_stream).await
                 }
                 Err(e) => {
                     eprintln!(
-                        "WS Connect Error: {e}. Retrying in {}s",
+                        "WS Connect Error ({url}): {e}. Retrying in {}s",
                         backoff.as_secs()
                     );
+                    SessionEnd::Lost
                 }
-            }
+            };
 
             if let ControlFlow::Break(reason) =
-                self.reconnect(&mut backoff).await
+                self.reconnect(end, &mut backoff).await
             {
                 eprintln!("Connection driver stopping: {reason}");
                 break;
@@ -263,6 +294,7 @@ impl<H: SessionHandler> ConnectionDriver<H> {
 
     async fn reconnect(
         &mut self,
+        end: SessionEnd,
         backoff: &mut Duration,
     ) -> ControlFlow<String> {
         self.handler.on_disconnect();
@@ -273,12 +305,24 @@ impl<H: SessionHandler> ConnectionDriver<H> {
             );
         }
 
+        let endpoint = self.endpoint.load(Ordering::Relaxed);
+        // Leaving an endpoint that was already marked unhealthy is not a new
+        // failure; reporting it would wake every other driver again.
+        if end == SessionEnd::Lost {
+            self.pool.report_failure(endpoint);
+        }
+        let next = self.pool.best();
+        if next != endpoint && self.pool.is_healthy(next) {
+            // A healthy alternative is available - fail over without waiting.
+            return ControlFlow::Continue(());
+        }
+
         tokio::time::sleep(*backoff).await;
         *backoff = (*backoff * 2).min(self.config.max_backoff);
         ControlFlow::Continue(())
     }
 
-    async fn run_session(&mut self, ws_stream: WsStream) {
+    async fn run_session(&mut self, ws_stream: WsStream) -> SessionEnd {
         let (mut write, mut read) = ws_stream.split();
         let mut ping_interval =
             tokio::time::interval(self.config.keepalive_interval);
@@ -292,14 +336,33 @@ impl<H: SessionHandler> ConnectionDriver<H> {
                 _   = ping_interval.tick() => self.ping(&mut write).await,
                 req = self.req_rx.recv()   => self.request(&mut write, req).await,
                 msg = read.next()          => self.response(&mut write, msg).await,
+                _   = self.failover.changed() => {
+                    if let ControlFlow::Break(reason) = self.check_endpoint() {
+                        eprintln!("Session ending: {reason}");
+                        return SessionEnd::FailedOver;
+                    }
+                    ControlFlow::Continue(())
+                }
             };
             if let ControlFlow::Break(reason) = alive {
                 eprintln!("Session ending: {reason}");
-                break;
+                return SessionEnd::Lost;
             }
         }
     }
 
+    /// Ends the session when the connected endpoint has been marked unhealthy.
+    fn check_endpoint(&self) -> ControlFlow<String> {
+        let endpoint = self.endpoint.load(Ordering::Relaxed);
+        if self.pool.is_healthy(endpoint) {
+            return ControlFlow::Continue(());
+        }
+        ControlFlow::Break(format!(
+            "endpoint {} unhealthy - failing over",
+            self.pool.url(endpoint)
+        ))
+    }
+
     async fn ping(&mut self, write: &mut WsSink) -> ControlFlow<String> {
         let empty = vec![].into();
         match write.send(Message::Ping(empty)).await {
@@ -466,11 +529,8 @@ impl SessionHandler for SubscriptionHandler {
                     return None;
                 };
 
-                let mut request = sub.payload;
-                request["command"] = "unsubscribe".into();
-
                 let payload = self.requests.register(
-                    request,
+                    unsubscribe_payload(sub.payload),
                     SessionResponder::Unsubscribe(responder),
                 );
                 Some(payload)
@@ -479,7 +539,13 @@ impl SessionHandler for SubscriptionHandler {
     }
 
     fn on_response(&mut self, value: Value) -> ControlFlow<String> {
-        match value["id"].as_u64() {
+        // `path_find` updates echo the id of the request that opened them,
+        // so only untyped or `"response"` messages are responses.
+        let push = value
+            .get("type")
+            .and_then(Value::as_str)
+            .is_some_and(|message_type| message_type != "response");
+        match value["id"].as_u64().filter(|_| !push) {
             Some(id) => {
                 json_dump!("RESPONSE", &value);
                 if let Some(responder) = self.requests.resolve(id) {
@@ -501,6 +567,15 @@ impl SessionHandler for SubscriptionHandler {
                     }
                     return ControlFlow::Continue(());
                 }
+                if let Some(sub) = self.subscriptions.get(&id) {
+                    // Acknowledgement of a subscription replayed by on_connect:
+                    // pushes sent while disconnected are gone, so tell the
+                    // stream it may have a gap.
+                    let _ = sub
+                        .sender
+                        .send(serde_json::json!({ "type": RECONNECTED }));
+                    return ControlFlow::Continue(());
+                }
                 json_dump!("UNMATCHED_RESPONSE", &value);
                 ControlFlow::Break(format!(
                     "protocol violation: unmatched response id {id}"
@@ -530,16 +605,34 @@ impl SessionHandler for SubscriptionHandler {
     }
 }
 
+/// Builds the request that stops the subscription opened by `payload`:
+/// `path_find` sessions are closed with their own subcommand, everything
+/// else is unsubscribed with the original request's fields.
+fn unsubscribe_payload(mut payload: Value) -> Value {
+    if payload["command"] == "path_find" {
+        let mut close = serde_json::json!({ "command": "path_find", "subcommand": "close" });
+        if let Some(version) = payload.get("api_version") {
+            close["api_version"] = version.clone();
+        }
+        return close;
+    }
+    payload["command"] = "unsubscribe".into();
+    payload
+}
+
 /// Spawns a persistent, multiplexed WebSocket connection for one-shot requests.
-/// Returns a sender that routes each [`SocketRequest`] through the shared connection.
+/// Returns a sender that routes each [`SocketRequest`] through the shared
+/// connection, and the index of the pool endpoint it is currently using.
 pub(crate) fn request(
-    url: String,
+    pool: Arc<EndpointPool>,
     config: ClientConfig,
-) -> mpsc::Sender<SocketRequest> {
+) -> (mpsc::Sender<SocketRequest>, Arc<AtomicUsize>) {
     let (req_tx, req_rx) = mpsc::channel(config.cmd_channel_size);
     let handler = RequestHandler::new();
-    tokio::spawn(ConnectionDriver::new(url, config, req_rx, handler).run());
-    req_tx
+    let driver = ConnectionDriver::new(pool, config, req_rx, handler);
+    let endpoint = driver.endpoint.clone();
+    tokio::spawn(driver.run());
+    (req_tx, endpoint)
 }
 
 /// Spawns a persistent, multiplexed WebSocket connection shared by all
@@ -547,7 +640,7 @@ pub(crate) fn request(
 /// [`SubscriptionSessionRequest`]s and the umbrella broadcast receiver carrying
 /// every pushed message, untyped, regardless of wire message type.
 pub(crate) fn subscribe(
-    url: String,
+    pool: Arc<EndpointPool>,
     config: ClientConfig,
 ) -> (mpsc::Sender<SubscriptionSessionRequest>, broadcast::Receiver<Value>) {
     let (req_tx, req_rx) = mpsc::channel(config.cmd_channel_size);
@@ -557,7 +650,7 @@ pub(crate) fn subscribe(
     let handler =
         SubscriptionHandler::new(stream_tx, config.subscription_channel_size);
 
-    tokio::spawn(ConnectionDriver::new(url, config, req_rx, handler).run());
+    tokio::spawn(ConnectionDriver::new(pool, config, req_rx, handler).run());
 
     (req_tx, stream_rx)
 }
@@ -653,6 +746,29 @@ mod tests {
         assert!(flow.is_continue());
     }
 
+    /// The server's acknowledgement of a subscription replayed after a
+    /// reconnect carries the original id, which no longer has a pending
+    /// responder; it must not be treated as a protocol violation, and the
+    /// stream (but not the umbrella channel) is told it may have missed
+    /// pushes.
+    #[test]
+    fn replayed_subscription_ack_does_not_break_connection() {
+        let (stream_tx, _stream_rx) = broadcast::channel(16);
+        let mut handler = SubscriptionHandler::new(stream_tx, 16);
+        let mut umbrella = handler.stream_tx.subscribe();
+        let mut ack = acknowledge(&mut handler, "ledgerClosed");
+
+        handler.on_disconnect();
+        let replayed = handler.on_connect();
+        assert_eq!(replayed.len(), 1);
+
+        let flow =
+            handler.on_response(json!({ "id": ack.id, "status": "success" }));
+        assert!(flow.is_continue());
+        assert_eq!(ack.receiver.try_recv().unwrap()["type"], RECONNECTED);
+        assert!(umbrella.try_recv().is_err());
+    }
+
     /// Once a subscription's receiver is dropped, the next push of a matching
     /// message type must prune it from `subscriptions` - no manual GC pass
     /// required, and no unbounded growth of stale entries or upstream
@@ -767,6 +883,51 @@ mod tests {
             .expect("unsubscribe ack must not be an error");
     }
 
+    /// A `path_find` session is closed with `subcommand: close`, and its
+    /// updates - which echo the creating request's id - are routed as
+    /// pushes rather than mistaken for responses.
+    #[test]
+    fn path_find_updates_are_pushes_and_close_on_unsubscribe() {
+        let (stream_tx, _stream_rx) = broadcast::channel(16);
+        let mut handler = SubscriptionHandler::new(stream_tx, 16);
+
+        let (responder, mut sub_rx) = oneshot::channel();
+        let payload = handler
+            .on_request(SubscriptionSessionRequest::Subscribe(
+                SubscribeRequest {
+                    request: json!({
+                        "command": "path_find",
+                        "subcommand": "create",
+                        "source_account": "rA",
+                    }),
+                    responder,
+                    message_type: "path_find",
+                },
+            ))
+            .unwrap();
+        let id = payload["id"].as_u64().unwrap();
+        let _ = handler.on_response(
+            json!({ "id": id, "type": "response", "status": "success" }),
+        );
+        let mut ack = sub_rx.try_recv().unwrap().unwrap();
+
+        let flow = handler.on_response(
+            json!({ "id": id, "type": "path_find", "full_reply": true }),
+        );
+        assert!(flow.is_continue());
+        assert_eq!(ack.receiver.try_recv().unwrap()["full_reply"], true);
+
+        let (unsub_responder, _unsub_rx) = oneshot::channel();
+        let close = handler
+            .on_request(SubscriptionSessionRequest::Unsubscribe(
+                UnsubscribeRequest { id, responder: unsub_responder },
+            ))
+            .unwrap();
+        assert_eq!(close["command"], "path_find");
+        assert_eq!(close["subcommand"], "close");
+        assert!(close.get("source_account").is_none());
+    }
+
     /// Unsubscribing an id that is no longer tracked (already pruned, or a
     /// redundant second call) resolves locally without sending anything over
     /// the wire - there is nothing left to tell the server to stop.
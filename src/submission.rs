@@ -0,0 +1,263 @@
+use thiserror::Error;
+
+use crate::request::submit::SubmitResponse;
+use crate::request::tx::TxRequest;
+use crate::subscriptions::{LedgerMessage, LedgerSubscription};
+use crate::types::builders::{BuildError, SubmitRequestBuilder};
+use crate::types::{SigningContext, Transaction, TransactionMeta};
+use crate::{Client, SubscriptionStream, XrplError};
+
+/// Final result of a [`ReliableSubmission`].
+///
+/// Only [`Validated`](Self::Validated) and [`Failed`](Self::Failed) are final
+/// on-ledger outcomes; both carry the transaction metadata from the validated
+/// ledger. [`Expired`](Self::Expired) and [`Rejected`](Self::Rejected) mean the
+/// transaction never made it into a validated ledger and will not in the future.
+///
+/// # Example
+/// ```rust
+/// use xrpl::submission::SubmissionOutcome;
+///
+/// fn describe(outcome: &SubmissionOutcome) -> String {
+///     match outcome {
+///         SubmissionOutcome::Validated { hash, .. } => format!("{hash} succeeded"),
+///         SubmissionOutcome::Failed { meta, .. } => meta.transaction_result.clone(),
+///         SubmissionOutcome::Expired { last_ledger_sequence, .. } => {
+///             format!("not included before ledger {last_ledger_sequence}")
+///         }
+///         SubmissionOutcome::Rejected { engine_result, .. } => engine_result.clone(),
+///         _ => "unknown".to_string(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum SubmissionOutcome {
+    /// Included in a validated ledger with `tesSUCCESS`.
+    Validated {
+        /// Transaction hash.
+        hash: String,
+        /// Sequence of the validated ledger that includes the transaction.
+        ledger_index: u32,
+        /// Execution metadata from the validated ledger.
+        meta: TransactionMeta,
+    },
+    /// Included in a validated ledger with a `tec` code. The fee was charged.
+    Failed {
+        /// Transaction hash.
+        hash: String,
+        /// Sequence of the validated ledger that includes the transaction.
+        ledger_index: u32,
+        /// Execution metadata from the validated ledger.
+        meta: TransactionMeta,
+    },
+    /// The ledger at `LastLedgerSequence` was validated without the transaction.
+    Expired {
+        /// Transaction hash.
+        hash: String,
+        /// The transaction's `LastLedgerSequence`.
+        last_ledger_sequence: u32,
+    },
+    /// The server rejected the transaction permanently (`tem` or `tef` codes).
+    Rejected {
+        /// Symbolic result code, e.g. `"temBAD_FEE"` or `"tefPAST_SEQ"`.
+        engine_result: String,
+        /// Human-readable description of the result.
+        engine_result_message: String,
+    },
+}
+
+impl SubmissionOutcome {
+    /// Whether the transaction was validated with `tesSUCCESS`.
+    pub fn is_success(&self) -> bool {
+        matches!(self, SubmissionOutcome::Validated { .. })
+    }
+
+    /// Transaction metadata, for outcomes included in a validated ledger.
+    pub fn meta(&self) -> Option<&TransactionMeta> {
+        match self {
+            SubmissionOutcome::Validated { meta, .. }
+            | SubmissionOutcome::Failed { meta, .. } => Some(meta),
+            _ => None,
+        }
+    }
+}
+
+/// Errors returned by [`ReliableSubmission::submit`].
+#[derive(Error, Debug)]
+pub enum SubmissionError<E> {
+    /// The signing context failed to sign the transaction.
+    #[error("Failed to sign transaction: {0}")]
+    Signing(E),
+    /// The transaction could not be built.
+    #[error("Failed to build transaction: {0}")]
+    Build(#[from] BuildError),
+    /// The transaction has no `LastLedgerSequence`, so expiry cannot be detected.
+    #[error("Transaction has no LastLedgerSequence")]
+    MissingLastLedgerSequence,
+    /// A request or subscription failed while submitting or waiting.
+    #[error(transparent)]
+    Xrpl(#[from] XrplError),
+}
+
+/// Signs, submits, and follows a transaction until it reaches a final outcome.
+///
+/// Subscribes to the `ledger` stream before submitting, then looks the
+/// transaction up with `tx` after every ledger close until it is validated
+/// or the ledger at its `LastLedgerSequence` has been validated. The transaction must
+/// carry a `LastLedgerSequence`; [`TransactionBuilder::fill`] sets one.
+///
+/// [`TransactionBuilder::fill`]: crate::types::builders::TransactionBuilder::fill
+///
+/// # Example
+/// ```rust,no_run
+/// # use xrpl::types::{Transaction, SigningContext};
+/// # struct MyWallet;
+/// # impl SigningContext for MyWallet {
+/// #     type Error = anyhow::Error;
+/// #     fn sign_transaction(&self, _tx: &Transaction) -> anyhow::Result<String> { Ok(String::new()) }
+/// # }
+/// use xrpl::{Client, submission::ReliableSubmission};
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let tx: Transaction = todo!();
+/// # let wallet = MyWallet;
+/// let client = Client::new("wss://xrplcluster.com");
+/// let outcome = ReliableSubmission::new(&tx, &wallet)
+///     .fail_hard(true)
+///     .submit(&client)
+///     .await?;
+/// println!("success: {}", outcome.is_success());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReliableSubmission<'a, W: SigningContext> {
+    tx: &'a Transaction,
+    wallet: &'a W,
+    fail_hard: Option<bool>,
+}
+
+impl<'a, W: SigningContext> ReliableSubmission<'a, W> {
+    /// Creates a new submission for the given transaction and signing wallet.
+    pub fn new(tx: &'a Transaction, wallet: &'a W) -> Self {
+        Self { tx, wallet, fail_hard: None }
+    }
+
+    /// Rejects the transaction instead of queuing it when it cannot enter the open ledger.
+    pub fn fail_hard(mut self, value: bool) -> Self {
+        self.fail_hard = Some(value);
+        self
+    }
+
+    /// Signs and submits the transaction, then waits for its final outcome.
+    pub async fn submit(
+        self,
+        client: &Client,
+    ) -> Result<SubmissionOutcome, SubmissionError<W::Error>> {
+        let last_ledger_sequence = self
+            .tx
+            .last_ledger_sequence
+            .ok_or(SubmissionError::MissingLastLedgerSequence)?;
+
+        let mut builder = SubmitRequestBuilder::new(self.tx, self.wallet);
+        if let Some(v) = self.fail_hard {
+            builder = builder.fail_hard(v);
+        }
+        let req = builder.build().map_err(SubmissionError::Signing)?;
+
+        // Subscribe first so no ledger close between submit and wait is missed.
+        let mut session = client.subscription().await?;
+        let (_, ledgers) =
+            session.subscribe(&LedgerSubscription::new()).await?;
+
+        let resp = client.request(&req).await?.result()?;
+        if is_permanent_rejection(&resp.engine_result) {
+            return Ok(SubmissionOutcome::Rejected {
+                engine_result: resp.engine_result,
+                engine_result_message: resp.engine_result_message,
+            });
+        }
+
+        let hash = submitted_hash(&resp)?;
+        let outcome =
+            wait_for_outcome(client, ledgers, hash, last_ledger_sequence)
+                .await?;
+        Ok(outcome)
+    }
+}
+
+/// `tem` (malformed) and `tef` (failure) codes can never succeed later.
+fn is_permanent_rejection(engine_result: &str) -> bool {
+    engine_result.starts_with("tem") || engine_result.starts_with("tef")
+}
+
+fn submitted_hash(resp: &SubmitResponse) -> Result<String, XrplError> {
+    resp.tx_json
+        .as_ref()
+        .and_then(|tx| tx.get("hash"))
+        .and_then(|h| h.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            XrplError::ParseError(
+                "Submit response did not include a transaction hash"
+                    .to_string(),
+            )
+        })
+}
+
+/// Looks the transaction up after every ledger close until it is final.
+async fn wait_for_outcome(
+    client: &Client,
+    mut ledgers: SubscriptionStream<LedgerMessage>,
+    hash: String,
+    last_ledger_sequence: u32,
+) -> Result<SubmissionOutcome, XrplError> {
+    loop {
+        let closed = match ledgers.recv().await {
+            Ok(msg) => msg.ledger_index,
+            // A lagged stream only means we skipped some closes; look up anyway.
+            Err(XrplError::MessageDropped(_)) => 0,
+            Err(e) => return Err(e),
+        };
+
+        if let Some(outcome) = lookup(client, &hash).await? {
+            return Ok(outcome);
+        }
+        if closed >= i64::from(last_ledger_sequence) {
+            return Ok(SubmissionOutcome::Expired {
+                hash,
+                last_ledger_sequence,
+            });
+        }
+    }
+}
+
+/// Returns the final outcome once the transaction is in a validated ledger.
+async fn lookup(
+    client: &Client,
+    hash: &str,
+) -> Result<Option<SubmissionOutcome>, XrplError> {
+    let tx = match client.request(&TxRequest::by_hash(hash)).await?.result() {
+        Ok(tx) => tx,
+        Err(XrplError::ApiError { ref error, .. })
+            if error == "txnNotFound" =>
+        {
+            return Ok(None);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let (Some(meta), Some(ledger_index), true) =
+        (tx.meta, tx.ledger_index, tx.validated)
+    else {
+        return Ok(None);
+    };
+
+    let hash = hash.to_string();
+    if meta.transaction_result == "tesSUCCESS" {
+        Ok(Some(SubmissionOutcome::Validated { hash, ledger_index, meta }))
+    } else {
+        Ok(Some(SubmissionOutcome::Failed { hash, ledger_index, meta }))
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::request::book_offers::BookOffer;
 use crate::request::{XrplRequest, XrplResponse, XrplSubscription};
 use crate::types::{validation::validate_currency_code, builders::BuildError};
 
@@ -221,11 +222,18 @@ impl XrplSubscription for BookSubscription {
 
 /// Initial response returned when subscribing to an order book.
 ///
-/// When `snapshot: true` is set, the subscribe response envelope includes an
-/// `offers` array with the current order book state. No additional fields
-/// beyond the standard envelope are present in this response struct.
+/// When `snapshot: true` is set, the subscribe response carries the current
+/// order book state: `offers` for a one-sided book, or `asks` and `bids` when
+/// `both` is also set. All three are `None` without a snapshot.
 #[derive(Debug, Deserialize)]
-pub struct BookSubscriptionResponse {}
+pub struct BookSubscriptionResponse {
+    /// Offers in the subscribed direction, best quality first.
+    pub offers: Option<Vec<BookOffer>>,
+    /// Offers in the subscribed direction of a two-sided book.
+    pub asks: Option<Vec<BookOffer>>,
+    /// Offers in the reverse direction of a two-sided book.
+    pub bids: Option<Vec<BookOffer>>,
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::request::{XrplRequest, XrplResponse, XrplSubscription};
+
+/// Subscription request for the `consensus` stream.
+///
+/// Sends a `consensusPhase` message whenever the server's consensus process
+/// moves to a new phase.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::Client;
+/// use xrpl::subscriptions::ConsensusSubscription;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Client::new("wss://xrplcluster.com");
+///     let mut session = client.subscription().await?;
+///     let (_resp, mut stream) = session.subscribe(&ConsensusSubscription::new()).await?;
+///     while let Ok(msg) = stream.recv().await {
+///         println!("consensus phase: {:?}", msg.consensus);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsensusSubscription {
+    streams: [&'static str; 1],
+}
+
+impl ConsensusSubscription {
+    /// Creates a new subscription to the `consensus` stream.
+    pub fn new() -> Self {
+        Self { streams: [<Self as XrplSubscription>::STREAM] }
+    }
+}
+
+impl Default for ConsensusSubscription {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XrplRequest for ConsensusSubscription {
+    type Response = XrplResponse<ConsensusSubscriptionResponse>;
+    const COMMAND: &str = "subscribe";
+}
+
+impl XrplSubscription for ConsensusSubscription {
+    type Message = ConsensusMessage;
+    const STREAM: &'static str = "consensus";
+    const MESSAGE_TYPE: &'static str = "consensusPhase";
+}
+
+/// Initial response returned when subscribing to the `consensus` stream.
+#[derive(Debug, Deserialize)]
+pub struct ConsensusSubscriptionResponse {}
+
+/// A `consensusPhase` stream message.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConsensusMessage {
+    /// The phase consensus just entered.
+    pub consensus: ConsensusPhase,
+}
+
+/// Phase of the consensus process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsensusPhase {
+    /// Collecting transactions for the next ledger.
+    Open,
+    /// Exchanging proposals with other validators.
+    Establish,
+    /// Consensus was reached and the ledger is being built.
+    Accepted,
+    /// A phase this library does not know about.
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+use crate::request::{XrplRequest, XrplResponse, XrplSubscription};
+
+/// Subscription request for the `manifests` stream.
+///
+/// Sends a `manifestReceived` message whenever the server learns of a
+/// validator manifest: a master key delegating signing to an ephemeral key.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::Client;
+/// use xrpl::subscriptions::ManifestsSubscription;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Client::new("wss://xrplcluster.com");
+///     let mut session = client.subscription().await?;
+///     let (_resp, mut stream) = session.subscribe(&ManifestsSubscription::new()).await?;
+///     while let Ok(msg) = stream.recv().await {
+///         println!("{} now signs with {}", msg.master_key, msg.signing_key);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestsSubscription {
+    streams: [&'static str; 1],
+}
+
+impl ManifestsSubscription {
+    /// Creates a new subscription to the `manifests` stream.
+    pub fn new() -> Self {
+        Self { streams: [<Self as XrplSubscription>::STREAM] }
+    }
+}
+
+impl Default for ManifestsSubscription {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XrplRequest for ManifestsSubscription {
+    type Response = XrplResponse<ManifestsSubscriptionResponse>;
+    const COMMAND: &str = "subscribe";
+}
+
+impl XrplSubscription for ManifestsSubscription {
+    type Message = ManifestMessage;
+    const STREAM: &'static str = "manifests";
+    const MESSAGE_TYPE: &'static str = "manifestReceived";
+}
+
+/// Initial response returned when subscribing to the `manifests` stream.
+#[derive(Debug, Deserialize)]
+pub struct ManifestsSubscriptionResponse {}
+
+/// A `manifestReceived` stream message.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ManifestMessage {
+    /// Domain the validator claims, when set in the manifest.
+    pub domain: Option<String>,
+    /// Base64 of the whole serialized manifest.
+    pub manifest: Option<String>,
+    /// Master public key of the validator (base58, `n…`).
+    pub master_key: String,
+    /// Signature of the manifest by the master key.
+    pub master_signature: String,
+    /// Manifest sequence number; a higher one supersedes earlier manifests.
+    pub seq: u32,
+    /// Signature of the manifest by the ephemeral signing key.
+    pub signature: String,
+    /// Ephemeral public key the validator now signs with (base58, `n…`).
+    pub signing_key: String,
+}
@@ -4,13 +4,31 @@ pub mod account_tx;
 pub mod book;
 /// Aggregated order-book change subscription and streamed messages.
 pub mod book_changes;
+/// Consensus-phase subscription types and streamed messages.
+pub mod consensus;
 /// Ledger-close subscription types and streamed messages.
 pub mod ledger;
+/// Validator-manifest subscription types and streamed messages.
+pub mod manifests;
+/// Streaming `path_find` sessions and their path updates.
+pub mod path_find;
+/// Peer-status subscription types and streamed messages (admin only).
+pub mod peer_status;
+/// Server-status subscription types and streamed messages.
+pub mod server;
 /// Transaction stream subscription types and streamed messages.
 pub mod transaction;
+/// Validation-vote subscription types and streamed messages.
+pub mod validations;
 
 pub use account_tx::*;
 pub use book::*;
 pub use book_changes::*;
+pub use consensus::*;
 pub use ledger::*;
+pub use manifests::*;
+pub use path_find::*;
+pub use peer_status::*;
+pub use server::*;
 pub use transaction::*;
+pub use validations::*;
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::request::ripple_path_find::PathAlternative;
+use crate::request::{XrplRequest, XrplResponse, XrplSubscription};
+use crate::types::Amount;
+
+/// Opens a `path_find` session that keeps searching for payment paths and
+/// pushes a `path_find` message whenever the best paths change.
+///
+/// The subscribe response carries the first (possibly partial) set of
+/// alternatives; later messages refine it, with `full_reply` set once the
+/// search for the current ledger is complete. Dropping the stream (or calling
+/// [`unsubscribe`](crate::SubscriptionStream::unsubscribe)) sends
+/// `subcommand: close`.
+///
+/// rippled keeps at most one `path_find` session per connection: opening a
+/// second one on the same [`SubscriptionSession`](crate::SubscriptionSession)
+/// replaces the first.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::Client;
+/// use xrpl::subscriptions::PathFindSubscription;
+/// use xrpl::types::Amount;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Client::new("wss://xrplcluster.com");
+///     let amount = Amount::issued_currency("10", "USD", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B")?;
+///     let sub = PathFindSubscription::new(
+///         "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+///         "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+///         amount,
+///     );
+///     let mut session = client.subscription().await?;
+///     let (_resp, mut stream) = session.subscribe(&sub).await?;
+///     while let Ok(msg) = stream.recv().await {
+///         for alt in &msg.alternatives {
+///             println!("send {} (full: {})", alt.source_amount, msg.full_reply);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+pub struct PathFindSubscription {
+    subcommand: &'static str,
+    /// Account that will send the payment.
+    pub source_account: String,
+    /// Account that will receive the payment.
+    pub destination_account: String,
+    /// Amount the destination account should receive.
+    pub destination_amount: Amount,
+    /// Maximum amount the source account is willing to spend.
+    pub send_max: Option<Amount>,
+    /// If provided, only return paths that use the corresponding permissioned DEX.
+    pub domain: Option<String>,
+}
+
+impl PathFindSubscription {
+    /// Creates a `path_find create` request with the mandatory source,
+    /// destination, and amount fields.
+    pub fn new(
+        source_account: impl AsRef<str>,
+        destination_account: impl AsRef<str>,
+        destination_amount: impl Into<Amount>,
+    ) -> Self {
+        Self {
+            subcommand: "create",
+            source_account: source_account.as_ref().to_string(),
+            destination_account: destination_account.as_ref().to_string(),
+            destination_amount: destination_amount.into(),
+            send_max: None,
+            domain: None,
+        }
+    }
+
+    /// Maximum amount the source account is willing to spend.
+    pub fn with_send_max(mut self, send_max: impl Into<Amount>) -> Self {
+        self.send_max = Some(send_max.into());
+        self
+    }
+
+    /// If provided, only return paths that use the corresponding permissioned DEX.
+    pub fn with_domain(mut self, domain: impl AsRef<str>) -> Self {
+        self.domain = Some(domain.as_ref().to_string());
+        self
+    }
+}
+
+impl XrplRequest for PathFindSubscription {
+    type Response = XrplResponse<PathFindSubscriptionResponse>;
+    const COMMAND: &str = "path_find";
+}
+
+impl XrplSubscription for PathFindSubscription {
+    type Message = PathFindMessage;
+    const MESSAGE_TYPE: &'static str = "path_find";
+}
+
+/// Initial response to `path_find create`: the first set of alternatives.
+#[derive(Debug, Deserialize)]
+pub struct PathFindSubscriptionResponse {
+    /// Path alternatives found so far, sorted by quality (best first).
+    pub alternatives: Vec<PathAlternative>,
+    /// Destination account from the request.
+    pub destination_account: String,
+    /// Destination amount from the request.
+    pub destination_amount: Amount,
+    /// Source account from the request.
+    pub source_account: String,
+    /// Whether the search for the current ledger is already complete.
+    pub full_reply: Option<bool>,
+}
+
+/// A `path_find` stream message: an updated set of alternatives.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PathFindMessage {
+    /// Path alternatives, sorted by quality (best first).
+    pub alternatives: Vec<PathAlternative>,
+    /// Destination account from the request.
+    pub destination_account: String,
+    /// Destination amount from the request.
+    pub destination_amount: Amount,
+    /// Source account from the request.
+    pub source_account: String,
+    /// `false` for a quick first answer, `true` once the search for the
+    /// current ledger is complete.
+    pub full_reply: bool,
+    /// Send-max from the request, if one was set.
+    pub send_max: Option<Amount>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_find_create_payload() {
+        let amount = Amount::drops("1000").unwrap();
+        let sub = PathFindSubscription::new("rSource", "rDest", amount);
+        let json = sub.to_value().unwrap();
+
+        assert_eq!(json["command"], "path_find");
+        assert_eq!(json["subcommand"], "create");
+        assert_eq!(json["destination_amount"], "1000");
+        assert!(json.get("send_max").is_none());
+    }
+}
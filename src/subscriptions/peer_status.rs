@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+use crate::request::{XrplRequest, XrplResponse, XrplSubscription};
+
+/// Subscription request for the admin-only `peer_status` stream.
+///
+/// Sends a `peerStatusChange` message whenever a connected peer reports a
+/// change in its ledger state.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::Client;
+/// use xrpl::subscriptions::PeerStatusSubscription;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Client::new("ws://localhost:6006");
+///     let mut session = client.subscription().await?;
+///     let (_resp, mut stream) = session.subscribe(&PeerStatusSubscription::new()).await?;
+///     while let Ok(msg) = stream.recv().await {
+///         println!("peer {:?} at {:?}", msg.action, msg.ledger_index);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct PeerStatusSubscription {
+    streams: [&'static str; 1],
+}
+
+impl PeerStatusSubscription {
+    /// Creates a new subscription to the `peer_status` stream.
+    pub fn new() -> Self {
+        Self { streams: [<Self as XrplSubscription>::STREAM] }
+    }
+}
+
+impl Default for PeerStatusSubscription {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XrplRequest for PeerStatusSubscription {
+    type Response = XrplResponse<PeerStatusSubscriptionResponse>;
+    const COMMAND: &str = "subscribe";
+}
+
+impl XrplSubscription for PeerStatusSubscription {
+    type Message = PeerStatusMessage;
+    const STREAM: &'static str = "peer_status";
+    const MESSAGE_TYPE: &'static str = "peerStatusChange";
+}
+
+/// Initial response returned when subscribing to the `peer_status` stream.
+#[derive(Debug, Deserialize)]
+pub struct PeerStatusSubscriptionResponse {}
+
+/// A `peerStatusChange` stream message.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PeerStatusMessage {
+    /// What happened to the peer.
+    pub action: PeerStatusAction,
+    /// When the change happened, in seconds since the Ripple epoch.
+    pub date: u32,
+    /// Hash of the peer's latest ledger, when known.
+    pub ledger_hash: Option<String>,
+    /// Sequence number of the peer's latest ledger, when known.
+    pub ledger_index: Option<u32>,
+    /// Newest ledger the peer has available.
+    pub ledger_index_max: Option<u32>,
+    /// Oldest ledger the peer has available.
+    pub ledger_index_min: Option<u32>,
+}
+
+/// Kind of change reported by a `peerStatusChange` message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PeerStatusAction {
+    /// The peer closed a ledger version.
+    ClosingLedger,
+    /// The peer built the ledger consensus agreed on.
+    AcceptedLedger,
+    /// The peer switched to a different ledger chain.
+    SwitchedLedger,
+    /// The peer fell behind the rest of the network.
+    LostSync,
+    /// An action this library does not know about.
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+use crate::request::{XrplRequest, XrplResponse, XrplSubscription};
+
+/// Subscription request for the `server` stream.
+///
+/// Sends a `serverStatus` message whenever the server's status or load
+/// factor changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::Client;
+/// use xrpl::subscriptions::ServerSubscription;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Client::new("wss://xrplcluster.com");
+///     let mut session = client.subscription().await?;
+///     let (_resp, mut stream) = session.subscribe(&ServerSubscription::new()).await?;
+///     while let Ok(msg) = stream.recv().await {
+///         println!("{}: load {}/{}", msg.server_status, msg.load_factor, msg.load_base);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerSubscription {
+    streams: [&'static str; 1],
+}
+
+impl ServerSubscription {
+    /// Creates a new subscription to the `server` stream.
+    pub fn new() -> Self {
+        Self { streams: [<Self as XrplSubscription>::STREAM] }
+    }
+}
+
+impl Default for ServerSubscription {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XrplRequest for ServerSubscription {
+    type Response = XrplResponse<ServerSubscriptionResponse>;
+    const COMMAND: &str = "subscribe";
+}
+
+impl XrplSubscription for ServerSubscription {
+    type Message = ServerStatusMessage;
+    const STREAM: &'static str = "server";
+    const MESSAGE_TYPE: &'static str = "serverStatus";
+}
+
+/// Initial response returned when subscribing to the `server` stream.
+#[derive(Debug, Deserialize)]
+pub struct ServerSubscriptionResponse {
+    /// Server name or, for non-admin connections, a hash of it.
+    pub hostid: Option<String>,
+    /// Baseline load level of the server.
+    pub load_base: Option<u32>,
+    /// Current load factor, relative to `load_base`.
+    pub load_factor: Option<u32>,
+    /// Public key the server uses for peer-to-peer communication.
+    pub pubkey_node: Option<String>,
+    /// A random 256-bit hex value.
+    pub random: Option<String>,
+    /// Current server state (e.g. `"full"`, `"syncing"`).
+    pub server_status: Option<String>,
+}
+
+/// A `serverStatus` stream message.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerStatusMessage {
+    /// Reference transaction cost, in drops.
+    pub base_fee: u64,
+    /// Baseline load level of the server.
+    pub load_base: u32,
+    /// Current load factor, relative to `load_base`.
+    pub load_factor: u32,
+    /// Load factor for fee escalation in the open ledger.
+    pub load_factor_fee_escalation: Option<u32>,
+    /// Load factor for getting into the transaction queue.
+    pub load_factor_fee_queue: Option<u32>,
+    /// Reference load factor for fee escalation.
+    pub load_factor_fee_reference: Option<u32>,
+    /// Load factor from the server's own load alone.
+    pub load_factor_server: Option<u32>,
+    /// Current server state (e.g. `"full"`, `"syncing"`).
+    pub server_status: String,
+}
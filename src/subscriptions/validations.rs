@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::request::{XrplRequest, XrplResponse, XrplSubscription};
+
+/// Subscription request for the `validations` stream.
+///
+/// Sends a `validationReceived` message whenever the server receives a
+/// validation vote, whether or not the validator is on its trusted list.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::Client;
+/// use xrpl::subscriptions::ValidationsSubscription;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Client::new("wss://xrplcluster.com");
+///     let mut session = client.subscription().await?;
+///     let (_resp, mut stream) = session.subscribe(&ValidationsSubscription::new()).await?;
+///     while let Ok(msg) = stream.recv().await {
+///         println!("{} validated {}", msg.validation_public_key, msg.ledger_index);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationsSubscription {
+    streams: [&'static str; 1],
+}
+
+impl ValidationsSubscription {
+    /// Creates a new subscription to the `validations` stream.
+    pub fn new() -> Self {
+        Self { streams: [<Self as XrplSubscription>::STREAM] }
+    }
+}
+
+impl Default for ValidationsSubscription {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XrplRequest for ValidationsSubscription {
+    type Response = XrplResponse<ValidationsSubscriptionResponse>;
+    const COMMAND: &str = "subscribe";
+}
+
+impl XrplSubscription for ValidationsSubscription {
+    type Message = ValidationMessage;
+    const STREAM: &'static str = "validations";
+    const MESSAGE_TYPE: &'static str = "validationReceived";
+}
+
+/// Initial response returned when subscribing to the `validations` stream.
+#[derive(Debug, Deserialize)]
+pub struct ValidationsSubscriptionResponse {}
+
+/// A `validationReceived` stream message: one validator's vote on a ledger.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ValidationMessage {
+    /// Amendments the validator votes for; only sent on flag ledgers.
+    pub amendments: Option<Vec<String>>,
+    /// Transaction cost the validator votes for, in drops; only sent on flag
+    /// ledgers.
+    pub base_fee: Option<u64>,
+    /// Random value identifying the validator's running instance.
+    pub cookie: Option<String>,
+    /// Hex of the whole signed validation.
+    pub data: Option<String>,
+    /// Validation flags bit field.
+    pub flags: u32,
+    /// Whether this is a full validation rather than a partial one.
+    pub full: bool,
+    /// Hash of the ledger being validated.
+    pub ledger_hash: String,
+    /// Sequence number of the ledger being validated, as a string.
+    pub ledger_index: String,
+    /// Local load-scaled transaction cost of the validator.
+    pub load_fee: Option<u32>,
+    /// Master public key of the validator, when it signs with an ephemeral key.
+    pub master_key: Option<String>,
+    /// Network ID the validator is on.
+    pub network_id: Option<u32>,
+    /// Account reserve the validator votes for, in drops; only sent on flag
+    /// ledgers.
+    pub reserve_base: Option<u64>,
+    /// Owner reserve the validator votes for, in drops; only sent on flag
+    /// ledgers.
+    pub reserve_inc: Option<u64>,
+    /// rippled version of the validator, encoded as a 64-bit integer string.
+    pub server_version: Option<String>,
+    /// Signature over the validation.
+    pub signature: String,
+    /// When the validation was signed, in seconds since the Ripple epoch.
+    pub signing_time: u32,
+    /// Hash of the last ledger the validator considers fully validated.
+    pub validated_hash: Option<String>,
+    /// Public key the validation was signed with (base58, `n…`).
+    pub validation_public_key: String,
+}
@@ -0,0 +1,360 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{Notify, broadcast, mpsc};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+/// Scripted reply to one request received by a [`MockServer`].
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use serde_json::json;
+/// use xrpl::testing::MockReply;
+///
+/// let ok = MockReply::result(json!({ "ledger_current_index": 7 }));
+/// let slow = ok.clone().with_delay(Duration::from_millis(200));
+/// let missing = MockReply::error("actNotFound", 19, "Account not found.");
+/// let lost = MockReply::silent();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockReply {
+    body: ReplyBody,
+    delay: Duration,
+}
+
+#[derive(Debug, Clone)]
+enum ReplyBody {
+    Result(Value),
+    Error { error: String, error_code: i32, error_message: String },
+    Silent,
+}
+
+impl MockReply {
+    /// Replies with `status: "success"` and the given `result` payload.
+    pub fn result(result: impl Into<Value>) -> Self {
+        Self { body: ReplyBody::Result(result.into()), delay: Duration::ZERO }
+    }
+
+    /// Replies with `status: "error"`, as rippled does for a failed command.
+    pub fn error(
+        error: impl AsRef<str>,
+        error_code: i32,
+        error_message: impl AsRef<str>,
+    ) -> Self {
+        let body = ReplyBody::Error {
+            error: error.as_ref().to_string(),
+            error_code,
+            error_message: error_message.as_ref().to_string(),
+        };
+        Self { body, delay: Duration::ZERO }
+    }
+
+    /// Never replies, so the client's request times out.
+    pub fn silent() -> Self {
+        Self { body: ReplyBody::Silent, delay: Duration::ZERO }
+    }
+
+    /// Waits `delay` before sending the reply. Other requests on the same
+    /// connection are still answered in the meantime.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Builds the wire message answering `request`, or `None` for a silent reply.
+    fn render(&self, request: &Value) -> Option<Value> {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        match &self.body {
+            ReplyBody::Result(result) => Some(json!({
+                "id": id,
+                "type": "response",
+                "status": "success",
+                "api_version": request.get("api_version"),
+                "result": result,
+            })),
+            ReplyBody::Error { error, error_code, error_message } => {
+                Some(json!({
+                    "id": id,
+                    "type": "response",
+                    "status": "error",
+                    "error": error,
+                    "error_code": error_code,
+                    "error_message": error_message,
+                    "request": request,
+                }))
+            }
+            ReplyBody::Silent => None,
+        }
+    }
+}
+
+/// Replies configured for one command.
+#[derive(Default)]
+struct Script {
+    once: VecDeque<MockReply>,
+    always: Option<MockReply>,
+}
+
+#[derive(Default)]
+struct State {
+    scripts: HashMap<String, Script>,
+    requests: Vec<Value>,
+    connections: usize,
+}
+
+impl State {
+    fn reply_for(&mut self, command: &str) -> MockReply {
+        if let Some(script) = self.scripts.get_mut(command) {
+            if let Some(reply) = script.once.pop_front() {
+                return reply;
+            }
+            if let Some(reply) = &script.always {
+                return reply.clone();
+            }
+        }
+        default_reply(command)
+    }
+}
+
+/// `subscribe` and `unsubscribe` succeed unless scripted otherwise, so a
+/// test only needs to script the commands it cares about.
+fn default_reply(command: &str) -> MockReply {
+    match command {
+        "subscribe" | "unsubscribe" => MockReply::result(json!({})),
+        _ => MockReply::error("unknownCmd", 32, "Unknown method."),
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Control {
+    Push(Value),
+    Disconnect,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    received: Notify,
+}
+
+impl Shared {
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// In-process WebSocket server speaking the rippled wire protocol.
+///
+/// Listens on a random local port. Replies are scripted per command with
+/// [`on`](Self::on) and [`once`](Self::once); subscription messages are
+/// injected with [`push`](Self::push), and [`disconnect`](Self::disconnect)
+/// drops every open connection to exercise reconnects. Every request is
+/// recorded for later assertions. The server stops when dropped.
+///
+/// Requires the `testing` cargo feature.
+///
+/// # Example
+/// ```no_run
+/// use serde_json::json;
+/// use xrpl::Client;
+/// use xrpl::request::ledger_current::LedgerCurrentRequest;
+/// use xrpl::testing::{MockReply, MockServer};
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let server = MockServer::start().await?;
+///     server.on(
+///         "ledger_current",
+///         MockReply::result(json!({ "ledger_current_index": 7 })),
+///     );
+///
+///     let client = Client::new(server.url());
+///     let resp = client.request(&LedgerCurrentRequest).await?;
+///     assert_eq!(resp.result()?.ledger_current_index, 7);
+///     Ok(())
+/// }
+/// ```
+pub struct MockServer {
+    url: String,
+    shared: Arc<Shared>,
+    control: broadcast::Sender<Control>,
+    accept: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Binds to `127.0.0.1` on a free port and starts accepting connections.
+    pub async fn start() -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr: SocketAddr = listener.local_addr()?;
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::default()),
+            received: Notify::new(),
+        });
+        let (control, _) = broadcast::channel(64);
+
+        let accept = tokio::spawn(accept_loop(
+            listener,
+            shared.clone(),
+            control.clone(),
+        ));
+
+        Ok(Self { url: format!("ws://{addr}"), shared, control, accept })
+    }
+
+    /// WebSocket URL to pass to [`crate::Client::new`].
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Answers every request for `command` with `reply`, after any queued
+    /// [`once`](Self::once) replies are used up.
+    pub fn on(&self, command: impl AsRef<str>, reply: MockReply) {
+        let mut state = self.shared.lock();
+        let script = state.scripts.entry(command.as_ref().to_string());
+        script.or_default().always = Some(reply);
+    }
+
+    /// Answers the next request for `command` with `reply`. Queued replies
+    /// are used in order before the reply set with [`on`](Self::on).
+    pub fn once(&self, command: impl AsRef<str>, reply: MockReply) {
+        let mut state = self.shared.lock();
+        let script = state.scripts.entry(command.as_ref().to_string());
+        script.or_default().once.push_back(reply);
+    }
+
+    /// Sends a server-initiated message, such as a `ledgerClosed` push, to
+    /// every open connection.
+    pub fn push(&self, message: Value) {
+        let _ = self.control.send(Control::Push(message));
+    }
+
+    /// Drops every open connection without a close handshake. Clients
+    /// reconnect to the same server, which keeps its script.
+    pub fn disconnect(&self) {
+        let _ = self.control.send(Control::Disconnect);
+    }
+
+    /// Every request received so far, in arrival order.
+    pub fn requests(&self) -> Vec<Value> {
+        self.shared.lock().requests.clone()
+    }
+
+    /// Requests received so far for `command`, in arrival order.
+    pub fn requests_for(&self, command: &str) -> Vec<Value> {
+        let state = self.shared.lock();
+        let matching =
+            state.requests.iter().filter(|r| r["command"] == command);
+        matching.cloned().collect()
+    }
+
+    /// Number of WebSocket connections accepted so far.
+    pub fn connections(&self) -> usize {
+        self.shared.lock().connections
+    }
+
+    /// Waits until at least `count` requests for `command` have arrived.
+    ///
+    /// Useful before [`push`](Self::push), so the client has subscribed,
+    /// and after [`disconnect`](Self::disconnect), to see subscriptions
+    /// replayed.
+    pub async fn wait_for_requests(&self, command: &str, count: usize) {
+        loop {
+            let received = self.shared.received.notified();
+            if self.requests_for(command).len() >= count {
+                return;
+            }
+            received.await;
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.accept.abort();
+        // Open connections end once the control channel closes.
+    }
+}
+
+async fn accept_loop(
+    listener: TcpListener,
+    shared: Arc<Shared>,
+    control: broadcast::Sender<Control>,
+) {
+    while let Ok((stream, _)) = listener.accept().await {
+        shared.lock().connections += 1;
+        tokio::spawn(serve(stream, shared.clone(), control.subscribe()));
+    }
+}
+
+/// Serves one connection until it is closed by either side.
+async fn serve(
+    stream: TcpStream,
+    shared: Arc<Shared>,
+    mut control: broadcast::Receiver<Control>,
+) {
+    let Ok(ws) = accept_async(stream).await else { return };
+    let (mut write, mut read) = ws.split();
+    // Delayed replies are sent from their own tasks through this channel.
+    let (reply_tx, mut reply_rx) = mpsc::unbounded_channel::<Value>();
+
+    loop {
+        let outgoing = tokio::select! {
+            msg = read.next() => match msg {
+                Some(Ok(Message::Text(text))) => {
+                    answer(&shared, &text, &reply_tx);
+                    continue;
+                }
+                Some(Ok(Message::Ping(data))) => Message::Pong(data),
+                Some(Ok(_)) => continue,
+                _ => break,
+            },
+            reply = reply_rx.recv() => match reply {
+                Some(reply) => Message::Text(reply.to_string().into()),
+                None => break,
+            },
+            ctl = control.recv() => match ctl {
+                Ok(Control::Push(msg)) => Message::Text(msg.to_string().into()),
+                Ok(Control::Disconnect) => break,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        };
+        if write.send(outgoing).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Records a request and schedules its scripted reply.
+fn answer(
+    shared: &Shared,
+    text: &str,
+    reply_tx: &mpsc::UnboundedSender<Value>,
+) {
+    let Ok(request) = serde_json::from_str::<Value>(text) else { return };
+    let command = request["command"].as_str().unwrap_or_default().to_string();
+
+    let reply = {
+        let mut state = shared.lock();
+        state.requests.push(request.clone());
+        state.reply_for(&command)
+    };
+    shared.received.notify_waiters();
+
+    let Some(message) = reply.render(&request) else { return };
+    if reply.delay.is_zero() {
+        let _ = reply_tx.send(message);
+        return;
+    }
+    let reply_tx = reply_tx.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(reply.delay).await;
+        let _ = reply_tx.send(message);
+    });
+}
@@ -0,0 +1,332 @@
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+
+use futures_util::StreamExt;
+
+use crate::request::account_objects::{AccountObjectType, AccountObjectsRequest};
+use crate::submission::{ReliableSubmission, SubmissionError, SubmissionOutcome};
+use crate::types::SigningContext;
+use crate::types::account_object::AccountObject;
+use crate::types::builders::TicketCreateBuilder;
+use crate::{Client, XrplError};
+
+/// Most tickets one account may hold at a time.
+pub const MAX_TICKETS: u32 = 250;
+
+/// Keeps track of an account's unused `Ticket`s and leases them out, so
+/// transactions can be built, signed and submitted in any order.
+///
+/// [`load`](Self::load) reads the account's tickets from the validated
+/// ledger. [`lease`](Self::lease) (or
+/// [`TransactionBuilder::with_ticket_from`]) hands out the lowest free
+/// ticket; report what became of the transaction with
+/// [`settle`](Self::settle) so the ticket is dropped once used, or returned
+/// to the pool if the transaction never made it into a ledger.
+///
+/// When fewer than the low-water mark are free,
+/// [`lease_or_replenish`](Self::lease_or_replenish) first submits a
+/// `TicketCreate` for another batch and waits for it to validate.
+///
+/// The pool is cheap to clone; clones share state. Use one per account.
+///
+/// [`TransactionBuilder::with_ticket_from`]: crate::types::builders::TransactionBuilder::with_ticket_from
+///
+/// # Example
+/// ```rust,no_run
+/// # use xrpl::types::{Transaction, SigningContext};
+/// # struct MyWallet;
+/// # impl SigningContext for MyWallet {
+/// #     type Error = anyhow::Error;
+/// #     fn sign_transaction(&self, _tx: &Transaction) -> anyhow::Result<String> { Ok(String::new()) }
+/// # }
+/// use xrpl::{Client, tickets::TicketPool, xrp};
+/// use xrpl::types::builders::PaymentBuilder;
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let wallet = MyWallet;
+/// let client = Client::new("wss://xrplcluster.com");
+/// let account = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+/// let pool = TicketPool::new(account).with_low_water_mark(5).with_batch_size(20);
+/// pool.load(&client).await?;
+///
+/// let ticket = pool.lease_or_replenish(&client, &wallet).await?.expect("top-up failed");
+/// let tx = PaymentBuilder::new(account, "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", xrp!(1))
+///     .with_ticket_sequence(ticket)
+///     .fill(&client)
+///     .await?
+///     .build()?;
+/// let outcome = client.submit_and_wait(&tx, &wallet).await?;
+/// pool.settle(ticket, &outcome);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TicketPool {
+    account: Arc<str>,
+    low_water_mark: u32,
+    batch_size: u32,
+    state: Arc<Mutex<State>>,
+    replenishing: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl TicketPool {
+    /// Creates an empty pool for `account`; call [`load`](Self::load) to
+    /// pick up tickets it already owns. Defaults to topping up 10 tickets
+    /// when fewer than 2 are free.
+    pub fn new(account: impl AsRef<str>) -> Self {
+        Self {
+            account: account.as_ref().into(),
+            low_water_mark: 2,
+            batch_size: 10,
+            state: Arc::new(Mutex::new(State::default())),
+            replenishing: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Tops up when fewer than `mark` tickets are free.
+    pub fn with_low_water_mark(mut self, mark: u32) -> Self {
+        self.low_water_mark = mark;
+        self
+    }
+
+    /// Number of tickets each top-up creates, at most [`MAX_TICKETS`].
+    pub fn with_batch_size(mut self, size: u32) -> Self {
+        self.batch_size = size.clamp(1, MAX_TICKETS);
+        self
+    }
+
+    /// The account whose tickets this pool manages.
+    pub fn account(&self) -> &str {
+        &self.account
+    }
+
+    /// Number of tickets free to lease.
+    pub fn available(&self) -> usize {
+        self.lock().available.len()
+    }
+
+    /// Number of tickets leased and not yet settled.
+    pub fn leased(&self) -> usize {
+        self.lock().leased.len()
+    }
+
+    /// Whether fewer than the low-water mark are free.
+    pub fn needs_replenish(&self) -> bool {
+        self.available() < self.low_water_mark as usize
+    }
+
+    /// Reads every `Ticket` the account owns in the validated ledger.
+    ///
+    /// Tickets still leased stay leased; leased tickets that are gone from
+    /// the ledger were used and are forgotten.
+    pub async fn load(&self, client: &Client) -> Result<(), XrplError> {
+        let req = AccountObjectsRequest::new(&*self.account)
+            .with_kind(AccountObjectType::Ticket)
+            .with_ledger_index("validated");
+        let mut objects = client.paginate(req);
+        let mut on_ledger = BTreeSet::new();
+        while let Some(object) = objects.next().await {
+            if let AccountObject::Ticket(ticket) = object? {
+                on_ledger.insert(ticket.ticket_sequence);
+            }
+        }
+        self.lock().load(on_ledger);
+        Ok(())
+    }
+
+    /// Takes the lowest free ticket, or `None` if the pool is empty.
+    pub fn lease(&self) -> Option<u32> {
+        self.lock().lease()
+    }
+
+    /// Like [`lease`](Self::lease), but first tops the pool up if it is
+    /// below the low-water mark. Concurrent callers share one top-up.
+    ///
+    /// Returns `None` only if the pool is empty and the top-up did not
+    /// validate.
+    pub async fn lease_or_replenish<W: SigningContext>(
+        &self,
+        client: &Client,
+        wallet: &W,
+    ) -> Result<Option<u32>, SubmissionError<W::Error>> {
+        if self.needs_replenish() {
+            let _guard = self.replenishing.lock().await;
+            if self.needs_replenish() {
+                self.replenish(client, wallet).await?;
+            }
+        }
+        Ok(self.lease())
+    }
+
+    /// Submits a `TicketCreate` for one batch and waits for it. On
+    /// validation the new tickets join the pool.
+    ///
+    /// The batch shrinks so the account never holds more than
+    /// [`MAX_TICKETS`]; returns `None` without submitting when it already
+    /// does.
+    pub async fn replenish<W: SigningContext>(
+        &self,
+        client: &Client,
+        wallet: &W,
+    ) -> Result<Option<SubmissionOutcome>, SubmissionError<W::Error>> {
+        let count = self.lock().batch(self.batch_size);
+        if count == 0 {
+            return Ok(None);
+        }
+        let tx = TicketCreateBuilder::new(&*self.account, count)
+            .fill(client)
+            .await?
+            .build()?;
+
+        let outcome =
+            ReliableSubmission::new(&tx, wallet).submit(client).await?;
+        if outcome.is_success()
+            && let Some(tickets) = tx.ticket_sequences()
+        {
+            self.lock().available.extend(tickets);
+        }
+        Ok(Some(outcome))
+    }
+
+    /// Records what became of the transaction that used `ticket`.
+    ///
+    /// A transaction in a validated ledger consumed the ticket, whatever its
+    /// result. One that expired, or was rejected without being applied, did
+    /// not, so the ticket returns to the pool - unless the rejection was
+    /// `tefNO_TICKET`.
+    pub fn settle(&self, ticket: u32, outcome: &SubmissionOutcome) {
+        let used = match outcome {
+            SubmissionOutcome::Validated { .. }
+            | SubmissionOutcome::Failed { .. } => true,
+            SubmissionOutcome::Rejected { engine_result, .. } => {
+                engine_result == "tefNO_TICKET"
+            }
+            _ => false,
+        };
+        let mut state = self.lock();
+        if used {
+            state.consume(ticket);
+        } else {
+            state.release(ticket);
+        }
+    }
+
+    /// Marks a leased ticket as used.
+    pub fn consume(&self, ticket: u32) {
+        self.lock().consume(ticket);
+    }
+
+    /// Returns a leased ticket to the pool unused.
+    pub fn release(&self, ticket: u32) {
+        self.lock().release(ticket);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    available: BTreeSet<u32>,
+    leased: BTreeSet<u32>,
+}
+
+impl State {
+    fn load(&mut self, on_ledger: BTreeSet<u32>) {
+        self.leased.retain(|ticket| on_ledger.contains(ticket));
+        self.available = &on_ledger - &self.leased;
+    }
+
+    fn lease(&mut self) -> Option<u32> {
+        let ticket = self.available.pop_first()?;
+        self.leased.insert(ticket);
+        Some(ticket)
+    }
+
+    fn consume(&mut self, ticket: u32) {
+        self.leased.remove(&ticket);
+    }
+
+    fn release(&mut self, ticket: u32) {
+        if self.leased.remove(&ticket) {
+            self.available.insert(ticket);
+        }
+    }
+
+    /// Size of the next `TicketCreate`: `batch_size`, capped so the account
+    /// holds at most [`MAX_TICKETS`].
+    fn batch(&self, batch_size: u32) -> u32 {
+        let held = (self.available.len() + self.leased.len()) as u32;
+        batch_size.min(MAX_TICKETS.saturating_sub(held))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lease_consume_release() {
+        let mut state = State::default();
+        state.load(BTreeSet::from([7, 3, 5]));
+
+        assert_eq!(state.lease(), Some(3));
+        assert_eq!(state.lease(), Some(5));
+        state.consume(3);
+        state.release(5);
+        // Releasing a ticket that is not leased does nothing.
+        state.release(3);
+
+        assert_eq!(state.available, BTreeSet::from([5, 7]));
+        assert!(state.leased.is_empty());
+    }
+
+    #[test]
+    fn test_load_keeps_leases_and_drops_used_tickets() {
+        let mut state = State::default();
+        state.load(BTreeSet::from([3, 5, 7]));
+        state.lease();
+        state.lease();
+
+        // 3 was used on ledger; 5 is still leased; 9 is new.
+        state.load(BTreeSet::from([5, 7, 9]));
+
+        assert_eq!(state.leased, BTreeSet::from([5]));
+        assert_eq!(state.available, BTreeSet::from([7, 9]));
+    }
+
+    #[test]
+    fn test_batch_stops_at_max_tickets() {
+        let mut state = State::default();
+        state.load((1..MAX_TICKETS - 1).collect());
+        state.lease();
+        assert_eq!(state.batch(10), 2);
+
+        state.load((1..=MAX_TICKETS).collect());
+        assert_eq!(state.batch(10), 0);
+    }
+
+    #[test]
+    fn test_settle_by_outcome() {
+        let pool = TicketPool::new("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        pool.lock().load(BTreeSet::from([1, 2, 3]));
+        let (a, b, c) = (pool.lease(), pool.lease(), pool.lease());
+
+        let expired = SubmissionOutcome::Expired {
+            hash: "AB".repeat(32),
+            last_ledger_sequence: 10,
+        };
+        let rejected = |engine_result: &str| SubmissionOutcome::Rejected {
+            engine_result: engine_result.to_string(),
+            engine_result_message: String::new(),
+        };
+        pool.settle(a.unwrap(), &expired);
+        pool.settle(b.unwrap(), &rejected("temBAD_FEE"));
+        pool.settle(c.unwrap(), &rejected("tefNO_TICKET"));
+
+        assert_eq!(pool.lock().available, BTreeSet::from([1, 2]));
+        assert_eq!(pool.leased(), 0);
+    }
+}
@@ -1,35 +1,66 @@
-use serde::{Deserialize, Serialize};
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
-use super::{Amount, XChainBridge};
+use crate::request::account_info::AccountRoot;
+use super::{Amm, Amount, Asset, SignerEntryWrapper, XChainBridge};
 
-/// Any ledger object that an account can own, discriminated by `LedgerEntryType`.
+/// Any ledger object, discriminated by `LedgerEntryType`.
 ///
-/// Returned inside the `account_objects` array of the `account_objects` RPC command.
-/// Match on this enum to access type-specific fields without casting.
+/// Returned inside the `account_objects` array of the `account_objects` RPC
+/// command, the `state` array of `ledger_data` and the `node` of
+/// `ledger_entry`. Match on this enum to access type-specific fields without
+/// casting.
+///
+/// Entry types this library doesn't model, such as `FeeSettings`,
+/// deserialize into [`Unknown`](Self::Unknown), as do entries returned in
+/// binary form. A recognized `LedgerEntryType` whose fields don't
+/// match its variant fails deserialization instead.
+///
+/// Serializes back to the ledger JSON, `LedgerEntryType` included. Fields a
+/// typed variant doesn't model are not preserved.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use xrpl::types::account_object::AccountObject;
-/// // Typically deserialized from the account_objects RPC response.
+///
+/// fn escrowed_drops(objects: &[AccountObject]) -> u64 {
+///     objects
+///         .iter()
+///         .filter_map(|object| match object {
+///             AccountObject::Escrow(escrow) => escrow.amount.parse::<u64>().ok(),
+///             _ => None,
+///         })
+///         .sum()
+/// }
 /// ```
-#[derive(Debug, Deserialize)]
-#[serde(tag = "LedgerEntryType")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
 pub enum AccountObject {
+    /// An account's root entry: its XRP balance, sequence and settings.
+    AccountRoot(AccountRoot),
+    /// An AMM pool, owned by the pool's special account.
+    Amm(Amm),
     /// A cross-chain bridge door entry owned by this account.
     Bridge(Bridge),
     /// A deferred payment check that can be cashed by the destination.
     Check(Check),
     /// A verifiable credential issued to or by this account.
     Credential(Credential),
+    /// A set of permissions this account has delegated to another account.
+    Delegate(Delegate),
     /// A deposit pre-authorization granted by this account.
     DepositPreauth(DepositPreauth),
     /// A Decentralized Identifier (DID) document anchored to this account.
-    #[serde(rename = "DID")]
     Did(Did),
+    /// A page of an owner directory or an order book directory.
+    DirectoryNode(DirectoryNode),
     /// A time-locked or condition-locked XRP escrow.
     Escrow(Escrow),
+    /// A list of recent ledger hashes, or of flag ledger hashes.
+    LedgerHashes(LedgerHashes),
     /// A Multi-Purpose Token holding owned by this account.
     MPToken(MPToken),
     /// An MPT issuance created by this account.
@@ -44,23 +75,170 @@ pub enum AccountObject {
     Oracle(Oracle),
     /// A payment channel funded by this account.
     PayChannel(PayChannel),
+    /// A permissioned domain whose members hold one of its accepted credentials.
+    PermissionedDomain(PermissionedDomain),
     /// A trust line (RippleState) between this account and a counterparty.
     RippleState(RippleState),
     /// A multi-signature signer list associated with this account.
     SignerList(SignerList),
     /// A sequence-number ticket reserved for a future transaction.
     Ticket(Ticket),
+    /// A single asset vault created by this account.
+    Vault(Vault),
     /// A cross-chain claim ID owned by this account.
     XChainOwnedClaimID(XChainOwnedClaimID),
     /// A cross-chain create-account claim ID owned by this account.
     XChainOwnedCreateAccountClaimID(XChainOwnedCreateAccountClaimID),
+    /// Catch-all for entry types not yet modelled and for binary entries.
+    Unknown {
+        /// The raw `LedgerEntryType` string; empty when the entry has none.
+        ledger_entry_type: String,
+        /// The full JSON object, preserved for inspection.
+        value: Value,
+    },
+}
+
+impl AccountObject {
+    /// Returns the `LedgerEntryType` of the object, as named on the wire.
+    pub fn ledger_entry_type(&self) -> &str {
+        match self {
+            Self::AccountRoot(_) => "AccountRoot",
+            Self::Amm(_) => "AMM",
+            Self::Bridge(_) => "Bridge",
+            Self::Check(_) => "Check",
+            Self::Credential(_) => "Credential",
+            Self::Delegate(_) => "Delegate",
+            Self::DepositPreauth(_) => "DepositPreauth",
+            Self::Did(_) => "DID",
+            Self::DirectoryNode(_) => "DirectoryNode",
+            Self::Escrow(_) => "Escrow",
+            Self::LedgerHashes(_) => "LedgerHashes",
+            Self::MPToken(_) => "MPToken",
+            Self::MPTokenIssuance(_) => "MPTokenIssuance",
+            Self::NFTokenOffer(_) => "NFTokenOffer",
+            Self::NFTokenPage(_) => "NFTokenPage",
+            Self::Offer(_) => "Offer",
+            Self::Oracle(_) => "Oracle",
+            Self::PayChannel(_) => "PayChannel",
+            Self::PermissionedDomain(_) => "PermissionedDomain",
+            Self::RippleState(_) => "RippleState",
+            Self::SignerList(_) => "SignerList",
+            Self::Ticket(_) => "Ticket",
+            Self::Vault(_) => "Vault",
+            Self::XChainOwnedClaimID(_) => "XChainOwnedClaimID",
+            Self::XChainOwnedCreateAccountClaimID(_) => {
+                "XChainOwnedCreateAccountClaimID"
+            }
+            Self::Unknown { ledger_entry_type, .. } => ledger_entry_type,
+        }
+    }
+}
+
+impl Serialize for AccountObject {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut value = match self {
+            Self::AccountRoot(object) => serde_json::to_value(object),
+            Self::Amm(object) => serde_json::to_value(object),
+            Self::Bridge(object) => serde_json::to_value(object),
+            Self::Check(object) => serde_json::to_value(object),
+            Self::Credential(object) => serde_json::to_value(object),
+            Self::Delegate(object) => serde_json::to_value(object),
+            Self::DepositPreauth(object) => serde_json::to_value(object),
+            Self::Did(object) => serde_json::to_value(object),
+            Self::DirectoryNode(object) => serde_json::to_value(object),
+            Self::Escrow(object) => serde_json::to_value(object),
+            Self::LedgerHashes(object) => serde_json::to_value(object),
+            Self::MPToken(object) => serde_json::to_value(object),
+            Self::MPTokenIssuance(object) => serde_json::to_value(object),
+            Self::NFTokenOffer(object) => serde_json::to_value(object),
+            Self::NFTokenPage(object) => serde_json::to_value(object),
+            Self::Offer(object) => serde_json::to_value(object),
+            Self::Oracle(object) => serde_json::to_value(object),
+            Self::PayChannel(object) => serde_json::to_value(object),
+            Self::PermissionedDomain(object) => serde_json::to_value(object),
+            Self::RippleState(object) => serde_json::to_value(object),
+            Self::SignerList(object) => serde_json::to_value(object),
+            Self::Ticket(object) => serde_json::to_value(object),
+            Self::Vault(object) => serde_json::to_value(object),
+            Self::XChainOwnedClaimID(object) => serde_json::to_value(object),
+            Self::XChainOwnedCreateAccountClaimID(object) => {
+                serde_json::to_value(object)
+            }
+            Self::Unknown { value, .. } => return value.serialize(serializer),
+        }
+        .map_err(serde::ser::Error::custom)?;
+        if let Value::Object(fields) = &mut value {
+            fields.insert(
+                "LedgerEntryType".to_string(),
+                self.ledger_entry_type().into(),
+            );
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        fn typed<T: DeserializeOwned, E: Error>(
+            value: Value,
+            variant: fn(T) -> AccountObject,
+        ) -> Result<AccountObject, E> {
+            serde_json::from_value(value).map(variant).map_err(E::custom)
+        }
+
+        let value = Value::deserialize(deserializer)?;
+        let ledger_entry_type =
+            value.get("LedgerEntryType").and_then(Value::as_str).unwrap_or("");
+
+        match ledger_entry_type {
+            "AccountRoot" => typed(value, Self::AccountRoot),
+            "AMM" => typed(value, Self::Amm),
+            "Bridge" => typed(value, Self::Bridge),
+            "Check" => typed(value, Self::Check),
+            "Credential" => typed(value, Self::Credential),
+            "Delegate" => typed(value, Self::Delegate),
+            "DepositPreauth" => typed(value, Self::DepositPreauth),
+            "DID" => typed(value, Self::Did),
+            "DirectoryNode" => typed(value, Self::DirectoryNode),
+            "Escrow" => typed(value, Self::Escrow),
+            "LedgerHashes" => typed(value, Self::LedgerHashes),
+            "MPToken" => typed(value, Self::MPToken),
+            "MPTokenIssuance" => typed(value, Self::MPTokenIssuance),
+            "NFTokenOffer" => typed(value, Self::NFTokenOffer),
+            "NFTokenPage" => typed(value, Self::NFTokenPage),
+            "Offer" => typed(value, Self::Offer),
+            "Oracle" => typed(value, Self::Oracle),
+            "PayChannel" => typed(value, Self::PayChannel),
+            "PermissionedDomain" => typed(value, Self::PermissionedDomain),
+            "RippleState" => typed(value, Self::RippleState),
+            "SignerList" => typed(value, Self::SignerList),
+            "Ticket" => typed(value, Self::Ticket),
+            "Vault" => typed(value, Self::Vault),
+            "XChainOwnedClaimID" => typed(value, Self::XChainOwnedClaimID),
+            "XChainOwnedCreateAccountClaimID" => {
+                typed(value, Self::XChainOwnedCreateAccountClaimID)
+            }
+            other => Ok(Self::Unknown {
+                ledger_entry_type: other.to_string(),
+                value,
+            }),
+        }
+    }
 }
 
 /// Fields present on every ledger object; flattened into each concrete type.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Common {
-    /// Bitfield of object-specific flags.
+    /// Bitfield of object-specific flags. Metadata omits it when zero.
+    #[serde(default)]
     pub flags: u32,
     /// Ledger object index (hash), when included in responses.
     pub index: Option<String>,
@@ -75,7 +253,8 @@ pub struct Common {
 }
 
 /// A deferred payment check that the destination can cash for up to `send_max`.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Check {
     /// r-address of the account that created the check.
@@ -104,7 +283,8 @@ pub struct Check {
 }
 
 /// A verifiable credential issued to `account` by `issuer`.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Credential {
     /// r-address of the credential subject (holder).
@@ -124,8 +304,43 @@ pub struct Credential {
     pub common: Common,
 }
 
+/// Permissions that `account` has granted to `authorize`, letting it send
+/// some transaction types on the account's behalf.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Delegate {
+    /// r-address of the account that granted the permissions.
+    pub account: String,
+    /// r-address of the account allowed to act on its behalf.
+    pub authorize: String,
+    /// Granted permissions: transaction type names or granular permission names.
+    pub permissions: Vec<PermissionWrapper>,
+
+    /// Shared ledger-object metadata (flags, index, previous transaction reference).
+    #[serde(flatten)]
+    pub common: Common,
+}
+
+/// One permission granted by a [`Delegate`] entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Permission {
+    /// Transaction type name (e.g. `"Payment"`) or granular permission name
+    /// (e.g. `"TrustlineAuthorize"`).
+    pub permission_value: String,
+}
+
+/// Wire-format wrapper that nests a [`Permission`] under the `Permission` key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionWrapper {
+    /// The contained permission.
+    #[serde(rename = "Permission")]
+    pub permission: Permission,
+}
+
 /// A deposit pre-authorization allowing a specific sender to make payments.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DepositPreauth {
     /// r-address of the account that granted the pre-authorization.
@@ -141,7 +356,8 @@ pub struct DepositPreauth {
 }
 
 /// A Decentralized Identifier (DID) document anchored on the XRPL.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Did {
     /// r-address of the DID subject.
@@ -161,8 +377,50 @@ pub struct Did {
     pub common: Common,
 }
 
+/// A page of a directory: an account's owner directory, listing the objects
+/// it owns, or an order book directory, listing offers at one quality.
+///
+/// The `taker_*` and `exchange_rate` fields are only set on order book pages,
+/// and `owner` only on owner directory pages.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DirectoryNode {
+    /// Index of the first page of this directory.
+    pub root_index: String,
+    /// Indexes of the ledger objects listed on this page.
+    pub indexes: Vec<String>,
+    /// Page number of the next page, when there is one (hex).
+    pub index_next: Option<String>,
+    /// Page number of the previous page, when there is one (hex).
+    pub index_previous: Option<String>,
+    /// r-address of the account that owns the objects listed.
+    pub owner: Option<String>,
+    /// Exchange rate of the offers listed, encoded in the directory index (hex).
+    pub exchange_rate: Option<String>,
+    /// Currency code of the asset the offers' takers pay.
+    pub taker_pays_currency: Option<String>,
+    /// Issuer account ID of the asset the offers' takers pay.
+    pub taker_pays_issuer: Option<String>,
+    /// Currency code of the asset the offers' takers get.
+    pub taker_gets_currency: Option<String>,
+    /// Issuer account ID of the asset the offers' takers get.
+    pub taker_gets_issuer: Option<String>,
+    /// NFToken whose buy or sell offers this directory lists.
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: Option<String>,
+    /// Permissioned domain the listed offers belong to.
+    #[serde(rename = "DomainID")]
+    pub domain_id: Option<String>,
+
+    /// Shared ledger-object metadata (flags, index, previous transaction reference).
+    #[serde(flatten)]
+    pub common: Common,
+}
+
 /// An XRP amount held in escrow, releasable by time or crypto-condition.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Escrow {
     /// r-address of the account that created the escrow.
@@ -189,8 +447,26 @@ pub struct Escrow {
     pub common: Common,
 }
 
+/// Hashes of previous ledgers: the 256 most recent, or every 256th ledger
+/// for the older "flag ledger" lists.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LedgerHashes {
+    /// Sequence of the first ledger listed (older entries only).
+    pub first_ledger_sequence: Option<u32>,
+    /// Sequence of the last ledger listed.
+    pub last_ledger_sequence: Option<u32>,
+    /// Ledger hashes, oldest first.
+    pub hashes: Vec<String>,
+
+    /// Shared ledger-object metadata (flags, index, previous transaction reference).
+    #[serde(flatten)]
+    pub common: Common,
+}
+
 /// An MPT holding owned by an account for a specific issuance.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct MPToken {
     /// r-address of the token holder.
@@ -207,7 +483,8 @@ pub struct MPToken {
 }
 
 /// An MPT issuance definition created by `issuer`.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct MPTokenIssuance {
     /// r-address of the account that created the issuance.
@@ -230,7 +507,8 @@ pub struct MPTokenIssuance {
 }
 
 /// An offer to buy or sell a specific NFToken.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenOffer {
     /// Price offered (XRP drops string or issued-currency object).
@@ -254,14 +532,15 @@ pub struct NFTokenOffer {
 }
 
 /// A page of up to 32 NFTokens stored in an account's NFToken directory.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenPage {
     /// Low boundary of the next page's NFToken IDs, used for pagination.
     pub next_page_min: Option<String>,
-    /// Array of NFToken objects on this page.
+    /// NFTokens on this page, sorted by ID.
     #[serde(rename = "NFTokens")]
-    pub nftokens: Value,
+    pub nftokens: Vec<NFTokenWrapper>,
     /// High boundary of the previous page's NFToken IDs, used for pagination.
     pub previous_page_min: Option<String>,
 
@@ -270,15 +549,39 @@ pub struct NFTokenPage {
     pub common: Common,
 }
 
+/// One NFToken held on an [`NFTokenPage`].
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NFToken {
+    /// 256-bit hex identifier of the NFToken.
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: String,
+    /// Hex-encoded URI pointing to the token's data or metadata.
+    #[serde(rename = "URI")]
+    pub uri: Option<String>,
+}
+
+/// Wire-format wrapper that nests an [`NFToken`] under the `NFToken` key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NFTokenWrapper {
+    /// The contained NFToken.
+    #[serde(rename = "NFToken")]
+    pub nftoken: NFToken,
+}
+
 /// A DEX offer to exchange `taker_pays` for `taker_gets`.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Offer {
     /// r-address of the account that placed the offer.
     pub account: String,
     /// Hash of the order-book directory this offer belongs to.
     pub book_directory: String,
-    /// Index of this offer within its order-book directory page.
+    /// Index of this offer within its order-book directory page. Metadata
+    /// omits it when zero.
+    #[serde(default)]
     pub book_node: String,
     /// Ripple epoch time after which the offer is automatically removed.
     pub expiration: Option<u32>,
@@ -295,7 +598,8 @@ pub struct Offer {
 }
 
 /// A price oracle entry publishing one or more asset price feeds on-ledger.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Oracle {
     /// r-address of the account that controls this oracle.
@@ -321,7 +625,8 @@ pub struct Oracle {
 }
 
 /// A unidirectional payment channel funded by `account` for streaming payments.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PayChannel {
     /// r-address of the channel funder (source).
@@ -352,17 +657,56 @@ pub struct PayChannel {
     pub common: Common,
 }
 
+/// A permissioned domain: the set of accounts holding one of its accepted
+/// credentials.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PermissionedDomain {
+    /// r-address of the account that created the domain.
+    pub owner: String,
+    /// Credentials that grant membership; holding any one is enough.
+    pub accepted_credentials: Vec<AcceptedCredentialWrapper>,
+    /// Sequence number of the PermissionedDomainSet transaction that created the domain.
+    pub sequence: u32,
+
+    /// Shared ledger-object metadata (flags, index, previous transaction reference).
+    #[serde(flatten)]
+    pub common: Common,
+}
+
+/// A credential accepted by a [`PermissionedDomain`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AcceptedCredential {
+    /// r-address of the credential issuer.
+    pub issuer: String,
+    /// Hex-encoded credential type identifier.
+    pub credential_type: String,
+}
+
+/// Wire-format wrapper that nests an [`AcceptedCredential`] under the
+/// `Credential` key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcceptedCredentialWrapper {
+    /// The contained credential.
+    #[serde(rename = "Credential")]
+    pub credential: AcceptedCredential,
+}
+
 /// A trust line between two accounts, tracking the issued-currency balance and limits.
 ///
 /// The "low" side is the account whose r-address sorts lexicographically lower.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct RippleState {
     /// Current balance of the trust line (positive = low side holds, negative = high side holds).
     pub balance: Amount,
     /// Maximum balance the high-side account is willing to hold.
     pub high_limit: Amount,
-    /// Index into the high-side account's owner directory.
+    /// Index into the high-side account's owner directory. Metadata omits it
+    /// when zero.
+    #[serde(default)]
     pub high_node: String,
     /// Quality applied to incoming transfers on the high side (rate in millionths).
     pub high_quality_in: Option<u32>,
@@ -374,7 +718,9 @@ pub struct RippleState {
     pub locked_balance: Option<Amount>,
     /// Maximum balance the low-side account is willing to hold.
     pub low_limit: Amount,
-    /// Index into the low-side account's owner directory.
+    /// Index into the low-side account's owner directory. Metadata omits it
+    /// when zero.
+    #[serde(default)]
     pub low_node: String,
     /// Quality applied to incoming transfers on the low side (rate in millionths).
     pub low_quality_in: Option<u32>,
@@ -387,11 +733,11 @@ pub struct RippleState {
 }
 
 /// A multi-signature signer list defining the accounts and quorum for an account.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SignerList {
     /// Ordered list of signers and their weights.
-    pub signer_entries: Vec<SignerEntry>,
+    pub signer_entries: Vec<SignerEntryWrapper>,
     /// Always `0` - reserved for future use.
     #[serde(rename = "SignerListID")]
     pub signer_list_id: u32,
@@ -440,7 +786,7 @@ impl SignerEntry {
 }
 
 /// A sequence-number ticket that reserves a future transaction slot.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Ticket {
     /// r-address of the account that created the ticket.
@@ -453,8 +799,48 @@ pub struct Ticket {
     pub common: Common,
 }
 
+/// A single asset vault that pools deposits of `asset` and issues shares,
+/// as an MPT, in return.
+///
+/// Totals are decimal strings and are absent while zero.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Vault {
+    /// r-address of the account that created the vault.
+    pub owner: String,
+    /// r-address of the vault's pseudo-account, which holds the assets.
+    pub account: String,
+    /// Asset the vault holds.
+    pub asset: Asset,
+    /// Total value of the vault, including assets lent out.
+    pub assets_total: Option<String>,
+    /// Assets available for withdrawal.
+    pub assets_available: Option<String>,
+    /// Maximum assets the vault may hold; absent when unlimited.
+    pub assets_maximum: Option<String>,
+    /// Losses not yet realized, which reduce the share value on withdrawal.
+    pub loss_unrealized: Option<String>,
+    /// 48-character hex ID of the MPT issuance of the vault's shares.
+    #[serde(rename = "ShareMPTID")]
+    pub share_mpt_id: String,
+    /// Withdrawal strategy; `1` is first come, first served.
+    pub withdrawal_policy: u8,
+    /// Decimal places of the shares for token assets.
+    pub scale: Option<u8>,
+    /// Hex-encoded arbitrary data set by the owner.
+    pub data: Option<String>,
+    /// Sequence number of the VaultCreate transaction.
+    pub sequence: u32,
+
+    /// Shared ledger-object metadata (flags, index, previous transaction reference).
+    #[serde(flatten)]
+    pub common: Common,
+}
+
 /// A cross-chain bridge door object managed by `account`.
-#[derive(Debug, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Bridge {
     /// r-address of the bridge door account on this chain.
@@ -482,7 +868,7 @@ pub struct Bridge {
 }
 
 /// A cross-chain claim ID that collects attestations for a pending bridge transfer.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainOwnedClaimID {
     /// r-address of the account that created this claim ID.
@@ -505,7 +891,7 @@ pub struct XChainOwnedClaimID {
 }
 
 /// A cross-chain claim ID for a bridge transfer that creates a new account on the destination chain.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainOwnedCreateAccountClaimID {
     /// r-address of the account that initiated this account-create transfer.
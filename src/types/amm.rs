@@ -73,7 +73,7 @@ pub struct AuctionSlot {
     pub account: String,
     /// Additional accounts granted the discounted fee alongside the slot holder.
     #[serde(rename = "AuthAccounts")]
-    pub auth_accounts: Option<Vec<AuthAccountEntry>>,
+    pub auth_accounts: Option<Vec<AuthAccountWrapper>>,
     /// Trading fee charged to the slot holder, in units of 1/100,000.
     #[serde(rename = "DiscountedFee")]
     pub discounted_fee: Option<u32>,
@@ -87,15 +87,15 @@ pub struct AuctionSlot {
 
 /// Ledger object representing an Automated Market Maker (AMM) pool.
 ///
-/// Returned by the `amm_info` RPC command. Holds the full on-chain state of a
-/// two-asset constant-product pool, including the current LP-token supply,
-/// trading fee, and any active auction slot.
+/// Returned as [`AccountObject::Amm`](super::AccountObject::Amm). Holds the
+/// full on-chain state of a two-asset constant-product pool, including the
+/// current LP-token supply, trading fee, and any active auction slot.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use xrpl::types::amm::Amm;
-/// // Typically obtained via the amm_info WebSocket command response.
+/// // Typically obtained from ledger_entry, ledger_data or account_objects.
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Amm {
@@ -1,5 +1,7 @@
+use std::cmp::Ordering;
 use std::fmt;
 use serde::{Deserialize, Serialize};
+use super::decimal::{ArithmeticError, XrplDecimal};
 use super::validation::{
     ValidationError, validate_address, validate_currency_code, validate_mpt_id,
     validate_amount_string,
@@ -345,6 +347,9 @@ impl Amount {
     }
 
     /// Returns the amount as a decimal. For XRP, converts drops to XRP units. Returns `None` for MPTs.
+    ///
+    /// The result is an approximation; use [`to_xrpl_decimal`](Self::to_xrpl_decimal)
+    /// or the `checked_*` methods for exact math.
     pub fn to_decimal(&self) -> Option<f64> {
         match self {
             Amount::Xrpl(value) => value
@@ -357,6 +362,219 @@ impl Amount {
     }
 }
 
+/// Exact arithmetic between amounts of the same asset.
+///
+/// XRP (in drops) and MPT amounts are integers and stay exact; results that
+/// need rounding are rounded half to even. Token amounts use
+/// [`XrplDecimal`], matching the ledger's 16-digit precision.
+///
+/// # Example
+/// ```rust
+/// use xrpl::{drops, issued, types::XrplDecimal};
+///
+/// let balance = drops!(10_000_000);
+/// let fee = drops!(12).checked_mul("1.5".parse::<XrplDecimal>()?)?;
+/// assert_eq!(balance.checked_sub(&fee)?, drops!(9_999_982));
+///
+/// let a = issued!(0.1, "USD", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+/// let b = issued!(0.2, "USD", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+/// assert_eq!(a.checked_add(&b)?.value(), "0.3");
+/// assert!(a.checked_cmp(&b)?.is_lt());
+/// assert!(a.checked_add(&balance).is_err());
+/// # Ok::<(), xrpl::types::ArithmeticError>(())
+/// ```
+impl Amount {
+    /// Parses [`value`](Self::value) as an [`XrplDecimal`]: drops for XRP,
+    /// token units otherwise.
+    pub fn to_xrpl_decimal(&self) -> Result<XrplDecimal, ArithmeticError> {
+        self.value().parse()
+    }
+
+    /// `self + other`. Fails with [`ArithmeticError::AssetMismatch`] unless
+    /// both amounts are of the same asset.
+    pub fn checked_add(
+        &self,
+        other: &Amount,
+    ) -> Result<Amount, ArithmeticError> {
+        self.ensure_same_asset(other)?;
+        match self {
+            Amount::IssuedCurrency { .. } => self.with_decimal(
+                self.to_xrpl_decimal()?
+                    .checked_add(other.to_xrpl_decimal()?)?,
+            ),
+            _ => self.with_integer(
+                i128::from(self.integer()?) + i128::from(other.integer()?),
+            ),
+        }
+    }
+
+    /// `self - other`. Fails with [`ArithmeticError::AssetMismatch`] unless
+    /// both amounts are of the same asset.
+    pub fn checked_sub(
+        &self,
+        other: &Amount,
+    ) -> Result<Amount, ArithmeticError> {
+        self.ensure_same_asset(other)?;
+        match self {
+            Amount::IssuedCurrency { .. } => self.with_decimal(
+                self.to_xrpl_decimal()?
+                    .checked_sub(other.to_xrpl_decimal()?)?,
+            ),
+            _ => self.with_integer(
+                i128::from(self.integer()?) - i128::from(other.integer()?),
+            ),
+        }
+    }
+
+    /// Scales the amount by `factor`, e.g. a fee multiplier or a rate.
+    pub fn checked_mul(
+        &self,
+        factor: XrplDecimal,
+    ) -> Result<Amount, ArithmeticError> {
+        match self {
+            Amount::IssuedCurrency { .. } => {
+                self.with_decimal(self.to_xrpl_decimal()?.checked_mul(factor)?)
+            }
+            _ => {
+                let product =
+                    i128::from(self.integer()?) * i128::from(factor.mantissa());
+                self.with_integer(scale(product, factor.exponent())?)
+            }
+        }
+    }
+
+    /// Divides the amount by `divisor`.
+    pub fn checked_div(
+        &self,
+        divisor: XrplDecimal,
+    ) -> Result<Amount, ArithmeticError> {
+        if divisor.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        match self {
+            Amount::IssuedCurrency { .. } => {
+                self.with_decimal(self.to_xrpl_decimal()?.checked_div(divisor)?)
+            }
+            _ => {
+                let value = i128::from(self.integer()?);
+                let mantissa = i128::from(divisor.mantissa());
+                let exponent = divisor.exponent();
+                let quotient = if exponent <= 0 {
+                    let numerator = 10i128
+                        .checked_pow(exponent.unsigned_abs())
+                        .and_then(|p| p.checked_mul(value))
+                        .ok_or(ArithmeticError::Overflow)?;
+                    div_round(numerator, mantissa)
+                } else {
+                    match 10i128
+                        .checked_pow(exponent.unsigned_abs())
+                        .and_then(|p| p.checked_mul(mantissa))
+                    {
+                        Some(d) => div_round(value, d),
+                        None => 0,
+                    }
+                };
+                self.with_integer(quotient)
+            }
+        }
+    }
+
+    /// Compares two amounts of the same asset by value.
+    ///
+    /// `Amount` does not implement [`PartialOrd`] because its [`PartialEq`]
+    /// compares the value strings (`"1.5"` differs from `"1.50"`).
+    pub fn checked_cmp(
+        &self,
+        other: &Amount,
+    ) -> Result<Ordering, ArithmeticError> {
+        self.ensure_same_asset(other)?;
+        match self {
+            Amount::IssuedCurrency { .. } => {
+                Ok(self.to_xrpl_decimal()?.cmp(&other.to_xrpl_decimal()?))
+            }
+            _ => Ok(self.integer()?.cmp(&other.integer()?)),
+        }
+    }
+
+    fn ensure_same_asset(&self, other: &Amount) -> Result<(), ArithmeticError> {
+        let same = match (self, other) {
+            (Amount::Xrpl(_), Amount::Xrpl(_)) => true,
+            (
+                Amount::IssuedCurrency { currency, issuer, .. },
+                Amount::IssuedCurrency { currency: c, issuer: i, .. },
+            ) => currency == c && issuer == i,
+            (
+                Amount::Mpt { mpt_issuance_id, .. },
+                Amount::Mpt { mpt_issuance_id: id, .. },
+            ) => mpt_issuance_id == id,
+            _ => false,
+        };
+        if same { Ok(()) } else { Err(ArithmeticError::AssetMismatch) }
+    }
+
+    /// The value of an XRP or MPT amount, which is always an integer.
+    fn integer(&self) -> Result<i64, ArithmeticError> {
+        self.value().parse().map_err(|_| {
+            ArithmeticError::InvalidNumber(self.value().to_string())
+        })
+    }
+
+    fn with_integer(&self, value: i128) -> Result<Amount, ArithmeticError> {
+        let value = i64::try_from(value)
+            .map_err(|_| ArithmeticError::Overflow)?
+            .to_string();
+        Ok(match self {
+            Amount::Xrpl(_) => Amount::Xrpl(value),
+            Amount::Mpt { mpt_issuance_id, .. } => {
+                Amount::Mpt { value, mpt_issuance_id: mpt_issuance_id.clone() }
+            }
+            Amount::IssuedCurrency { .. } => {
+                unreachable!("tokens use XrplDecimal")
+            }
+        })
+    }
+
+    fn with_decimal(
+        &self,
+        value: XrplDecimal,
+    ) -> Result<Amount, ArithmeticError> {
+        let mut amount = self.clone();
+        if let Amount::IssuedCurrency { value: v, .. } = &mut amount {
+            *v = value.to_string();
+        }
+        Ok(amount)
+    }
+}
+
+/// `value × 10^exponent`, rounded half to even to an integer.
+fn scale(value: i128, exponent: i32) -> Result<i128, ArithmeticError> {
+    let power = 10i128.checked_pow(exponent.unsigned_abs());
+    if exponent >= 0 {
+        return power
+            .and_then(|p| p.checked_mul(value))
+            .ok_or(ArithmeticError::Overflow);
+    }
+    Ok(power.map_or(0, |p| div_round(value, p)))
+}
+
+/// `numerator ÷ divisor`, rounded half to even.
+fn div_round(numerator: i128, divisor: i128) -> i128 {
+    let quotient = numerator / divisor;
+    let remainder = (numerator % divisor).unsigned_abs() * 2;
+    let away = match remainder.cmp(&divisor.unsigned_abs()) {
+        Ordering::Greater => true,
+        Ordering::Equal => quotient % 2 != 0,
+        Ordering::Less => false,
+    };
+    if !away {
+        quotient
+    } else if (numerator < 0) == (divisor < 0) {
+        quotient + 1
+    } else {
+        quotient - 1
+    }
+}
+
 impl fmt::Display for Amount {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -505,7 +723,7 @@ mod tests {
         let usd = Amount::issued_currency(
             "100.5",
             "USD",
-            "rXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
         )
         .unwrap();
 
@@ -666,4 +884,57 @@ mod tests {
         .unwrap();
         assert_eq!(format!("{mpt_amount}"), "1000000 MPT (0000012F...1BED47)");
     }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        const ISSUER: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+        const MPT_ID: &str = "0000012FFD9EE5DA93AC614B4DB94D7E0FCE415CA51BED47";
+        let dec = |text: &str| text.parse::<XrplDecimal>().unwrap();
+
+        let fee = Amount::drops("15").unwrap();
+        assert_eq!(
+            fee.checked_mul(dec("0.5")).unwrap(),
+            Amount::drops("8").unwrap()
+        );
+        assert_eq!(
+            fee.checked_div(dec("2")).unwrap(),
+            Amount::drops("8").unwrap()
+        );
+        assert_eq!(
+            fee.checked_div(dec("1e-30")),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(
+            Amount::drops("9223372036854775807").unwrap().checked_add(&fee),
+            Err(ArithmeticError::Overflow)
+        );
+
+        let tokens = Amount::mpt("10", MPT_ID).unwrap();
+        assert_eq!(
+            tokens
+                .checked_sub(&Amount::mpt("25", MPT_ID).unwrap())
+                .unwrap()
+                .value(),
+            "-15"
+        );
+
+        let usd = |value: &str| {
+            Amount::issued_currency(value, "USD", ISSUER).unwrap()
+        };
+        assert_eq!(
+            usd("1").checked_div(dec("3")).unwrap().value(),
+            "0.3333333333333333"
+        );
+        assert_eq!(usd("1.50").checked_cmp(&usd("1.5")), Ok(Ordering::Equal));
+        assert_eq!(
+            usd("1").checked_add(&fee),
+            Err(ArithmeticError::AssetMismatch)
+        );
+        assert_eq!(
+            usd("1").checked_cmp(
+                &Amount::issued_currency("1", "EUR", ISSUER).unwrap()
+            ),
+            Err(ArithmeticError::AssetMismatch)
+        );
+    }
 }
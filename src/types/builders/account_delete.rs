@@ -1,4 +1,6 @@
-use super::{BuildError, TransactionBuilder, TransactionTypeBuilder};
+use super::{
+    BuildError, TransactionBuilder, TransactionTypeBuilder, split_x_address,
+};
 use crate::types::{
     validation::validate_address, transactions::account::AccountDelete, Amount,
     TransactionType,
@@ -28,16 +30,19 @@ pub type AccountDeleteBuilder = TransactionBuilder<AccountDelete>;
 impl AccountDeleteBuilder {
     /// Creates a new `AccountDeleteBuilder` with the required destination account.
     pub fn new(account: impl AsRef<str>, destination: impl AsRef<str>) -> Self {
+        let (destination, destination_tag, network) =
+            split_x_address(destination.as_ref());
         Self::init(
             account,
             0,
             Amount::default(),
             AccountDelete {
-                destination: destination.as_ref().to_string(),
-                destination_tag: None,
+                destination,
+                destination_tag,
                 credential_ids: None,
             },
         )
+        .with_x_address_network(network)
     }
 
     /// Sets the destination tag for routing within the destination account.
@@ -14,7 +14,7 @@ use crate::types::{
 /// fn main() -> Result<()> {
 ///     let asset = Asset::xrp();
 ///     let asset2 = Asset::token("USD", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")?;
-///     let amm_bid = AMMBidBuilder::new("rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
+///     let amm_bid = AMMBidBuilder::new("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
 ///         .with_bid_min(Amount::issued_currency("10", "USD", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")?)
 ///         .with_bid_max(Amount::issued_currency("50", "USD", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")?)
 ///         .build()?;
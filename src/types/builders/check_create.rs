@@ -1,4 +1,6 @@
-use super::{BuildError, TransactionBuilder, TransactionTypeBuilder};
+use super::{
+    BuildError, TransactionBuilder, TransactionTypeBuilder, split_x_address,
+};
 use crate::types::{
     validation::{validate_address, validate_amount, validate_invoice_id},
     transactions::payment::CheckCreate,
@@ -35,18 +37,21 @@ impl CheckCreateBuilder {
         destination: impl AsRef<str>,
         send_max: impl Into<Amount>,
     ) -> Self {
+        let (destination, destination_tag, network) =
+            split_x_address(destination.as_ref());
         Self::init(
             account,
             0,
             Amount::default(),
             CheckCreate {
-                destination: destination.as_ref().to_string(),
+                destination,
                 send_max: send_max.into(),
-                destination_tag: None,
+                destination_tag,
                 expiration: None,
                 invoice_id: None,
             },
         )
+        .with_x_address_network(network)
     }
 
     /// Sets the destination tag for routing within the recipient account.
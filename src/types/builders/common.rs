@@ -1,7 +1,12 @@
+use super::FeeStrategy;
+use crate::address::XAddress;
+use crate::sequence::SequenceManager;
+use crate::tickets::TicketPool;
+use crate::util::is_test_network;
 use crate::types::{
     validation::{validate_address, ValidationError},
-    Amount, Memo, MemoWrapper, Signer, SignerWrapper, Transaction,
-    TransactionType,
+    Amount, ArithmeticError, Memo, MemoWrapper, Signer, SignerWrapper,
+    Transaction, TransactionType,
 };
 
 /// Default number of ledgers added to the current ledger index to compute
@@ -16,6 +21,21 @@ use crate::types::{
 /// [`last_ledger_sequence`]: https://xrpl.org/docs/references/protocol/transactions/common-fields#lastledgersequence
 pub const LAST_LEDGER_OFFSET: u32 = 4;
 
+/// Splits an X-address into its classic address, embedded tag, and
+/// test-network flag.
+///
+/// Anything that is not a valid X-address is returned unchanged, with no
+/// flag, so that [`TransactionBuilder::build`] reports it through address
+/// validation.
+pub(crate) fn split_x_address(
+    address: &str,
+) -> (String, Option<u32>, Option<bool>) {
+    match address.parse::<XAddress>() {
+        Ok(x) => (x.classic_address(), x.tag, Some(x.test_network)),
+        Err(_) => (address.to_string(), None, None),
+    }
+}
+
 /// Errors that can occur when building a transaction.
 #[derive(Debug, thiserror::Error)]
 pub enum BuildError {
@@ -37,6 +57,18 @@ pub enum BuildError {
     /// An address or amount validation check failed.
     #[error(transparent)]
     Validation(#[from] ValidationError),
+    /// [`with_ticket_from`](TransactionBuilder::with_ticket_from) found no
+    /// free ticket in the pool.
+    #[error("No ticket available in the pool")]
+    NoTicketAvailable,
+    /// An amount derived by the builder could not be computed.
+    #[error(transparent)]
+    Arithmetic(#[from] ArithmeticError),
+    /// X-addresses for a test network and for mainnet were mixed, either
+    /// with each other or with the server used by
+    /// [`fill`](TransactionBuilder::fill).
+    #[error("X-address is for a different network")]
+    NetworkMismatch,
 }
 
 /// Generic builder for any XRPL transaction type.
@@ -51,13 +83,19 @@ pub struct TransactionBuilder<T> {
     fee: Amount,
     sequence: u32,
     account_txn_id: Option<String>,
+    fee_strategy: FeeStrategy,
     flags: Option<u32>,
     last_ledger_sequence: Option<u32>,
     last_ledger_offset: Option<u32>,
     memos: Option<Vec<MemoWrapper>>,
+    sequence_manager: Option<SequenceManager>,
+    signer_count: Option<u32>,
     signers: Option<Vec<SignerWrapper>>,
     source_tag: Option<u32>,
     ticket_sequence: Option<u32>,
+    /// Test-network flags that must agree: one for each X-address given,
+    /// and the server's once filled.
+    networks: Vec<bool>,
     pub(crate) transaction_type: T,
 }
 
@@ -68,6 +106,11 @@ pub trait TransactionTypeBuilder {
     type TransactionType;
     /// Validates transaction-specific fields before building.
     fn validate(&self) -> Result<(), BuildError>;
+    /// Base cost of this transaction, in reference transactions, before any
+    /// multi-signing surcharge. Used by [`TransactionBuilder::fill`].
+    fn fee_units(&self) -> u64 {
+        1
+    }
     /// Consumes `self` and returns the `TransactionType` variant.
     fn build_transaction_type(
         self,
@@ -78,28 +121,66 @@ impl<T: TransactionTypeBuilder<TransactionType = TransactionType>>
     TransactionBuilder<T>
 {
     /// Creates a new builder with the mandatory common fields.
+    ///
+    /// An X-address `account` is split into its classic address and
+    /// `SourceTag`.
     pub fn init(
         account: impl AsRef<str>,
         sequence: u32,
         fee: impl Into<Amount>,
         transaction_type: T,
     ) -> Self {
+        let (account, source_tag, network) = split_x_address(account.as_ref());
         Self {
-            account: account.as_ref().to_string(),
+            account,
             account_txn_id: None,
             fee: fee.into(),
+            fee_strategy: FeeStrategy::default(),
             flags: None,
             last_ledger_sequence: None,
             last_ledger_offset: None,
             memos: None,
             sequence,
+            sequence_manager: None,
+            signer_count: None,
             signers: None,
-            source_tag: None,
+            source_tag,
             ticket_sequence: None,
+            networks: network.into_iter().collect(),
             transaction_type,
         }
     }
 
+    /// Records the test-network flag of an X-address destination, so
+    /// [`build`](Self::build) can reject a mismatch.
+    pub(crate) fn with_x_address_network(
+        mut self,
+        test_network: Option<bool>,
+    ) -> Self {
+        self.networks.extend(test_network);
+        self
+    }
+
+    /// Starts a builder from an existing transaction of type `T`, such as
+    /// one suggested by `noripple_check`.
+    ///
+    /// Keeps the account, flags, memos, source tag and type-specific
+    /// fields. Fee, sequence and `LastLedgerSequence` are left for
+    /// [`fill`](Self::fill); signatures are dropped. Returns `None` if `tx`
+    /// is another transaction type.
+    pub fn from_transaction(tx: Transaction) -> Option<Self>
+    where
+        T: TryFrom<TransactionType>,
+    {
+        let transaction_type = T::try_from(tx.transaction_type).ok()?;
+        let mut builder =
+            Self::init(tx.account, 0, Amount::default(), transaction_type);
+        builder.flags = tx.flags;
+        builder.memos = tx.memos;
+        builder.source_tag = tx.source_tag;
+        Some(builder)
+    }
+
     /// Sets the transaction flags bitmask.
     ///
     /// Each transaction type has a corresponding typed flags value that converts
@@ -143,6 +224,12 @@ impl<T: TransactionTypeBuilder<TransactionType = TransactionType>>
         self
     }
 
+    /// Sets `flags` on top of any flags already set.
+    pub(crate) fn add_flags(mut self, flags: impl Into<u32>) -> Self {
+        self.flags = Some(self.flags.unwrap_or(0) | flags.into());
+        self
+    }
+
     /// Sets the last ledger sequence; the transaction is invalid after this ledger closes.
     pub fn with_last_ledger_sequence(mut self, sequence: u32) -> Self {
         self.last_ledger_sequence = Some(sequence);
@@ -161,6 +248,60 @@ impl<T: TransactionTypeBuilder<TransactionType = TransactionType>>
         self
     }
 
+    /// Leases the lowest free ticket from `pool` and uses it as the
+    /// `TicketSequence`, as [`with_ticket_sequence`] would. Settle the ticket
+    /// with [`TicketPool::settle`] once the outcome is known.
+    ///
+    /// [`with_ticket_sequence`]: Self::with_ticket_sequence
+    pub fn with_ticket_from(
+        self,
+        pool: &TicketPool,
+    ) -> Result<Self, BuildError> {
+        let ticket = pool.lease().ok_or(BuildError::NoTicketAvailable)?;
+        Ok(self.with_ticket_sequence(ticket))
+    }
+
+    /// Makes [`fill`] take the sequence from `manager` instead of asking
+    /// `account_info`, so concurrent transactions from one account get
+    /// distinct sequences. [`fill`] also tracks the transaction's
+    /// `LastLedgerSequence` with the manager. Call before [`fill`].
+    ///
+    /// [`fill`]: Self::fill
+    pub fn with_sequence_manager(mut self, manager: &SequenceManager) -> Self {
+        self.sequence_manager = Some(manager.clone());
+        self
+    }
+
+    /// Sets how [`fill`] chooses the fee; defaults to
+    /// [`FeeStrategy::OpenLedger`]. Call before [`fill`].
+    ///
+    /// [`fill`]: Self::fill
+    pub fn with_fee_strategy(mut self, strategy: FeeStrategy) -> Self {
+        self.fee_strategy = strategy;
+        self
+    }
+
+    /// Declares that the transaction will be multi-signed by `count`
+    /// signers, so [`fill`] charges `(1 + count)` times the base cost. Not
+    /// needed once [`with_signers`] has attached the signatures. Call before
+    /// [`fill`].
+    ///
+    /// [`fill`]: Self::fill
+    /// [`with_signers`]: Self::with_signers
+    pub fn with_signer_count(mut self, count: u32) -> Self {
+        self.signer_count = Some(count);
+        self
+    }
+
+    /// Cost of the transaction in reference transactions, including one per
+    /// multi-signer.
+    pub(crate) fn fee_units(&self) -> u64 {
+        let signers = self.signer_count.map(u64::from).unwrap_or_else(|| {
+            self.signers.as_ref().map_or(0, |signers| signers.len() as u64)
+        });
+        self.transaction_type.fee_units() + signers
+    }
+
     /// Attaches human-readable memo entries to the transaction.
     ///
     /// Accepts any iterable of items convertible into [`Memo`], so users can
@@ -233,6 +374,11 @@ impl<T: TransactionTypeBuilder<TransactionType = TransactionType>>
         self
     }
 
+    /// Returns the sending account (classic address).
+    pub fn account(&self) -> &str {
+        &self.account
+    }
+
     /// Returns the current fee (as set by `init` or `fill`).
     ///
     /// Useful when you need to read back the filled fee to compute a derived fee,
@@ -262,14 +408,22 @@ impl<T: TransactionTypeBuilder<TransactionType = TransactionType>>
     ///
     /// **Regular mode** - when no ticket sequence is set, all three fields (`sequence`,
     /// `fee`, `last_ledger_sequence`) are fetched concurrently from `account_info`,
-    /// `fee`, and `ledger_current`.
+    /// `fee`, and `ledger_current`. With [`with_sequence_manager`] the sequence
+    /// comes from the manager instead.
     ///
-    /// The fee is always set to `open_ledger_fee`. `last_ledger_sequence` is set to
+    /// The fee is chosen by the [`FeeStrategy`] set with [`with_fee_strategy`]
+    /// (open-ledger by default) from the `fee` and `server_state` responses,
+    /// scaled for multi-signing and fulfillments. `last_ledger_sequence` is set to
     /// `ledger_current_index +` [`LAST_LEDGER_OFFSET`] (~12-16 s window). Override
     /// the offset per transaction with [`with_last_ledger_offset`].
     ///
+    /// The server's `network_id` is kept so that [`build`](Self::build)
+    /// rejects X-addresses encoded for another network.
+    ///
     /// [`with_ticket_sequence`]: Self::with_ticket_sequence
+    /// [`with_fee_strategy`]: Self::with_fee_strategy
     /// [`with_last_ledger_offset`]: Self::with_last_ledger_offset
+    /// [`with_sequence_manager`]: Self::with_sequence_manager
     ///
     /// # Examples
     ///
@@ -316,46 +470,86 @@ impl<T: TransactionTypeBuilder<TransactionType = TransactionType>>
     ) -> Result<Self, crate::XrplError> {
         use crate::request::{
             fee::FeeRequest, ledger_current::LedgerCurrentRequest,
+            server_state::ServerStateRequest,
         };
 
         let offset = self.last_ledger_offset.unwrap_or(LAST_LEDGER_OFFSET);
+        let state_req = ServerStateRequest::new();
+        let fees = async {
+            let (fee_resp, state_resp) = tokio::try_join!(
+                client.request(&FeeRequest),
+                client.request(&state_req),
+            )?;
+            let state = state_resp.result()?.state;
+            let fee = self.fee_strategy.fee_drops(
+                &fee_resp.result()?,
+                &state,
+                self.fee_units(),
+            )?;
+            Ok::<_, crate::XrplError>((fee, state.network_id))
+        };
 
         match self.ticket_sequence {
             Some(_) => {
-                let (fee_resp, ledger_resp) = tokio::try_join!(
-                    client.request(&FeeRequest),
+                let ((fee, network_id), ledger_resp) = tokio::try_join!(
+                    fees,
                     client.request(&LedgerCurrentRequest),
                 )?;
-                let fee = fee_resp.result()?.drops.open_ledger_fee;
                 let last_ledger_sequence =
                     ledger_resp.result()?.ledger_current_index + offset;
                 Ok(Self {
                     sequence: 0,
-                    fee: Amount::Xrpl(fee),
+                    fee: Amount::Xrpl(fee.to_string()),
                     last_ledger_sequence: Some(last_ledger_sequence),
                     ..self
-                })
+                }
+                .on_server_network(network_id))
+            }
+            None if self.sequence_manager.is_some() => {
+                let ((fee, network_id), ledger_resp) = tokio::try_join!(
+                    fees,
+                    client.request(&LedgerCurrentRequest),
+                )?;
+                let last_ledger_sequence =
+                    ledger_resp.result()?.ledger_current_index + offset;
+                // Allocate last, so a failed request above does not leak a
+                // sequence.
+                let manager = self.sequence_manager.as_ref().expect("checked");
+                let seq = manager.next(client).await?;
+                manager.track(seq, last_ledger_sequence);
+                Ok(Self {
+                    sequence: seq,
+                    fee: Amount::Xrpl(fee.to_string()),
+                    last_ledger_sequence: Some(last_ledger_sequence),
+                    ..self
+                }
+                .on_server_network(network_id))
             }
             None => {
                 use crate::util::next_sequence;
-                let (seq, fee_resp, ledger_resp) = tokio::try_join!(
+                let (seq, (fee, network_id), ledger_resp) = tokio::try_join!(
                     next_sequence(client, &self.account),
-                    client.request(&FeeRequest),
+                    fees,
                     client.request(&LedgerCurrentRequest),
                 )?;
-                let fee = fee_resp.result()?.drops.open_ledger_fee;
                 let last_ledger_sequence =
                     ledger_resp.result()?.ledger_current_index + offset;
                 Ok(Self {
                     sequence: seq,
-                    fee: Amount::Xrpl(fee),
+                    fee: Amount::Xrpl(fee.to_string()),
                     last_ledger_sequence: Some(last_ledger_sequence),
                     ..self
-                })
+                }
+                .on_server_network(network_id))
             }
         }
     }
 
+    fn on_server_network(mut self, network_id: Option<u64>) -> Self {
+        self.networks.extend(network_id.map(is_test_network));
+        self
+    }
+
     /// Validates all fields and produces the final [`Transaction`].
     pub fn build(self) -> Result<Transaction, BuildError> {
         match &self.fee {
@@ -368,6 +562,10 @@ impl<T: TransactionTypeBuilder<TransactionType = TransactionType>>
             return Err(BuildError::TicketRequiresZeroSequence);
         }
 
+        if self.networks.windows(2).any(|pair| pair[0] != pair[1]) {
+            return Err(BuildError::NetworkMismatch);
+        }
+
         validate_address(&self.account)?;
 
         self.transaction_type.validate()?;
@@ -1,4 +1,6 @@
-use super::{BuildError, TransactionBuilder, TransactionTypeBuilder};
+use super::{
+    BuildError, TransactionBuilder, TransactionTypeBuilder, split_x_address,
+};
 use crate::types::{
     validation::{validate_address, validate_amount},
     transactions::escrow::EscrowCreate,
@@ -36,19 +38,22 @@ impl EscrowCreateBuilder {
         destination: impl AsRef<str>,
         amount: impl Into<Amount>,
     ) -> Self {
+        let (destination, destination_tag, network) =
+            split_x_address(destination.as_ref());
         Self::init(
             account,
             0,
             Amount::default(),
             EscrowCreate {
                 amount: amount.into(),
-                destination: destination.as_ref().to_string(),
+                destination,
                 cancel_after: None,
                 finish_after: None,
                 condition: None,
-                destination_tag: None,
+                destination_tag,
             },
         )
+        .with_x_address_network(network)
     }
 
     /// Sets the Ripple-epoch time after which the escrow can be cancelled.
@@ -63,9 +68,11 @@ impl EscrowCreateBuilder {
         self
     }
 
-    /// Sets the hex-encoded PREIMAGE-SHA-256 crypto-condition that must be fulfilled to release funds.
-    pub fn with_condition(mut self, condition: impl AsRef<str>) -> Self {
-        self.transaction_type.condition = Some(condition.as_ref().to_string());
+    /// Sets the PREIMAGE-SHA-256 crypto-condition that must be fulfilled to
+    /// release funds, as a [`Condition`](crate::conditions::Condition) or hex
+    /// string.
+    pub fn with_condition(mut self, condition: impl Into<String>) -> Self {
+        self.transaction_type.condition = Some(condition.into());
         self
     }
 
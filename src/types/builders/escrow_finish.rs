@@ -1,4 +1,5 @@
 use super::{BuildError, TransactionBuilder, TransactionTypeBuilder};
+use crate::conditions::escrow_finish_fee_units;
 use crate::types::{
     validation::validate_address, transactions::escrow::EscrowFinish, Amount,
     TransactionType,
@@ -45,16 +46,17 @@ impl EscrowFinishBuilder {
         )
     }
 
-    /// Sets the hex-encoded PREIMAGE-SHA-256 condition originally placed on the escrow.
-    pub fn with_condition(mut self, condition: impl AsRef<str>) -> Self {
-        self.transaction_type.condition = Some(condition.as_ref().to_string());
+    /// Sets the PREIMAGE-SHA-256 condition originally placed on the escrow,
+    /// as a [`Condition`](crate::conditions::Condition) or hex string.
+    pub fn with_condition(mut self, condition: impl Into<String>) -> Self {
+        self.transaction_type.condition = Some(condition.into());
         self
     }
 
-    /// Sets the hex-encoded fulfillment that satisfies the escrow's condition.
-    pub fn with_fulfillment(mut self, fulfillment: impl AsRef<str>) -> Self {
-        self.transaction_type.fulfillment =
-            Some(fulfillment.as_ref().to_string());
+    /// Sets the fulfillment that satisfies the escrow's condition, as a
+    /// [`Fulfillment`](crate::conditions::Fulfillment) or hex string.
+    pub fn with_fulfillment(mut self, fulfillment: impl Into<String>) -> Self {
+        self.transaction_type.fulfillment = Some(fulfillment.into());
         self
     }
 }
@@ -67,6 +69,14 @@ impl TransactionTypeBuilder for EscrowFinish {
         Ok(())
     }
 
+    /// A fulfillment adds 32 reference transactions plus one per 16 bytes.
+    fn fee_units(&self) -> u64 {
+        match &self.fulfillment {
+            Some(fulfillment) => escrow_finish_fee_units(fulfillment.len() / 2),
+            None => 1,
+        }
+    }
+
     fn build_transaction_type(
         self,
     ) -> Result<Self::TransactionType, BuildError> {
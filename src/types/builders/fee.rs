@@ -0,0 +1,203 @@
+use crate::XrplError;
+use crate::request::fee::FeeResult;
+use crate::request::server_state::ServerState;
+
+/// How [`fill`](super::TransactionBuilder::fill) chooses the fee.
+///
+/// Every strategy picks a fee level from the `fee` response and scales it by
+/// the transaction's own base cost: one reference transaction, plus one per
+/// multi-signer (see [`with_signer_count`]), plus the extra cost of an
+/// `EscrowFinish` fulfillment. The result is then raised to the server's
+/// load-scaled cost (`load_factor_server` from `server_state`), below which
+/// rippled rejects the transaction with `telINSUF_FEE_P`. Caps are applied
+/// last and win over that floor.
+///
+/// [`with_signer_count`]: super::TransactionBuilder::with_signer_count
+///
+/// # Example
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// use xrpl::{Client, xrp, types::builders::{FeeStrategy, PaymentBuilder}};
+/// let client = Client::new("wss://xrplcluster.com");
+/// let tx = PaymentBuilder::new(
+///     "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+///     "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+///     xrp!(1),
+/// )
+/// .with_fee_strategy(FeeStrategy::Percentile { percentile: 50, max_drops: 1_000 })
+/// .fill(&client)
+/// .await?
+/// .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeeStrategy {
+    /// The lowest fee the queue accepts (`minimum_level`). Already escalated
+    /// when the queue is full; may wait several ledgers.
+    Minimum,
+    /// Enough to enter the current open ledger (`open_ledger_level`).
+    #[default]
+    OpenLedger,
+    /// The median of recently validated transactions (`median_level`), but
+    /// never below [`Minimum`](Self::Minimum).
+    Median,
+    /// `percentile` percent of the way from [`Minimum`](Self::Minimum) to
+    /// [`OpenLedger`](Self::OpenLedger), or the open-ledger fee while the
+    /// queue is full; never more than `max_drops`.
+    Percentile {
+        /// 0 for the minimum, 100 for the open-ledger fee.
+        percentile: u8,
+        /// Hard cap on the fee, in drops.
+        max_drops: u64,
+    },
+    /// The open-ledger fee, but never more than this many drops.
+    MaxBudget(u64),
+}
+
+impl FeeStrategy {
+    /// Fee in drops for a transaction costing `units` reference transactions.
+    pub fn fee_drops(
+        &self,
+        fee: &FeeResult,
+        server: &ServerState,
+        units: u64,
+    ) -> Result<u64, XrplError> {
+        let minimum = parse("minimum_level", &fee.levels.minimum_level)?;
+        let open_ledger =
+            parse("open_ledger_level", &fee.levels.open_ledger_level)?;
+        let level = match *self {
+            FeeStrategy::Minimum => minimum,
+            FeeStrategy::OpenLedger | FeeStrategy::MaxBudget(_) => open_ledger,
+            FeeStrategy::Median => {
+                parse("median_level", &fee.levels.median_level)?.max(minimum)
+            }
+            FeeStrategy::Percentile { percentile, .. } => {
+                let queued =
+                    parse("current_queue_size", &fee.current_queue_size)?;
+                let capacity = parse("max_queue_size", &fee.max_queue_size)?;
+                if queued >= capacity {
+                    open_ledger
+                } else {
+                    let span = open_ledger.saturating_sub(minimum);
+                    minimum + span * u64::from(percentile.min(100)) / 100
+                }
+            }
+        };
+
+        let base_fee = parse("base_fee", &fee.drops.base_fee)?;
+        let reference = parse("reference_level", &fee.levels.reference_level)?;
+        let cost = base_fee.saturating_mul(units);
+        let escalated = scale(cost, level, reference);
+        let loaded = scale(cost, server.load_factor_server, server.load_base);
+        let drops = escalated.max(loaded);
+
+        Ok(match *self {
+            FeeStrategy::Percentile { max_drops, .. } => drops.min(max_drops),
+            FeeStrategy::MaxBudget(budget) => drops.min(budget),
+            _ => drops,
+        })
+    }
+}
+
+/// `ceil(cost × numerator / denominator)`, or 0 when `denominator` is 0.
+fn scale(cost: u64, numerator: u64, denominator: u64) -> u64 {
+    if denominator == 0 {
+        return 0;
+    }
+    let scaled = (u128::from(cost) * u128::from(numerator))
+        .div_ceil(u128::from(denominator));
+    u64::try_from(scaled).unwrap_or(u64::MAX)
+}
+
+fn parse(field: &str, value: &str) -> Result<u64, XrplError> {
+    value.parse().map_err(|_| {
+        XrplError::ParseError(format!(
+            "fee response {field} is not a number: {value}"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::builders::EscrowFinishBuilder;
+
+    fn fee(queue: &str) -> FeeResult {
+        serde_json::from_value(json!({
+            "current_ledger_size": "10",
+            "current_queue_size": queue,
+            "drops": {
+                "base_fee": "10",
+                "median_fee": "5000",
+                "minimum_fee": "10",
+                "open_ledger_fee": "20",
+            },
+            "expected_ledger_size": "100",
+            "ledger_current_index": 1,
+            "levels": {
+                "median_level": "128000",
+                "minimum_level": "256",
+                "open_ledger_level": "512",
+                "reference_level": "256",
+            },
+            "max_queue_size": "2000",
+        }))
+        .unwrap()
+    }
+
+    fn server(load_factor_server: u64) -> ServerState {
+        ServerState { load_base: 256, load_factor_server, ..Default::default() }
+    }
+
+    #[test]
+    fn test_strategies() {
+        let (open, idle) = (fee("0"), server(256));
+        let drops = |strategy: FeeStrategy| {
+            strategy.fee_drops(&open, &idle, 1).unwrap()
+        };
+
+        assert_eq!(drops(FeeStrategy::Minimum), 10);
+        assert_eq!(drops(FeeStrategy::OpenLedger), 20);
+        assert_eq!(drops(FeeStrategy::Median), 5000);
+        assert_eq!(
+            drops(FeeStrategy::Percentile { percentile: 50, max_drops: 100 }),
+            15
+        );
+        assert_eq!(drops(FeeStrategy::MaxBudget(12)), 12);
+
+        let full = FeeStrategy::Percentile { percentile: 0, max_drops: 100 }
+            .fee_drops(&fee("2000"), &idle, 1)
+            .unwrap();
+        assert_eq!(full, 20);
+    }
+
+    #[test]
+    fn test_scales_by_units_and_server_load() {
+        // Two signers: (1 + 2) x open-ledger.
+        let fee = fee("0");
+        assert_eq!(
+            FeeStrategy::OpenLedger.fee_drops(&fee, &server(256), 3).unwrap(),
+            60
+        );
+        // A server at 4x load outbids the queue minimum.
+        assert_eq!(
+            FeeStrategy::Minimum.fee_drops(&fee, &server(1024), 1).unwrap(),
+            40
+        );
+    }
+
+    #[test]
+    fn test_fee_units() {
+        let owner = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+        let finish = EscrowFinishBuilder::new(owner, owner, 1);
+        assert_eq!(finish.fee_units(), 1);
+        // A 36-byte fulfillment: 33 + 36 / 16 reference transactions.
+        let finish = finish.with_fulfillment("AB".repeat(36));
+        assert_eq!(finish.fee_units(), 35);
+        assert_eq!(finish.with_signer_count(2).fee_units(), 37);
+    }
+}
@@ -21,6 +21,7 @@ mod did_set;
 mod escrow_cancel;
 mod escrow_create;
 mod escrow_finish;
+mod fee;
 mod mpt_authorize;
 mod mpt_issuance_create;
 mod mpt_issuance_destroy;
@@ -75,6 +76,7 @@ pub use did_set::DIDSetBuilder;
 pub use escrow_cancel::EscrowCancelBuilder;
 pub use escrow_create::EscrowCreateBuilder;
 pub use escrow_finish::EscrowFinishBuilder;
+pub use fee::FeeStrategy;
 pub use mpt_authorize::MPTokenAuthorizeBuilder;
 pub use mpt_issuance_create::MPTokenIssuanceCreateBuilder;
 pub use mpt_issuance_destroy::MPTokenIssuanceDestroyBuilder;
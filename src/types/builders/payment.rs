@@ -1,8 +1,11 @@
-use super::{BuildError, TransactionBuilder, TransactionTypeBuilder};
+use super::{
+    BuildError, TransactionBuilder, TransactionTypeBuilder, split_x_address,
+};
+use crate::request::ripple_path_find::PathAlternative;
 use crate::types::{
     validation::{validate_address, validate_amount, validate_invoice_id},
     transactions::payment::{PathStep, Payment},
-    Amount, TransactionType,
+    Amount, TransactionType, XrplDecimal,
 };
 
 /// Builder for XRPL payment transactions.
@@ -36,6 +39,9 @@ pub type PaymentBuilder = TransactionBuilder<Payment>;
 impl PaymentBuilder {
     /// Creates a new `PaymentBuilder` with the required sender, destination, and amount.
     ///
+    /// An X-address `destination` is split into its classic address and
+    /// `DestinationTag`; an X-address `account` likewise sets `SourceTag`.
+    ///
     /// For cross-currency payments, also call [`with_send_max`] to set the spending cap.
     /// Call [`fill`] to populate `sequence`, `fee`, and `last_ledger_sequence` from the network.
     ///
@@ -46,6 +52,8 @@ impl PaymentBuilder {
         destination: impl AsRef<str>,
         amount: impl Into<Amount>,
     ) -> Self {
+        let (destination, destination_tag, network) =
+            split_x_address(destination.as_ref());
         let amount = amount.into();
         Self::init(
             account,
@@ -54,14 +62,15 @@ impl PaymentBuilder {
             Payment {
                 deliver_max: Some(amount.clone()),
                 amount: Some(amount),
-                destination: destination.as_ref().to_string(),
+                destination,
                 deliver_min: None,
-                destination_tag: None,
+                destination_tag,
                 invoice_id: None,
                 paths: None,
                 send_max: None,
             },
         )
+        .with_x_address_network(network)
     }
 
     /// Sets the destination tag for routing within the recipient account.
@@ -103,6 +112,61 @@ impl PaymentBuilder {
         self.transaction_type.paths.get_or_insert_with(Vec::new).push(steps);
         self
     }
+
+    /// Pays along a path alternative returned by `ripple_path_find` or a
+    /// `path_find` session.
+    ///
+    /// Replaces `Paths` with the alternative's computed paths (clearing them
+    /// for a direct or order-book alternative, which has none), sets
+    /// `SendMax` to its `source_amount` plus `slippage_bps` basis points
+    /// (`50` = 0.5%), and adds `flags` (typically
+    /// [`PaymentFlag::PartialPayment`] and/or [`PaymentFlag::NoRippleDirect`])
+    /// to any flags already set. An XRP-to-XRP payment can't carry
+    /// `SendMax`, so it is cleared instead when both the alternative's
+    /// `source_amount` and `Amount` are XRP.
+    ///
+    /// Fails if `SendMax` overflows.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xrpl::request::ripple_path_find::PathAlternative;
+    /// use xrpl::types::{PaymentFlags, builders::PaymentBuilder};
+    ///
+    /// let alternative: PathAlternative = serde_json::from_value(serde_json::json!({
+    ///     "paths_computed": [[{ "currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B" }]],
+    ///     "source_amount": "2000000",
+    /// }))?;
+    /// let tx = PaymentBuilder::new(
+    ///     "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+    ///     "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+    ///     xrpl::issued!(1, "USD", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"),
+    /// )
+    /// .with_path_alternative(&alternative, 50, PaymentFlags::default())?
+    /// .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`PaymentFlag::PartialPayment`]: crate::types::PaymentFlag::PartialPayment
+    /// [`PaymentFlag::NoRippleDirect`]: crate::types::PaymentFlag::NoRippleDirect
+    pub fn with_path_alternative(
+        mut self,
+        alternative: &PathAlternative,
+        slippage_bps: u32,
+        flags: impl Into<u32>,
+    ) -> Result<Self, BuildError> {
+        let xrp_to_xrp = matches!(alternative.source_amount, Amount::Xrpl(_))
+            && matches!(self.transaction_type.amount, Some(Amount::Xrpl(_)));
+        self.transaction_type.send_max = if xrp_to_xrp {
+            None
+        } else {
+            let slippage = XrplDecimal::new(slippage_bps.into(), -4)?;
+            let factor = XrplDecimal::from(1).checked_add(slippage)?;
+            Some(alternative.source_amount.checked_mul(factor)?)
+        };
+        self.transaction_type.paths = (!alternative.paths_computed.is_empty())
+            .then(|| alternative.paths_computed.clone());
+        Ok(self.add_flags(flags))
+    }
 }
 
 impl TransactionTypeBuilder for Payment {
@@ -194,6 +258,133 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_payment_builder_with_path_alternative() {
+        use crate::request::ripple_path_find::RipplePathFindResponse;
+        use crate::types::PaymentFlag;
+
+        const ISSUER: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+        let found: RipplePathFindResponse =
+            serde_json::from_value(serde_json::json!({
+                "alternatives": [{
+                    "paths_computed": [[
+                        { "currency": "USD", "issuer": ISSUER, "type": 48, "type_hex": "0000000000000030" },
+                        { "account": ISSUER, "type": 1, "type_hex": "0000000000000001" },
+                    ]],
+                    "source_amount": { "currency": "EUR", "issuer": ISSUER, "value": "10" },
+                }],
+                "destination_account": RECEIVER,
+                "destination_amount": { "currency": "USD", "issuer": ISSUER, "value": "9" },
+                "source_account": SENDER,
+            }))
+            .unwrap();
+
+        let payment = PaymentBuilder::new(
+            SENDER,
+            RECEIVER,
+            found.destination_amount.clone(),
+        )
+        .with_flags(PaymentFlag::LimitQuality)
+        .with_path_alternative(
+            &found.alternatives[0],
+            50,
+            PaymentFlag::NoRippleDirect,
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+
+        assert_eq!(payment.flags, Some(0x00050000));
+        let TransactionType::Payment(Payment { paths, send_max, .. }) =
+            payment.transaction_type
+        else {
+            panic!("Expected Payment transaction type");
+        };
+        let paths = paths.unwrap();
+        assert_eq!(paths[0].len(), 2);
+        assert_eq!(paths[0][0].currency.as_deref(), Some("USD"));
+        assert_eq!(paths[0][1].account.as_deref(), Some(ISSUER));
+        assert_eq!(send_max.unwrap().value(), "10.05");
+    }
+
+    /// A direct or order-book alternative has no computed paths; an empty
+    /// `Paths` field would encode as an empty PathSet, which rippled rejects.
+    #[test]
+    fn test_payment_builder_direct_alternative_omits_paths() {
+        let direct: PathAlternative =
+            serde_json::from_value(serde_json::json!({
+                "paths_computed": [],
+                "source_amount": "2000000",
+            }))
+            .unwrap();
+
+        let payment = PaymentBuilder::new(SENDER, RECEIVER, xrp!(1))
+            .add_path([PathStep::new().with_account(RECEIVER)])
+            .with_path_alternative(&direct, 25, 0u32)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&payment).unwrap();
+        assert!(json.get("Paths").is_none());
+        assert!(json.get("SendMax").is_none());
+        crate::types::codec::encode(&json).expect("should encode");
+    }
+
+    #[test]
+    fn test_payment_builder_rejects_mixed_networks() {
+        let x_address = |account, test_network| {
+            crate::address::classic_to_x_address(account, None, test_network)
+                .unwrap()
+        };
+
+        PaymentBuilder::new(SENDER, x_address(RECEIVER, true), xrp!(1))
+            .build()
+            .expect("a single test-network X-address is consistent");
+        let err = PaymentBuilder::new(
+            x_address(SENDER, false),
+            x_address(RECEIVER, true),
+            xrp!(1),
+        )
+        .build()
+        .unwrap_err();
+        assert!(matches!(err, BuildError::NetworkMismatch));
+    }
+
+    #[test]
+    fn test_payment_builder_splits_x_address() {
+        let x_address =
+            crate::address::classic_to_x_address(RECEIVER, Some(42), false)
+                .unwrap();
+        let payment = PaymentBuilder::new(SENDER, x_address, xrp!(1))
+            .build()
+            .expect("Should build valid payment to an X-address");
+
+        if let TransactionType::Payment(Payment {
+            destination,
+            destination_tag,
+            ..
+        }) = payment.transaction_type
+        {
+            assert_eq!(destination, RECEIVER);
+            assert_eq!(destination_tag, Some(42));
+        } else {
+            panic!("Expected Payment transaction type");
+        }
+    }
+
+    #[test]
+    fn test_payment_builder_bad_checksum() {
+        let result = PaymentBuilder::new(
+            SENDER,
+            "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYf",
+            xrp!(1),
+        )
+        .build();
+
+        assert!(matches!(result, Err(BuildError::Validation(_))));
+    }
+
     #[test]
     fn test_payment_builder_invalid_account() {
         let result =
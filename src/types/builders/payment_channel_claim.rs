@@ -1,4 +1,5 @@
 use super::{BuildError, TransactionBuilder, TransactionTypeBuilder};
+use crate::channel::Claim;
 use crate::types::{
     validation::validate_amount,
     transactions::payment_channel::PaymentChannelClaim, Amount,
@@ -78,6 +79,21 @@ impl PaymentChannelClaimBuilder {
         self
     }
 
+    /// Redeems an off-ledger [`Claim`]: sets `Balance` and `Amount` to the
+    /// claimed drops, plus the claim's `Signature` and the channel's
+    /// `PublicKey`.
+    pub fn with_claim(
+        self,
+        claim: &Claim,
+        public_key: impl AsRef<str>,
+    ) -> Self {
+        let drops = Amount::Xrpl(claim.amount.to_string());
+        self.with_balance(drops.clone())
+            .with_amount(drops)
+            .with_signature(&claim.signature)
+            .with_public_key(public_key)
+    }
+
     /// Sets the sender's signature authorizing the claim amount.
     pub fn with_signature(mut self, signature: impl AsRef<str>) -> Self {
         self.transaction_type.signature = Some(signature.as_ref().to_string());
@@ -1,4 +1,6 @@
-use super::{BuildError, TransactionBuilder, TransactionTypeBuilder};
+use super::{
+    BuildError, TransactionBuilder, TransactionTypeBuilder, split_x_address,
+};
 use crate::types::{
     validation::{validate_address, validate_amount},
     transactions::payment_channel::PaymentChannelCreate,
@@ -39,19 +41,22 @@ impl PaymentChannelCreateBuilder {
         amount: impl Into<Amount>,
         settle_delay: u32,
     ) -> Self {
+        let (destination, destination_tag, network) =
+            split_x_address(destination.as_ref());
         Self::init(
             account,
             0,
             Amount::default(),
             PaymentChannelCreate {
                 amount: amount.into(),
-                destination: destination.as_ref().to_string(),
+                destination,
                 public_key: public_key.as_ref().to_string(),
                 settle_delay,
-                destination_tag: None,
+                destination_tag,
                 cancel_after: None,
             },
         )
+        .with_x_address_network(network)
     }
 
     /// Sets the destination tag for routing within the recipient account.
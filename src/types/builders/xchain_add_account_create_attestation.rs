@@ -20,8 +20,8 @@ use crate::types::{
 /// let attestation = XChainAddAccountCreateAttestation {
 ///     amount: xrp!(20),
 ///     attestation_reward_account: "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
-///     attestation_signer_account: "r3kmLJN5D28dHuH8vZNUZpMC4JP9X8RHsv".into(),
-///     destination: "rN7n3473SaZBCG4dFL83w7PB5NMJhkMFKE".into(),
+///     attestation_signer_account: "rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo".into(),
+///     destination: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
 ///     other_chain_source: "rGWrZyax5eXbi5gs49MRZKmskElsde6Rm1".into(),
 ///     public_key: "ED5E6F48B2B1E8C7D2C3F5A4B6E8D9F0A1C2D3E4F5A6B7C8D9E0F1A2B3C4D5E6F".into(),
 ///     signature: "A1B2C3D4E5F6A1B2C3D4E5F6A1B2C3D4E5F6A1B2C3D4E5F6A1B2C3D4E5F6A1B2".into(),
@@ -21,7 +21,7 @@ use crate::types::{
 /// let attestation = XChainAddClaimAttestation {
 ///     amount: xrp!(100),
 ///     attestation_reward_account: "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
-///     attestation_signer_account: "r3kmLJN5D28dHuH8vZNUZpMC4JP9X8RHsv".into(),
+///     attestation_signer_account: "rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo".into(),
 ///     destination: None,
 ///     other_chain_source: String::new(),
 ///     public_key: "ED5E6F48B2B1E8C7D2C3F5A4B6E8D9F0A1C2D3E4F5A6B7C8D9E0F1A2B3C4D5E6F".into(),
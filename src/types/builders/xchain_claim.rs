@@ -1,4 +1,6 @@
-use super::{BuildError, TransactionBuilder, TransactionTypeBuilder};
+use super::{
+    BuildError, TransactionBuilder, TransactionTypeBuilder, split_x_address,
+};
 use crate::types::{
     transactions::xchain::XChainClaim,
     validation::{validate_address, validate_amount},
@@ -46,18 +48,21 @@ impl XChainClaimBuilder {
         xchain_bridge: impl Into<XChainBridge>,
         xchain_claim_id: impl AsRef<str>,
     ) -> Self {
+        let (destination, destination_tag, network) =
+            split_x_address(destination.as_ref());
         Self::init(
             account,
             0,
             Amount::default(),
             XChainClaim {
                 amount: amount.into(),
-                destination: destination.as_ref().to_string(),
-                destination_tag: None,
+                destination,
+                destination_tag,
                 xchain_bridge: xchain_bridge.into(),
                 xchain_claim_id: xchain_claim_id.as_ref().to_string(),
             },
         )
+        .with_x_address_network(network)
     }
 
     /// Sets the destination tag for routing within the recipient account.
@@ -0,0 +1,22 @@
+//! AccountID fields, read from and written as classic addresses.
+
+use super::CodecError;
+use crate::address::AccountId;
+
+/// Decodes a classic `r…` address to its 20-byte AccountID.
+pub(super) fn encode_account_id(
+    field: &str,
+    address: &str,
+) -> Result<[u8; 20], CodecError> {
+    let id: AccountId =
+        address.parse().map_err(|e| CodecError::InvalidValue {
+            field: field.to_string(),
+            reason: format!("bad classic address {address}: {e}"),
+        })?;
+    Ok(id.0)
+}
+
+/// Encodes a 20-byte AccountID as a classic `r…` address.
+pub(super) fn decode_account_id(id: &[u8; 20]) -> String {
+    AccountId(*id).to_string()
+}
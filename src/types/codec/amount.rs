@@ -0,0 +1,274 @@
+//! `STAmount`, `Currency`, and `Issue` encodings.
+
+use serde_json::{Map, Value, json};
+
+use super::CodecError;
+use super::account::{decode_account_id, encode_account_id};
+use super::reader::Reader;
+
+const NOT_XRP: u64 = 0x8000_0000_0000_0000;
+const POSITIVE: u64 = 0x4000_0000_0000_0000;
+const MANTISSA_MASK: u64 = (1 << 54) - 1;
+const MPT_TAG: u8 = 0x60;
+const MIN_MANTISSA: u64 = 1_000_000_000_000_000;
+const MIN_EXPONENT: i32 = -96;
+const MAX_EXPONENT: i32 = 80;
+const MAX_DROPS: u64 = 100_000_000_000_000_000;
+/// `noAccount`, marking an `Issue` as an MPT issuance.
+const NO_ACCOUNT: [u8; 20] = {
+    let mut id = [0; 20];
+    id[19] = 1;
+    id
+};
+
+fn invalid(field: &str, reason: impl Into<String>) -> CodecError {
+    CodecError::InvalidValue { field: field.to_string(), reason: reason.into() }
+}
+
+/// Encodes an XRP, issued-currency, or MPT amount.
+pub(super) fn write_amount(
+    field: &str,
+    value: &Value,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    match value {
+        Value::String(drops) => {
+            out.extend(encode_drops(field, drops)?.to_be_bytes());
+        }
+        Value::Object(map) if map.contains_key("mpt_issuance_id") => {
+            write_mpt(field, map, out)?;
+        }
+        Value::Object(map) => {
+            let text = str_of(field, map, "value")?;
+            out.extend(encode_iou_value(field, text)?.to_be_bytes());
+            out.extend(encode_currency(
+                field,
+                str_of(field, map, "currency")?,
+            )?);
+            out.extend(encode_account_id(
+                field,
+                str_of(field, map, "issuer")?,
+            )?);
+        }
+        _ => return Err(invalid(field, "expected drops string or object")),
+    }
+    Ok(())
+}
+
+fn write_mpt(
+    field: &str,
+    map: &Map<String, Value>,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    let text = str_of(field, map, "value")?;
+    let value: u64 = text
+        .parse()
+        .ok()
+        .filter(|v| *v <= i64::MAX as u64)
+        .ok_or_else(|| invalid(field, format!("bad MPT value {text}")))?;
+    out.push(MPT_TAG);
+    out.extend(value.to_be_bytes());
+    out.extend(super::hex_exact(
+        field,
+        str_of(field, map, "mpt_issuance_id")?,
+        24,
+    )?);
+    Ok(())
+}
+
+/// Decodes an amount, dispatching on the leading type bits.
+pub(super) fn read_amount(reader: &mut Reader) -> Result<Value, CodecError> {
+    let lead = reader.peek()?;
+    if lead & 0x80 != 0 {
+        let raw = reader.read_u64()?;
+        let currency = decode_currency(reader.read_array::<20>()?);
+        let issuer = decode_account_id(&reader.read_array::<20>()?);
+        let value = decode_iou_value(raw);
+        return Ok(
+            json!({ "value": value, "currency": currency, "issuer": issuer }),
+        );
+    }
+    if lead & 0x20 != 0 {
+        let head = reader.read_array::<9>()?;
+        let value =
+            u64::from_be_bytes(head[1..].try_into().unwrap_or_default());
+        let id = hex::encode_upper(reader.read_array::<24>()?);
+        let sign = if head[0] & 0x40 == 0 { "-" } else { "" };
+        return Ok(
+            json!({ "value": format!("{sign}{value}"), "mpt_issuance_id": id }),
+        );
+    }
+    let raw = reader.read_u64()?;
+    let sign = if raw & POSITIVE == 0 && raw != 0 { "-" } else { "" };
+    Ok(Value::String(format!("{sign}{}", raw & !POSITIVE)))
+}
+
+fn encode_drops(field: &str, text: &str) -> Result<u64, CodecError> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let drops: u64 =
+        digits.parse().ok().filter(|d| *d <= MAX_DROPS).ok_or_else(|| {
+            invalid(field, format!("bad drops amount {text}"))
+        })?;
+    Ok(if negative && drops != 0 { drops } else { drops | POSITIVE })
+}
+
+/// Encodes a decimal string as a normalized 64-bit issued-currency value.
+pub(super) fn encode_iou_value(
+    field: &str,
+    text: &str,
+) -> Result<u64, CodecError> {
+    let bad = || invalid(field, format!("bad issued-currency value {text}"));
+    let (negative, mut digits, mut exponent) =
+        parse_decimal(text).ok_or_else(bad)?;
+    if digits.bytes().all(|b| b == b'0') {
+        return Ok(NOT_XRP);
+    }
+    digits = digits.trim_start_matches('0').to_string();
+    while digits.ends_with('0') {
+        digits.pop();
+        exponent += 1;
+    }
+    if digits.len() > 16 {
+        return Err(invalid(
+            field,
+            format!("more than 16 significant digits in {text}"),
+        ));
+    }
+    let mut mantissa: u64 = digits.parse().map_err(|_| bad())?;
+    while mantissa < MIN_MANTISSA {
+        mantissa *= 10;
+        exponent -= 1;
+    }
+    if !(MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+        return Err(invalid(field, format!("exponent out of range in {text}")));
+    }
+    let sign = if negative { 0 } else { POSITIVE };
+    Ok(NOT_XRP | sign | ((exponent + 97) as u64) << 54 | mantissa)
+}
+
+/// Splits `-12.5e3` into its sign, digits, and base-10 exponent.
+fn parse_decimal(text: &str) -> Option<(bool, String, i32)> {
+    let (negative, rest) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (number, exponent) = match rest.split_once(['e', 'E']) {
+        Some((number, exponent)) => (number, exponent.parse::<i32>().ok()?),
+        None => (rest, 0),
+    };
+    let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+    let digits = format!("{int}{frac}");
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((negative, digits, exponent - frac.len() as i32))
+}
+
+/// Renders a 64-bit issued-currency value as a plain decimal string.
+pub(super) fn decode_iou_value(raw: u64) -> String {
+    let mut mantissa = raw & MANTISSA_MASK;
+    if mantissa == 0 {
+        return "0".to_string();
+    }
+    let mut exponent = ((raw >> 54) & 0xFF) as i32 - 97;
+    while mantissa.is_multiple_of(10) {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    let digits = mantissa.to_string();
+    let sign = if raw & POSITIVE == 0 { "-" } else { "" };
+    if exponent >= 0 {
+        return format!("{sign}{digits}{}", "0".repeat(exponent as usize));
+    }
+    let point = digits.len() as i32 + exponent;
+    if point > 0 {
+        let (int, frac) = digits.split_at(point as usize);
+        format!("{sign}{int}.{frac}")
+    } else {
+        format!("{sign}0.{}{digits}", "0".repeat(-point as usize))
+    }
+}
+
+/// Encodes a currency code: `XRP`, a three-character ISO-style code, or
+/// 40 hex characters.
+pub(super) fn encode_currency(
+    field: &str,
+    code: &str,
+) -> Result<[u8; 20], CodecError> {
+    let mut bytes = [0u8; 20];
+    match code.len() {
+        3 if code == "XRP" => {}
+        3 if code.bytes().all(|b| b.is_ascii_graphic()) => {
+            bytes[12..15].copy_from_slice(code.as_bytes());
+        }
+        40 => bytes.copy_from_slice(&super::hex_exact(field, code, 20)?),
+        _ => return Err(invalid(field, format!("bad currency code {code}"))),
+    }
+    Ok(bytes)
+}
+
+pub(super) fn decode_currency(bytes: [u8; 20]) -> String {
+    if bytes == [0; 20] {
+        return "XRP".to_string();
+    }
+    let standard = bytes[..12].iter().chain(&bytes[15..]).all(|b| *b == 0);
+    let code = &bytes[12..15];
+    if standard && code.iter().all(u8::is_ascii_graphic) {
+        return String::from_utf8_lossy(code).into_owned();
+    }
+    hex::encode_upper(bytes)
+}
+
+/// Encodes an `Issue`: XRP, a currency/issuer pair, or an MPT issuance.
+pub(super) fn write_issue(
+    field: &str,
+    value: &Value,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    let map =
+        value.as_object().ok_or_else(|| invalid(field, "expected object"))?;
+    if let Some(id) = map.get("mpt_issuance_id").and_then(Value::as_str) {
+        let id = super::hex_exact(field, id, 24)?;
+        out.extend(&id[4..]);
+        out.extend(NO_ACCOUNT);
+        out.extend(id[..4].iter().rev());
+        return Ok(());
+    }
+    let currency = str_of(field, map, "currency")?;
+    out.extend(encode_currency(field, currency)?);
+    if currency != "XRP" {
+        out.extend(encode_account_id(field, str_of(field, map, "issuer")?)?);
+    }
+    Ok(())
+}
+
+pub(super) fn read_issue(reader: &mut Reader) -> Result<Value, CodecError> {
+    let first = reader.read_array::<20>()?;
+    if first == [0; 20] {
+        return Ok(json!({ "currency": "XRP" }));
+    }
+    let second = reader.read_array::<20>()?;
+    if second == NO_ACCOUNT {
+        let sequence: Vec<u8> =
+            reader.read_array::<4>()?.into_iter().rev().collect();
+        let id = hex::encode_upper([sequence.as_slice(), &first].concat());
+        return Ok(json!({ "mpt_issuance_id": id }));
+    }
+    Ok(json!({
+        "currency": decode_currency(first),
+        "issuer": decode_account_id(&second),
+    }))
+}
+
+pub(super) fn str_of<'a>(
+    field: &str,
+    map: &'a Map<String, Value>,
+    key: &str,
+) -> Result<&'a str, CodecError> {
+    map.get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid(field, format!("missing `{key}`")))
+}
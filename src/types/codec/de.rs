@@ -0,0 +1,190 @@
+//! Canonical binary → JSON.
+
+use serde_json::{Map, Value, json};
+
+use super::CodecError;
+use super::account::decode_account_id;
+use super::amount::{decode_currency, read_amount, read_issue};
+use super::definitions::{
+    ARRAY_END, DECIMAL_UINT64, Field, FieldType, LEDGER_ENTRY_TYPES,
+    OBJECT_END, TRANSACTION_RESULTS, TRANSACTION_TYPES, field_by_id, name_of,
+};
+use super::reader::Reader;
+
+const PATH_SEPARATOR: u8 = 0xFF;
+const PATHSET_END: u8 = 0x00;
+const STEP_ACCOUNT: u8 = 0x01;
+const STEP_CURRENCY: u8 = 0x10;
+const STEP_ISSUER: u8 = 0x20;
+
+/// Reads fields until the data runs out or, for a nested object, until the
+/// end marker.
+pub(super) fn read_object(
+    reader: &mut Reader,
+    nested: bool,
+) -> Result<Map<String, Value>, CodecError> {
+    let mut map = Map::new();
+    while nested || !reader.is_empty() {
+        let field = read_field_header(reader)?;
+        if *field == OBJECT_END && nested {
+            return Ok(map);
+        }
+        let value = read_value(reader, field)?;
+        map.insert(field.name.to_string(), value);
+    }
+    Ok(map)
+}
+
+fn read_field_header(
+    reader: &mut Reader,
+) -> Result<&'static Field, CodecError> {
+    let (type_code, nth) = reader.read_field_id()?;
+    field_by_id(type_code, nth)
+        .ok_or(CodecError::UnknownFieldId { type_code, nth })
+}
+
+fn read_value(reader: &mut Reader, field: &Field) -> Result<Value, CodecError> {
+    if field.is_vl() {
+        let len = reader.read_vl_length()?;
+        return read_vl_value(reader, field, len);
+    }
+    let value = match field.kind {
+        FieldType::UInt8 => named(field, reader.read_u8()?.into()),
+        FieldType::UInt16 => {
+            named(field, u16::from_be_bytes(reader.read_array()?))
+        }
+        FieldType::UInt32 => u32::from_be_bytes(reader.read_array()?).into(),
+        FieldType::UInt64 => {
+            let raw = reader.read_u64()?;
+            if DECIMAL_UINT64.contains(&field.name) {
+                raw.to_string().into()
+            } else {
+                format!("{raw:016X}").into()
+            }
+        }
+        FieldType::Hash128 => hex_upper(reader.read(16)?),
+        FieldType::Hash160 => hex_upper(reader.read(20)?),
+        FieldType::Hash192 => hex_upper(reader.read(24)?),
+        FieldType::Hash256 => hex_upper(reader.read(32)?),
+        FieldType::Amount => read_amount(reader)?,
+        FieldType::Currency => decode_currency(reader.read_array()?).into(),
+        FieldType::Issue => read_issue(reader)?,
+        FieldType::XChainBridge => read_bridge(reader)?,
+        FieldType::PathSet => read_path_set(reader)?,
+        FieldType::StObject => Value::Object(read_object(reader, true)?),
+        FieldType::StArray => read_array(reader)?,
+        FieldType::Blob | FieldType::AccountId | FieldType::Vector256 => {
+            unreachable!("variable-length types are handled above")
+        }
+    };
+    Ok(value)
+}
+
+fn read_vl_value(
+    reader: &mut Reader,
+    field: &Field,
+    len: usize,
+) -> Result<Value, CodecError> {
+    let bytes = reader.read(len)?;
+    let value = match field.kind {
+        FieldType::AccountId => {
+            let id: [u8; 20] =
+                bytes.try_into().map_err(|_| CodecError::InvalidLength(len))?;
+            decode_account_id(&id).into()
+        }
+        FieldType::Vector256 => {
+            if !len.is_multiple_of(32) {
+                return Err(CodecError::InvalidLength(len));
+            }
+            bytes.chunks(32).map(hex_upper).collect()
+        }
+        _ => hex_upper(bytes),
+    };
+    Ok(value)
+}
+
+/// Type and result codes are rendered by name where known.
+fn named(field: &Field, code: u16) -> Value {
+    let name = match field.name {
+        "TransactionType" => name_of(TRANSACTION_TYPES, code),
+        "LedgerEntryType" => name_of(LEDGER_ENTRY_TYPES, code),
+        "TransactionResult" => u8::try_from(code)
+            .ok()
+            .and_then(|code| name_of(TRANSACTION_RESULTS, code)),
+        _ => None,
+    };
+    name.map_or_else(|| code.into(), Value::from)
+}
+
+fn read_array(reader: &mut Reader) -> Result<Value, CodecError> {
+    let mut elements = Vec::new();
+    loop {
+        let field = read_field_header(reader)?;
+        if *field == ARRAY_END {
+            return Ok(Value::Array(elements));
+        }
+        let value = read_value(reader, field)?;
+        elements.push(json!({ field.name: value }));
+    }
+}
+
+fn read_path_set(reader: &mut Reader) -> Result<Value, CodecError> {
+    let mut paths = Vec::new();
+    let mut path = Vec::new();
+    loop {
+        match reader.read_u8()? {
+            PATHSET_END => {
+                paths.push(Value::Array(path));
+                return Ok(Value::Array(paths));
+            }
+            PATH_SEPARATOR => {
+                paths.push(Value::Array(std::mem::take(&mut path)))
+            }
+            kind => path.push(read_path_step(reader, kind)?),
+        }
+    }
+}
+
+fn read_path_step(reader: &mut Reader, kind: u8) -> Result<Value, CodecError> {
+    let mut step = Map::new();
+    if kind & STEP_ACCOUNT != 0 {
+        step.insert(
+            "account".into(),
+            decode_account_id(&reader.read_array()?).into(),
+        );
+    }
+    if kind & STEP_CURRENCY != 0 {
+        step.insert(
+            "currency".into(),
+            decode_currency(reader.read_array()?).into(),
+        );
+    }
+    if kind & STEP_ISSUER != 0 {
+        step.insert(
+            "issuer".into(),
+            decode_account_id(&reader.read_array()?).into(),
+        );
+    }
+    Ok(Value::Object(step))
+}
+
+fn read_bridge(reader: &mut Reader) -> Result<Value, CodecError> {
+    let mut map = Map::new();
+    for (door, issue) in [
+        ("LockingChainDoor", "LockingChainIssue"),
+        ("IssuingChainDoor", "IssuingChainIssue"),
+    ] {
+        let len = reader.read_vl_length()?;
+        let id: [u8; 20] = reader
+            .read(len)?
+            .try_into()
+            .map_err(|_| CodecError::InvalidLength(len))?;
+        map.insert(door.into(), decode_account_id(&id).into());
+        map.insert(issue.into(), read_issue(reader)?);
+    }
+    Ok(Value::Object(map))
+}
+
+fn hex_upper(bytes: &[u8]) -> Value {
+    hex::encode_upper(bytes).into()
+}
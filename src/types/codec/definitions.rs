@@ -0,0 +1,430 @@
+//! Field, transaction-type, ledger-entry-type, and result-code tables from the
+//! protocol definitions (`definitions.json` in rippled and xrpl.js).
+
+/// Serialized type of a field, with its protocol type code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FieldType {
+    UInt16 = 1,
+    UInt32 = 2,
+    UInt64 = 3,
+    Hash128 = 4,
+    Hash256 = 5,
+    Amount = 6,
+    Blob = 7,
+    AccountId = 8,
+    StObject = 14,
+    StArray = 15,
+    UInt8 = 16,
+    Hash160 = 17,
+    PathSet = 18,
+    Vector256 = 19,
+    Hash192 = 21,
+    Issue = 24,
+    XChainBridge = 25,
+    Currency = 26,
+}
+
+impl FieldType {
+    fn from_code(code: u8) -> Option<Self> {
+        use FieldType::*;
+        [
+            UInt16,
+            UInt32,
+            UInt64,
+            Hash128,
+            Hash256,
+            Amount,
+            Blob,
+            AccountId,
+            StObject,
+            StArray,
+            UInt8,
+            Hash160,
+            PathSet,
+            Vector256,
+            Hash192,
+            Issue,
+            XChainBridge,
+            Currency,
+        ]
+        .into_iter()
+        .find(|t| *t as u8 == code)
+    }
+}
+
+/// A serializable field: its JSON name, type, and ordinal within the type.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Field {
+    pub(crate) name: &'static str,
+    pub(crate) kind: FieldType,
+    pub(crate) nth: u8,
+}
+
+impl Field {
+    /// Canonical order: by type code, then by ordinal.
+    pub(crate) fn sort_key(&self) -> (u8, u8) {
+        (self.kind as u8, self.nth)
+    }
+
+    /// Whether the field is covered by a (multi-)signature.
+    pub(crate) fn is_signing(&self) -> bool {
+        !matches!(self.name, "TxnSignature" | "Signers")
+    }
+
+    /// Whether the value carries a variable-length prefix.
+    pub(crate) fn is_vl(&self) -> bool {
+        matches!(
+            self.kind,
+            FieldType::Blob | FieldType::AccountId | FieldType::Vector256
+        )
+    }
+
+    /// One- to three-byte field ID preceding the value.
+    pub(crate) fn header(&self) -> Vec<u8> {
+        let (code, nth) = (self.kind as u8, self.nth);
+        match (code < 16, nth < 16) {
+            (true, true) => vec![code << 4 | nth],
+            (true, false) => vec![code << 4, nth],
+            (false, true) => vec![nth, code],
+            (false, false) => vec![0, code, nth],
+        }
+    }
+}
+
+macro_rules! fields {
+    ( $( $kind:ident { $( $name:literal = $nth:literal ),+ $(,)? } )+ ) => {
+        &[ $( $( Field { name: $name, kind: FieldType::$kind, nth: $nth }, )+ )+ ]
+    };
+}
+
+pub(crate) const OBJECT_END: Field =
+    Field { name: "ObjectEndMarker", kind: FieldType::StObject, nth: 1 };
+pub(crate) const ARRAY_END: Field =
+    Field { name: "ArrayEndMarker", kind: FieldType::StArray, nth: 1 };
+
+const FIELDS: &[Field] = fields! {
+    UInt8 {
+        "CloseResolution" = 1, "Method" = 2, "TransactionResult" = 3,
+        "Scale" = 4, "AssetScale" = 5, "TickSize" = 16,
+        "UNLModifyDisabling" = 17, "WasLockingChainSend" = 19,
+    }
+    UInt16 {
+        "LedgerEntryType" = 1, "TransactionType" = 2, "SignerWeight" = 3,
+        "TransferFee" = 4, "TradingFee" = 5, "DiscountedFee" = 6,
+        "Version" = 16, "LedgerFixType" = 21,
+    }
+    UInt32 {
+        "NetworkID" = 1, "Flags" = 2, "SourceTag" = 3, "Sequence" = 4,
+        "PreviousTxnLgrSeq" = 5, "LedgerSequence" = 6, "CloseTime" = 7,
+        "ParentCloseTime" = 8, "SigningTime" = 9, "Expiration" = 10,
+        "TransferRate" = 11, "WalletSize" = 12, "OwnerCount" = 13,
+        "DestinationTag" = 14, "LastUpdateTime" = 15, "HighQualityIn" = 16,
+        "HighQualityOut" = 17, "LowQualityIn" = 18, "LowQualityOut" = 19,
+        "QualityIn" = 20, "QualityOut" = 21, "StampEscrow" = 22,
+        "BondAmount" = 23, "LoadFee" = 24, "OfferSequence" = 25,
+        "FirstLedgerSequence" = 26, "LastLedgerSequence" = 27,
+        "TransactionIndex" = 28, "OperationLimit" = 29,
+        "ReferenceFeeUnits" = 30, "ReserveBase" = 31, "ReserveIncrement" = 32,
+        "SetFlag" = 33, "ClearFlag" = 34, "SignerQuorum" = 35,
+        "CancelAfter" = 36, "FinishAfter" = 37, "SignerListID" = 38,
+        "SettleDelay" = 39, "TicketCount" = 40, "TicketSequence" = 41,
+        "NFTokenTaxon" = 42, "MintedNFTokens" = 43, "BurnedNFTokens" = 44,
+        "VoteWeight" = 48, "FirstNFTokenSequence" = 50,
+        "OracleDocumentID" = 51,
+    }
+    UInt64 {
+        "IndexNext" = 1, "IndexPrevious" = 2, "BookNode" = 3, "OwnerNode" = 4,
+        "BaseFee" = 5, "ExchangeRate" = 6, "LowNode" = 7, "HighNode" = 8,
+        "DestinationNode" = 9, "Cookie" = 10, "ServerVersion" = 11,
+        "NFTokenOfferNode" = 12, "XChainClaimID" = 20,
+        "XChainAccountCreateCount" = 21, "XChainAccountClaimCount" = 22,
+        "AssetPrice" = 23, "MaximumAmount" = 24, "OutstandingAmount" = 25,
+        "MPTAmount" = 26, "IssuerNode" = 27, "SubjectNode" = 28,
+    }
+    Hash128 { "EmailHash" = 1 }
+    Hash160 {
+        "TakerPaysCurrency" = 1, "TakerPaysIssuer" = 2,
+        "TakerGetsCurrency" = 3, "TakerGetsIssuer" = 4,
+    }
+    Hash192 { "MPTokenIssuanceID" = 1 }
+    Hash256 {
+        "LedgerHash" = 1, "ParentHash" = 2, "TransactionHash" = 3,
+        "AccountHash" = 4, "PreviousTxnID" = 5, "LedgerIndex" = 6,
+        "WalletLocator" = 7, "RootIndex" = 8, "AccountTxnID" = 9,
+        "NFTokenID" = 10, "AMMID" = 14, "BookDirectory" = 16,
+        "InvoiceID" = 17, "Nickname" = 18, "Amendment" = 19, "Digest" = 21,
+        "Channel" = 22, "ConsensusHash" = 23, "CheckID" = 24,
+        "ValidatedHash" = 25, "PreviousPageMin" = 26, "NextPageMin" = 27,
+        "NFTokenBuyOffer" = 28, "NFTokenSellOffer" = 29, "DomainID" = 34,
+    }
+    Amount {
+        "Amount" = 1, "Balance" = 2, "LimitAmount" = 3, "TakerPays" = 4,
+        "TakerGets" = 5, "LowLimit" = 6, "HighLimit" = 7, "Fee" = 8,
+        "SendMax" = 9, "DeliverMin" = 10, "Amount2" = 11, "BidMin" = 12,
+        "BidMax" = 13, "MinimumOffer" = 16, "RippleEscrow" = 17,
+        "DeliveredAmount" = 18, "NFTokenBrokerFee" = 19,
+        "BaseFeeDrops" = 22, "ReserveBaseDrops" = 23,
+        "ReserveIncrementDrops" = 24, "LPTokenOut" = 25, "LPTokenIn" = 26,
+        "EPrice" = 27, "Price" = 28, "SignatureReward" = 29,
+        "MinAccountCreateAmount" = 30, "LPTokenBalance" = 31,
+    }
+    Blob {
+        "PublicKey" = 1, "MessageKey" = 2, "SigningPubKey" = 3,
+        "TxnSignature" = 4, "URI" = 5, "Signature" = 6, "Domain" = 7,
+        "FundCode" = 8, "RemoveCode" = 9, "ExpireCode" = 10,
+        "CreateCode" = 11, "MemoType" = 12, "MemoData" = 13,
+        "MemoFormat" = 14, "Fulfillment" = 16, "Condition" = 17,
+        "MasterSignature" = 18, "UNLModifyValidator" = 19,
+        "ValidatorToDisable" = 20, "ValidatorToReEnable" = 21,
+        "DIDDocument" = 26, "Data" = 27, "AssetClass" = 28, "Provider" = 29,
+        "MPTokenMetadata" = 30, "CredentialType" = 31,
+    }
+    AccountId {
+        "Account" = 1, "Owner" = 2, "Destination" = 3, "Issuer" = 4,
+        "Authorize" = 5, "Unauthorize" = 6, "RegularKey" = 8,
+        "NFTokenMinter" = 9, "EmitCallback" = 10, "Holder" = 11,
+        "OtherChainSource" = 18, "OtherChainDestination" = 19,
+        "AttestationSignerAccount" = 20, "AttestationRewardAccount" = 21,
+        "LockingChainDoor" = 22, "IssuingChainDoor" = 23, "Subject" = 24,
+    }
+    StObject {
+        "TransactionMetaData" = 2, "CreatedNode" = 3, "DeletedNode" = 4,
+        "ModifiedNode" = 5, "PreviousFields" = 6, "FinalFields" = 7,
+        "NewFields" = 8, "TemplateEntry" = 9, "Memo" = 10,
+        "SignerEntry" = 11, "NFToken" = 12, "Signer" = 16, "Majority" = 18,
+        "DisabledValidator" = 19, "VoteEntry" = 25, "AuctionSlot" = 26,
+        "AuthAccount" = 27, "XChainClaimProofSig" = 28,
+        "XChainCreateAccountProofSig" = 29,
+        "XChainClaimAttestationCollectionElement" = 30,
+        "XChainCreateAccountAttestationCollectionElement" = 31,
+        "PriceData" = 32, "Credential" = 33,
+    }
+    StArray {
+        "Signers" = 3, "SignerEntries" = 4, "Template" = 5, "Necessary" = 6,
+        "Sufficient" = 7, "AffectedNodes" = 8, "Memos" = 9, "NFTokens" = 10,
+        "VoteSlots" = 12, "Majorities" = 16, "DisabledValidators" = 17,
+        "XChainClaimAttestations" = 21, "XChainCreateAccountAttestations" = 22,
+        "PriceDataSeries" = 24, "AuthAccounts" = 25,
+        "AuthorizeCredentials" = 26, "UnauthorizeCredentials" = 27,
+        "AcceptedCredentials" = 28,
+    }
+    PathSet { "Paths" = 1 }
+    Vector256 {
+        "Indexes" = 1, "Hashes" = 2, "Amendments" = 3, "NFTokenOffers" = 4,
+        "CredentialIDs" = 5,
+    }
+    Issue {
+        "LockingChainIssue" = 1, "IssuingChainIssue" = 2, "Asset" = 3,
+        "Asset2" = 4,
+    }
+    XChainBridge { "XChainBridge" = 1 }
+    Currency { "BaseAsset" = 1, "QuoteAsset" = 2 }
+};
+
+/// `UInt64` fields that hold token quantities and use decimal, not hex, in JSON.
+pub(crate) const DECIMAL_UINT64: [&str; 3] =
+    ["MaximumAmount", "OutstandingAmount", "MPTAmount"];
+
+pub(crate) fn field_by_name(name: &str) -> Option<&'static Field> {
+    FIELDS.iter().find(|f| f.name == name)
+}
+
+pub(crate) fn field_by_id(code: u8, nth: u8) -> Option<&'static Field> {
+    if (code, nth) == OBJECT_END.sort_key() {
+        return Some(&OBJECT_END);
+    }
+    if (code, nth) == ARRAY_END.sort_key() {
+        return Some(&ARRAY_END);
+    }
+    let kind = FieldType::from_code(code)?;
+    FIELDS.iter().find(|f| f.kind == kind && f.nth == nth)
+}
+
+pub(crate) const TRANSACTION_TYPES: &[(&str, u16)] = &[
+    ("Payment", 0),
+    ("EscrowCreate", 1),
+    ("EscrowFinish", 2),
+    ("AccountSet", 3),
+    ("EscrowCancel", 4),
+    ("SetRegularKey", 5),
+    ("OfferCreate", 7),
+    ("OfferCancel", 8),
+    ("TicketCreate", 10),
+    ("SignerListSet", 12),
+    ("PaymentChannelCreate", 13),
+    ("PaymentChannelFund", 14),
+    ("PaymentChannelClaim", 15),
+    ("CheckCreate", 16),
+    ("CheckCash", 17),
+    ("CheckCancel", 18),
+    ("DepositPreauth", 19),
+    ("TrustSet", 20),
+    ("AccountDelete", 21),
+    ("NFTokenMint", 25),
+    ("NFTokenBurn", 26),
+    ("NFTokenCreateOffer", 27),
+    ("NFTokenCancelOffer", 28),
+    ("NFTokenAcceptOffer", 29),
+    ("Clawback", 30),
+    ("AMMClawback", 31),
+    ("AMMCreate", 35),
+    ("AMMDeposit", 36),
+    ("AMMWithdraw", 37),
+    ("AMMVote", 38),
+    ("AMMBid", 39),
+    ("AMMDelete", 40),
+    ("XChainCreateClaimID", 41),
+    ("XChainCommit", 42),
+    ("XChainClaim", 43),
+    ("XChainAccountCreateCommit", 44),
+    ("XChainAddClaimAttestation", 45),
+    ("XChainAddAccountCreateAttestation", 46),
+    ("XChainModifyBridge", 47),
+    ("XChainCreateBridge", 48),
+    ("DIDSet", 49),
+    ("DIDDelete", 50),
+    ("OracleSet", 51),
+    ("OracleDelete", 52),
+    ("LedgerStateFix", 53),
+    ("MPTokenIssuanceCreate", 54),
+    ("MPTokenIssuanceDestroy", 55),
+    ("MPTokenIssuanceSet", 56),
+    ("MPTokenAuthorize", 57),
+    ("CredentialCreate", 58),
+    ("CredentialAccept", 59),
+    ("CredentialDelete", 60),
+    ("NFTokenModify", 61),
+    ("PermissionedDomainSet", 62),
+    ("PermissionedDomainDelete", 63),
+    ("EnableAmendment", 100),
+    ("SetFee", 101),
+    ("UNLModify", 102),
+];
+
+pub(crate) const LEDGER_ENTRY_TYPES: &[(&str, u16)] = &[
+    ("NFTokenOffer", 0x37),
+    ("Check", 0x43),
+    ("DID", 0x49),
+    ("NegativeUNL", 0x4e),
+    ("NFTokenPage", 0x50),
+    ("SignerList", 0x53),
+    ("Ticket", 0x54),
+    ("AccountRoot", 0x61),
+    ("DirectoryNode", 0x64),
+    ("Amendments", 0x66),
+    ("LedgerHashes", 0x68),
+    ("Bridge", 0x69),
+    ("Offer", 0x6f),
+    ("DepositPreauth", 0x70),
+    ("XChainOwnedClaimID", 0x71),
+    ("RippleState", 0x72),
+    ("FeeSettings", 0x73),
+    ("XChainOwnedCreateAccountClaimID", 0x74),
+    ("Escrow", 0x75),
+    ("PayChannel", 0x78),
+    ("AMM", 0x79),
+    ("MPTokenIssuance", 0x7e),
+    ("MPToken", 0x7f),
+    ("Oracle", 0x80),
+    ("Credential", 0x81),
+    ("PermissionedDomain", 0x82),
+    ("Delegate", 0x83),
+    ("Vault", 0x84),
+];
+
+/// Only `tes` and `tec` codes can appear in a validated transaction's metadata.
+pub(crate) const TRANSACTION_RESULTS: &[(&str, u8)] = &[
+    ("tesSUCCESS", 0),
+    ("tecCLAIM", 100),
+    ("tecPATH_PARTIAL", 101),
+    ("tecUNFUNDED_ADD", 102),
+    ("tecUNFUNDED_OFFER", 103),
+    ("tecUNFUNDED_PAYMENT", 104),
+    ("tecFAILED_PROCESSING", 105),
+    ("tecDIR_FULL", 121),
+    ("tecINSUF_RESERVE_LINE", 122),
+    ("tecINSUF_RESERVE_OFFER", 123),
+    ("tecNO_DST", 124),
+    ("tecNO_DST_INSUF_XRP", 125),
+    ("tecNO_LINE_INSUF_RESERVE", 126),
+    ("tecNO_LINE_REDUNDANT", 127),
+    ("tecPATH_DRY", 128),
+    ("tecUNFUNDED", 129),
+    ("tecNO_ALTERNATIVE_KEY", 130),
+    ("tecNO_REGULAR_KEY", 131),
+    ("tecOWNERS", 132),
+    ("tecNO_ISSUER", 133),
+    ("tecNO_AUTH", 134),
+    ("tecNO_LINE", 135),
+    ("tecINSUFF_FEE", 136),
+    ("tecFROZEN", 137),
+    ("tecNO_TARGET", 138),
+    ("tecNO_PERMISSION", 139),
+    ("tecNO_ENTRY", 140),
+    ("tecINSUFFICIENT_RESERVE", 141),
+    ("tecNEED_MASTER_KEY", 142),
+    ("tecDST_TAG_NEEDED", 143),
+    ("tecINTERNAL", 144),
+    ("tecOVERSIZE", 145),
+    ("tecCRYPTOCONDITION_ERROR", 146),
+    ("tecINVARIANT_FAILED", 147),
+    ("tecEXPIRED", 148),
+    ("tecDUPLICATE", 149),
+    ("tecKILLED", 150),
+    ("tecHAS_OBLIGATIONS", 151),
+    ("tecTOO_SOON", 152),
+    ("tecHOOK_REJECTED", 153),
+    ("tecMAX_SEQUENCE_REACHED", 154),
+    ("tecNO_SUITABLE_NFTOKEN_PAGE", 155),
+    ("tecNFTOKEN_BUY_SELL_MISMATCH", 156),
+    ("tecNFTOKEN_OFFER_TYPE_MISMATCH", 157),
+    ("tecCANT_ACCEPT_OWN_NFTOKEN_OFFER", 158),
+    ("tecINSUFFICIENT_FUNDS", 159),
+    ("tecOBJECT_NOT_FOUND", 160),
+    ("tecINSUFFICIENT_PAYMENT", 161),
+    ("tecUNFUNDED_AMM", 162),
+    ("tecAMM_BALANCE", 163),
+    ("tecAMM_FAILED", 164),
+    ("tecAMM_INVALID_TOKENS", 165),
+    ("tecAMM_EMPTY", 166),
+    ("tecAMM_NOT_EMPTY", 167),
+    ("tecAMM_ACCOUNT", 168),
+    ("tecINCOMPLETE", 169),
+    ("tecXCHAIN_BAD_TRANSFER_ISSUE", 170),
+    ("tecXCHAIN_NO_CLAIM_ID", 171),
+    ("tecXCHAIN_BAD_CLAIM_ID", 172),
+    ("tecXCHAIN_CLAIM_NO_QUORUM", 173),
+    ("tecXCHAIN_PROOF_UNKNOWN_KEY", 174),
+    ("tecXCHAIN_CREATE_ACCOUNT_NONXRP_ISSUE", 175),
+    ("tecXCHAIN_WRONG_CHAIN", 176),
+    ("tecXCHAIN_REWARD_MISMATCH", 177),
+    ("tecXCHAIN_NO_SIGNERS_LIST", 178),
+    ("tecXCHAIN_SENDING_ACCOUNT_MISMATCH", 179),
+    ("tecXCHAIN_INSUFF_CREATE_AMOUNT", 180),
+    ("tecXCHAIN_ACCOUNT_CREATE_PAST", 181),
+    ("tecXCHAIN_ACCOUNT_CREATE_TOO_MANY", 182),
+    ("tecXCHAIN_PAYMENT_FAILED", 183),
+    ("tecXCHAIN_SELF_COMMIT", 184),
+    ("tecXCHAIN_BAD_PUBLIC_KEY_ACCOUNT_PAIR", 185),
+    ("tecXCHAIN_CREATE_ACCOUNT_DISABLED", 186),
+    ("tecEMPTY_DID", 187),
+    ("tecINVALID_UPDATE_TIME", 188),
+    ("tecTOKEN_PAIR_NOT_FOUND", 189),
+    ("tecARRAY_EMPTY", 190),
+    ("tecARRAY_TOO_LARGE", 191),
+    ("tecLOCKED", 192),
+    ("tecBAD_CREDENTIALS", 193),
+];
+
+/// Looks up the code for `name` in one of the tables above.
+pub(crate) fn code_of<T: Copy>(table: &[(&str, T)], name: &str) -> Option<T> {
+    table.iter().find(|(n, _)| *n == name).map(|(_, code)| *code)
+}
+
+/// Looks up the name for `code` in one of the tables above.
+pub(crate) fn name_of<T: Copy + PartialEq>(
+    table: &[(&'static str, T)],
+    code: T,
+) -> Option<&'static str> {
+    table.iter().find(|(_, c)| *c == code).map(|(name, _)| *name)
+}
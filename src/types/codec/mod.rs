@@ -0,0 +1,445 @@
+//! Canonical XRPL binary format.
+//!
+//! Encodes transaction JSON (as produced by serializing a [`Transaction`])
+//! into the byte layout that is signed, hashed, and submitted as `tx_blob`,
+//! and decodes `tx_blob` / `meta_blob` hex back into JSON and typed values.
+//!
+//! Fields are written sorted by type code and field ordinal, each behind a
+//! one- to three-byte field ID. Blobs, account IDs and `Vector256` values
+//! carry a variable-length prefix; objects and arrays end with a marker byte.
+//!
+//! # Example
+//! ```rust
+//! use serde_json::json;
+//! use xrpl::types::codec;
+//!
+//! let tx = json!({
+//!     "TransactionType": "Payment",
+//!     "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+//!     "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+//!     "Amount": "1000000",
+//!     "Fee": "12",
+//!     "Sequence": 1,
+//! });
+//! let blob = codec::encode(&tx)?;
+//! assert_eq!(codec::decode(&blob)?, tx);
+//!
+//! let to_sign = codec::signing_data(&tx)?;
+//! assert_eq!(&to_sign[..4], &codec::HASH_PREFIX_TRANSACTION_SIGN);
+//! # Ok::<(), codec::CodecError>(())
+//! ```
+
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha512};
+use thiserror::Error;
+
+use crate::types::{Transaction, TransactionMeta};
+
+mod account;
+mod amount;
+mod de;
+mod definitions;
+mod reader;
+mod ser;
+
+use account::encode_account_id;
+
+/// Prefix of the bytes hashed to produce a transaction ID (`TXN\0`).
+pub const HASH_PREFIX_TRANSACTION_ID: [u8; 4] = *b"TXN\0";
+/// Prefix of the bytes signed by a single signer (`STX\0`).
+pub const HASH_PREFIX_TRANSACTION_SIGN: [u8; 4] = *b"STX\0";
+/// Prefix of the bytes signed by each signer of a multi-signed transaction (`SMT\0`).
+pub const HASH_PREFIX_TRANSACTION_MULTISIGN: [u8; 4] = *b"SMT\0";
+/// Prefix of the bytes signed for an off-ledger payment channel claim (`CLM\0`).
+pub const HASH_PREFIX_PAYMENT_CHANNEL_CLAIM: [u8; 4] = *b"CLM\0";
+
+/// Errors returned while encoding or decoding the binary format.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CodecError {
+    /// A PascalCase JSON key does not name a known field.
+    #[error("Unknown field: {0}")]
+    UnknownField(String),
+    /// A blob contains a field ID that does not name a known field.
+    #[error("Unknown field ID: type {type_code}, nth {nth}")]
+    UnknownFieldId {
+        /// Serialized type code read from the blob.
+        type_code: u8,
+        /// Field ordinal within the type.
+        nth: u8,
+    },
+    /// A field's JSON value does not fit the field's type.
+    #[error("Invalid value for {field}: {reason}")]
+    InvalidValue {
+        /// JSON name of the field.
+        field: String,
+        /// What is wrong with the value.
+        reason: String,
+    },
+    /// A length prefix or fixed-size value has an impossible length.
+    #[error("Invalid length: {0}")]
+    InvalidLength(usize),
+    /// The blob ended in the middle of a field.
+    #[error("Unexpected end of data")]
+    UnexpectedEnd,
+    /// The input is not a JSON object, or the decoded JSON does not match the
+    /// typed model.
+    #[error("JSON error: {0}")]
+    Json(String),
+}
+
+/// Encodes a transaction or ledger object into canonical binary.
+pub fn encode(json: &Value) -> Result<Vec<u8>, CodecError> {
+    let mut out = Vec::new();
+    ser::write_object(&normalized(json)?, false, &mut out)?;
+    Ok(out)
+}
+
+/// Encodes only the signed fields, omitting `TxnSignature` and `Signers`.
+pub fn encode_for_signing(json: &Value) -> Result<Vec<u8>, CodecError> {
+    let mut out = Vec::new();
+    ser::write_object(&normalized(json)?, true, &mut out)?;
+    Ok(out)
+}
+
+/// Bytes a single signer signs: `STX\0` followed by the signed fields.
+pub fn signing_data(json: &Value) -> Result<Vec<u8>, CodecError> {
+    let mut out = HASH_PREFIX_TRANSACTION_SIGN.to_vec();
+    out.extend(encode_for_signing(json)?);
+    Ok(out)
+}
+
+/// Bytes `signer` signs for a multi-signed transaction: `SMT\0`, the signed
+/// fields, then the signer's 20-byte AccountID. `SigningPubKey` must be empty.
+pub fn multisigning_data(
+    json: &Value,
+    signer: &str,
+) -> Result<Vec<u8>, CodecError> {
+    let mut out = HASH_PREFIX_TRANSACTION_MULTISIGN.to_vec();
+    out.extend(encode_for_signing(json)?);
+    out.extend(encode_account_id("Account", signer)?);
+    Ok(out)
+}
+
+/// Decodes canonical binary into JSON.
+pub fn decode(blob: &[u8]) -> Result<Value, CodecError> {
+    let mut reader = reader::Reader::new(blob);
+    Ok(Value::Object(de::read_object(&mut reader, false)?))
+}
+
+/// First 32 bytes of the SHA-512 digest of `data`.
+pub fn sha512_half(data: &[u8]) -> [u8; 32] {
+    let digest = Sha512::digest(data);
+    let mut half = [0u8; 32];
+    half.copy_from_slice(&digest[..32]);
+    half
+}
+
+/// Transaction ID of a signed `tx_blob`, as upper-case hex.
+pub fn transaction_hash(blob: &[u8]) -> String {
+    let mut data = HASH_PREFIX_TRANSACTION_ID.to_vec();
+    data.extend_from_slice(blob);
+    hex::encode_upper(sha512_half(&data))
+}
+
+/// Drops the API-only `DeliverMax` alias, which is written as `Amount`.
+fn normalized(json: &Value) -> Result<Map<String, Value>, CodecError> {
+    let mut map = json
+        .as_object()
+        .cloned()
+        .ok_or_else(|| CodecError::Json("expected a JSON object".into()))?;
+    if let Some(deliver_max) = map.remove("DeliverMax") {
+        match map.get("Amount") {
+            None => {
+                map.insert("Amount".into(), deliver_max);
+            }
+            Some(amount) if *amount == deliver_max => {}
+            Some(_) => {
+                return Err(CodecError::InvalidValue {
+                    field: "DeliverMax".into(),
+                    reason: "differs from Amount".into(),
+                });
+            }
+        }
+    }
+    Ok(map)
+}
+
+fn decode_hex(field: &str, text: &str) -> Result<Vec<u8>, CodecError> {
+    hex::decode(text).map_err(|e| CodecError::InvalidValue {
+        field: field.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Decodes exactly `len` bytes of hex.
+fn hex_exact(
+    field: &str,
+    text: &str,
+    len: usize,
+) -> Result<Vec<u8>, CodecError> {
+    let invalid = |reason: String| CodecError::InvalidValue {
+        field: field.to_string(),
+        reason,
+    };
+    let bytes = decode_hex(field, text)?;
+    if bytes.len() != len {
+        return Err(invalid(format!(
+            "expected {len} bytes, got {}",
+            bytes.len()
+        )));
+    }
+    Ok(bytes)
+}
+
+impl Transaction {
+    fn encoded(&self) -> Result<Vec<u8>, CodecError> {
+        let json = serde_json::to_value(self)
+            .map_err(|e| CodecError::Json(e.to_string()))?;
+        encode(&json)
+    }
+
+    /// Serializes the transaction into an upper-case hex `tx_blob`.
+    pub fn to_blob(&self) -> Result<String, CodecError> {
+        Ok(hex::encode_upper(self.encoded()?))
+    }
+
+    /// Parses a hex `tx_blob`, filling [`hash`](Self::hash) from its bytes.
+    pub fn from_blob(blob: impl AsRef<str>) -> Result<Self, CodecError> {
+        let bytes = decode_hex("tx_blob", blob.as_ref())?;
+        let mut tx: Self = serde_json::from_value(decode(&bytes)?)
+            .map_err(|e| CodecError::Json(e.to_string()))?;
+        tx.hash = Some(transaction_hash(&bytes));
+        Ok(tx)
+    }
+
+    /// Computes the transaction ID. Only meaningful once the transaction is
+    /// signed, since the signature fields are part of the hashed bytes.
+    pub fn compute_hash(&self) -> Result<String, CodecError> {
+        Ok(transaction_hash(&self.encoded()?))
+    }
+
+    /// Sets [`hash`](Self::hash) to [`compute_hash`](Self::compute_hash).
+    pub fn fill_hash(&mut self) -> Result<&str, CodecError> {
+        let hash = self.compute_hash()?;
+        Ok(self.hash.insert(hash))
+    }
+}
+
+impl TransactionMeta {
+    /// Parses a hex `meta_blob`. `delivered_amount` is taken from the binary
+    /// `DeliveredAmount` field when present.
+    pub fn from_blob(blob: impl AsRef<str>) -> Result<Self, CodecError> {
+        let bytes = decode_hex("meta_blob", blob.as_ref())?;
+        let mut json = decode(&bytes)?;
+        if let Some(delivered) = json.get("DeliveredAmount").cloned() {
+            json["delivered_amount"] = delivered;
+        }
+        serde_json::from_value(json)
+            .map_err(|e| CodecError::Json(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    use crate::types::AccountObject;
+
+    /// Worked example from the XRPL serialization documentation.
+    const OFFER_BLOB: &str = "120007220008000024001ABED82A2380BF2C2019001ABED764D55920AC9391400000000000000000000000000055534400000000000A20B3C85F482532A9578DBB3950B85CA06594D165400000037E11D60068400000000000000A732103EE83BB432547885C219634A1BC407A9DB0474145D69737D09CCDC63E1DEE7FE3744630440220143759437C04F7B61F012563AFE90D8DAFC46E86035E1D965A9CED282C97D4CE02204CFD241E86F17E011298FC1A39B63386C74306A5DE047E213B0F29EFA4571C2C8114DD76483FACDEE26E60D8A586BB58D09F27045C46";
+    const OFFER_HASH: &str =
+        "73734B611DDA23D3F5F62E20A173B78AB8406AC5015094DA53F53D39B9EDB06C";
+
+    fn offer_json() -> Value {
+        json!({
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Expiration": 595640108,
+            "Fee": "10",
+            "Flags": 524288,
+            "OfferSequence": 1752791,
+            "Sequence": 1752792,
+            "SigningPubKey": "03EE83BB432547885C219634A1BC407A9DB0474145D69737D09CCDC63E1DEE7FE3",
+            "TakerGets": "15000000000",
+            "TakerPays": {
+                "currency": "USD",
+                "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                "value": "7072.8"
+            },
+            "TransactionType": "OfferCreate",
+            "TxnSignature": "30440220143759437C04F7B61F012563AFE90D8DAFC46E86035E1D965A9CED282C97D4CE02204CFD241E86F17E011298FC1A39B63386C74306A5DE047E213B0F29EFA4571C2C",
+        })
+    }
+
+    fn encode_hex(json: Value) -> String {
+        hex::encode_upper(encode(&json).unwrap())
+    }
+
+    #[test]
+    fn test_encode_documented_vector() {
+        let mut tx = offer_json();
+        tx["hash"] = OFFER_HASH.into();
+
+        let blob = encode(&tx).unwrap();
+
+        assert_eq!(hex::encode_upper(&blob), OFFER_BLOB);
+        assert_eq!(transaction_hash(&blob), OFFER_HASH);
+        assert_eq!(decode(&blob).unwrap(), offer_json());
+    }
+
+    #[test]
+    fn test_transaction_blob_round_trip() {
+        let tx = Transaction::from_blob(OFFER_BLOB).unwrap();
+
+        assert_eq!(tx.hash.as_deref(), Some(OFFER_HASH));
+        assert_eq!(tx.to_blob().unwrap(), OFFER_BLOB);
+
+        let mut unhashed = tx.clone();
+        unhashed.hash = None;
+        assert_eq!(unhashed.fill_hash().unwrap(), OFFER_HASH);
+    }
+
+    #[test]
+    fn test_signing_data_omits_signatures() {
+        let tx = offer_json();
+        let signing = signing_data(&tx).unwrap();
+        let unsigned = {
+            let mut tx = tx.clone();
+            tx.as_object_mut().unwrap().remove("TxnSignature");
+            encode(&tx).unwrap()
+        };
+
+        assert_eq!(&signing[..4], b"STX\0");
+        assert_eq!(&signing[4..], unsigned.as_slice());
+
+        let signer = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+        let multi = multisigning_data(&tx, signer).unwrap();
+        assert_eq!(&multi[..4], b"SMT\0");
+        assert_eq!(
+            hex::encode_upper(&multi[multi.len() - 20..]),
+            "B5F762798A53D543A014CAF8B297CFF8F2F937E8"
+        );
+    }
+
+    #[test]
+    fn test_amount_encoding() {
+        let usd = |value: &str| {
+            json!({ "Amount": {
+                "value": value,
+                "currency": "USD",
+                "issuer": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            }})
+        };
+
+        assert_eq!(encode_hex(json!({ "Amount": "1" })), "614000000000000001");
+        assert!(encode_hex(usd("1")).starts_with("61D4838D7EA4C68000"));
+        assert!(encode_hex(usd("0")).starts_with("618000000000000000"));
+        assert!(encode_hex(usd("-1")).starts_with("6194838D7EA4C68000"));
+        assert!(encode(&usd("1.23456789012345678")).is_err());
+
+        for value in ["1", "0", "-1", "7072.8", "0.000001", "1200000"] {
+            let decoded = decode(&encode(&usd(value)).unwrap()).unwrap();
+            assert_eq!(decoded["Amount"]["value"], value);
+        }
+
+        let mpt = json!({ "Amount": {
+            "value": "100",
+            "mpt_issuance_id": "0000012FFD9EE5DA93AC614B4DB94D7E0FCE415CA51BED47",
+        }});
+        let blob = encode(&mpt).unwrap();
+        assert_eq!(hex::encode_upper(&blob[..10]), "61600000000000000064");
+        assert_eq!(decode(&blob).unwrap(), mpt);
+    }
+
+    #[test]
+    fn test_field_ids_and_lengths() {
+        let tx = json!({
+            "TransactionType": "Payment",
+            "LastLedgerSequence": 1,
+            "TransactionResult": "tecPATH_DRY",
+            "MemoData": "AB".repeat(193),
+        });
+        let hex = encode_hex(tx.clone());
+
+        assert!(hex.starts_with("1200"));
+        assert!(hex.contains("201B00000001"));
+        assert!(hex.contains("7DC100"));
+        assert!(hex.ends_with("031080"));
+        assert_eq!(decode(&hex::decode(hex).unwrap()).unwrap(), tx);
+    }
+
+    #[test]
+    fn test_nested_objects_arrays_and_paths() {
+        let tx = json!({
+            "TransactionType": "Payment",
+            "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Memos": [{ "Memo": { "MemoType": "01", "MemoData": "02" } }],
+            "Paths": [
+                [{ "account": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe" }],
+                [{ "currency": "EUR", "issuer": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe" }],
+            ],
+            "Asset": { "currency": "XRP" },
+            "Asset2": {
+                "currency": "0158415500000000C1F76FF6ECB0BAC600000000",
+                "issuer": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            },
+        });
+
+        let blob = encode(&tx).unwrap();
+
+        assert_eq!(decode(&blob).unwrap(), tx);
+    }
+
+    #[test]
+    fn test_deliver_max_is_written_as_amount() {
+        let amount = json!({ "TransactionType": "Payment", "Amount": "5" });
+        let both = json!({
+            "TransactionType": "Payment", "Amount": "5", "DeliverMax": "5",
+        });
+        let alias = json!({ "TransactionType": "Payment", "DeliverMax": "5" });
+        let conflict = json!({
+            "TransactionType": "Payment", "Amount": "5", "DeliverMax": "6",
+        });
+
+        assert_eq!(encode(&both), encode(&amount));
+        assert_eq!(encode(&alias), encode(&amount));
+        assert!(encode(&conflict).is_err());
+    }
+
+    #[test]
+    fn test_unknown_field_is_rejected() {
+        let err = encode(&json!({ "Bogus": 1 })).unwrap_err();
+        assert_eq!(err, CodecError::UnknownField("Bogus".into()));
+        assert_eq!(decode(&[0x12, 0x00]), Err(CodecError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn test_meta_from_blob() {
+        let meta = json!({
+            "AffectedNodes": [{ "ModifiedNode": {
+                "LedgerEntryType": "AccountRoot",
+                "LedgerIndex": "AB".repeat(32),
+                "FinalFields": {
+                    "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                    "Balance": "99999988",
+                    "Sequence": 2,
+                },
+                "PreviousFields": { "Balance": "100000000" },
+            }}],
+            "DeliveredAmount": "1000000",
+            "TransactionIndex": 3,
+            "TransactionResult": "tesSUCCESS",
+        });
+        let blob = encode_hex(meta);
+
+        let meta = TransactionMeta::from_blob(&blob).unwrap();
+
+        assert_eq!(meta.transaction_index, 3);
+        assert_eq!(meta.transaction_result, "tesSUCCESS");
+        assert!(matches!(
+            meta.affected_nodes[0].fields(),
+            Some(AccountObject::AccountRoot(root)) if root.sequence == 2
+        ));
+        assert!(meta.delivered_amount.is_some());
+    }
+}
@@ -0,0 +1,75 @@
+//! Cursor over a serialized blob.
+
+use super::CodecError;
+
+pub(super) struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    pub(super) fn peek(&self) -> Result<u8, CodecError> {
+        self.data.get(self.pos).copied().ok_or(CodecError::UnexpectedEnd)
+    }
+
+    pub(super) fn read(&mut self, len: usize) -> Result<&'a [u8], CodecError> {
+        let end = self.pos.checked_add(len).ok_or(CodecError::UnexpectedEnd)?;
+        let bytes =
+            self.data.get(self.pos..end).ok_or(CodecError::UnexpectedEnd)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    pub(super) fn read_array<const N: usize>(
+        &mut self,
+    ) -> Result<[u8; N], CodecError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.read(N)?);
+        Ok(out)
+    }
+
+    pub(super) fn read_u8(&mut self) -> Result<u8, CodecError> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub(super) fn read_u64(&mut self) -> Result<u64, CodecError> {
+        Ok(u64::from_be_bytes(self.read_array()?))
+    }
+
+    /// Reads a variable-length prefix.
+    pub(super) fn read_vl_length(&mut self) -> Result<usize, CodecError> {
+        let b1 = self.read_u8()? as usize;
+        match b1 {
+            0..=192 => Ok(b1),
+            193..=240 => {
+                let b2 = self.read_u8()? as usize;
+                Ok(193 + (b1 - 193) * 256 + b2)
+            }
+            241..=254 => {
+                let [b2, b3] = self.read_array::<2>()?.map(usize::from);
+                Ok(12481 + (b1 - 241) * 65536 + b2 * 256 + b3)
+            }
+            _ => Err(CodecError::InvalidLength(b1)),
+        }
+    }
+
+    /// Reads a field ID, returning `(type code, nth)`.
+    pub(super) fn read_field_id(&mut self) -> Result<(u8, u8), CodecError> {
+        let first = self.read_u8()?;
+        let (high, low) = (first >> 4, first & 0x0F);
+        match (high, low) {
+            (0, 0) => Ok((self.read_u8()?, self.read_u8()?)),
+            (0, nth) => Ok((self.read_u8()?, nth)),
+            (code, 0) => Ok((code, self.read_u8()?)),
+            (code, nth) => Ok((code, nth)),
+        }
+    }
+}
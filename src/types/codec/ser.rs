@@ -0,0 +1,286 @@
+//! JSON → canonical binary.
+
+use serde_json::{Map, Value};
+
+use super::CodecError;
+use super::account::encode_account_id;
+use super::amount::{encode_currency, str_of, write_amount, write_issue};
+use super::definitions::{
+    ARRAY_END, DECIMAL_UINT64, Field, FieldType, LEDGER_ENTRY_TYPES,
+    OBJECT_END, TRANSACTION_RESULTS, TRANSACTION_TYPES, code_of, field_by_name,
+};
+
+const PATH_SEPARATOR: u8 = 0xFF;
+const PATHSET_END: u8 = 0x00;
+const STEP_ACCOUNT: u8 = 0x01;
+const STEP_CURRENCY: u8 = 0x10;
+const STEP_ISSUER: u8 = 0x20;
+
+/// JSON keys that look like fields but have no binary representation.
+const NOT_SERIALIZED: [&str; 2] = ["Hash", "Date"];
+
+fn invalid(field: &Field, reason: impl Into<String>) -> CodecError {
+    CodecError::InvalidValue {
+        field: field.name.to_string(),
+        reason: reason.into(),
+    }
+}
+
+/// Writes the fields of `map` in canonical order. Lower-case keys are API
+/// annotations such as `hash` or `validated` and are skipped.
+pub(super) fn write_object(
+    map: &Map<String, Value>,
+    signing_only: bool,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    let mut fields = Vec::with_capacity(map.len());
+    for (key, value) in map {
+        let serialized = key.starts_with(|c: char| c.is_ascii_uppercase());
+        if !serialized || NOT_SERIALIZED.contains(&key.as_str()) {
+            continue;
+        }
+        let field = field_by_name(key)
+            .ok_or_else(|| CodecError::UnknownField(key.clone()))?;
+        if !signing_only || field.is_signing() {
+            fields.push((field, value));
+        }
+    }
+    fields.sort_by_key(|(field, _)| field.sort_key());
+    for (field, value) in fields {
+        write_field(field, value, signing_only, out)?;
+    }
+    Ok(())
+}
+
+fn write_field(
+    field: &Field,
+    value: &Value,
+    signing_only: bool,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    out.extend(field.header());
+    if field.is_vl() {
+        let bytes = vl_payload(field, value)?;
+        write_vl_length(field, bytes.len(), out)?;
+        out.extend(bytes);
+        return Ok(());
+    }
+    match field.kind {
+        FieldType::UInt8 => out.push(uint(field, value, u8::MAX.into())? as u8),
+        FieldType::UInt16 => out.extend(
+            (uint(field, value, u16::MAX.into())? as u16).to_be_bytes(),
+        ),
+        FieldType::UInt32 => out.extend(
+            (uint(field, value, u32::MAX.into())? as u32).to_be_bytes(),
+        ),
+        FieldType::UInt64 => out.extend(uint64(field, value)?.to_be_bytes()),
+        FieldType::Hash128 => out.extend(hash(field, value, 16)?),
+        FieldType::Hash160 => out.extend(hash(field, value, 20)?),
+        FieldType::Hash192 => out.extend(hash(field, value, 24)?),
+        FieldType::Hash256 => out.extend(hash(field, value, 32)?),
+        FieldType::Amount => write_amount(field.name, value, out)?,
+        FieldType::Currency => {
+            out.extend(encode_currency(field.name, as_str(field, value)?)?)
+        }
+        FieldType::Issue => write_issue(field.name, value, out)?,
+        FieldType::XChainBridge => write_bridge(field, value, out)?,
+        FieldType::PathSet => write_path_set(field, value, out)?,
+        FieldType::StObject => {
+            let map = value
+                .as_object()
+                .ok_or_else(|| invalid(field, "expected object"))?;
+            write_object(map, signing_only, out)?;
+            out.extend(OBJECT_END.header());
+        }
+        FieldType::StArray => write_array(field, value, signing_only, out)?,
+        FieldType::Blob | FieldType::AccountId | FieldType::Vector256 => {
+            unreachable!("variable-length types are handled above")
+        }
+    }
+    Ok(())
+}
+
+fn vl_payload(field: &Field, value: &Value) -> Result<Vec<u8>, CodecError> {
+    match field.kind {
+        FieldType::AccountId => {
+            Ok(encode_account_id(field.name, as_str(field, value)?)?.to_vec())
+        }
+        FieldType::Vector256 => {
+            let hashes = value
+                .as_array()
+                .ok_or_else(|| invalid(field, "expected array"))?;
+            let mut bytes = Vec::with_capacity(hashes.len() * 32);
+            for h in hashes {
+                bytes.extend(hash(field, h, 32)?);
+            }
+            Ok(bytes)
+        }
+        _ => hex::decode(as_str(field, value)?)
+            .map_err(|e| invalid(field, e.to_string())),
+    }
+}
+
+/// Writes the one- to three-byte length prefix of a variable-length value.
+pub(super) fn write_vl_length(
+    field: &Field,
+    len: usize,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    match len {
+        0..=192 => out.push(len as u8),
+        193..=12480 => {
+            let len = len - 193;
+            out.extend([193 + (len >> 8) as u8, len as u8]);
+        }
+        12481..=918744 => {
+            let len = len - 12481;
+            out.extend([241 + (len >> 16) as u8, (len >> 8) as u8, len as u8]);
+        }
+        _ => return Err(invalid(field, format!("{len} bytes is too long"))),
+    }
+    Ok(())
+}
+
+fn write_array(
+    field: &Field,
+    value: &Value,
+    signing_only: bool,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    let elements =
+        value.as_array().ok_or_else(|| invalid(field, "expected array"))?;
+    for element in elements {
+        let wrapper =
+            element.as_object().filter(|map| map.len() == 1).ok_or_else(
+                || invalid(field, "elements must be single-key objects"),
+            )?;
+        let (key, inner) =
+            wrapper.iter().next().unwrap_or_else(|| unreachable!());
+        let inner_field = field_by_name(key)
+            .ok_or_else(|| CodecError::UnknownField(key.clone()))?;
+        write_field(inner_field, inner, signing_only, out)?;
+    }
+    out.extend(ARRAY_END.header());
+    Ok(())
+}
+
+fn write_path_set(
+    field: &Field,
+    value: &Value,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    let paths =
+        value.as_array().ok_or_else(|| invalid(field, "expected array"))?;
+    for (i, path) in paths.iter().enumerate() {
+        if i > 0 {
+            out.push(PATH_SEPARATOR);
+        }
+        let steps = path
+            .as_array()
+            .ok_or_else(|| invalid(field, "expected path array"))?;
+        for step in steps {
+            let step = step
+                .as_object()
+                .ok_or_else(|| invalid(field, "expected path step"))?;
+            write_path_step(field, step, out)?;
+        }
+    }
+    out.push(PATHSET_END);
+    Ok(())
+}
+
+fn write_path_step(
+    field: &Field,
+    step: &Map<String, Value>,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    let get = |key| step.get(key).and_then(Value::as_str);
+    let type_pos = out.len();
+    out.push(0);
+    let mut kind = 0;
+    if let Some(account) = get("account") {
+        kind |= STEP_ACCOUNT;
+        out.extend(encode_account_id(field.name, account)?);
+    }
+    if let Some(currency) = get("currency") {
+        kind |= STEP_CURRENCY;
+        out.extend(encode_currency(field.name, currency)?);
+    }
+    if let Some(issuer) = get("issuer") {
+        kind |= STEP_ISSUER;
+        out.extend(encode_account_id(field.name, issuer)?);
+    }
+    if kind == 0 {
+        return Err(invalid(field, "empty path step"));
+    }
+    out[type_pos] = kind;
+    Ok(())
+}
+
+fn write_bridge(
+    field: &Field,
+    value: &Value,
+    out: &mut Vec<u8>,
+) -> Result<(), CodecError> {
+    let map =
+        value.as_object().ok_or_else(|| invalid(field, "expected object"))?;
+    for (door, issue) in [
+        ("LockingChainDoor", "LockingChainIssue"),
+        ("IssuingChainDoor", "IssuingChainIssue"),
+    ] {
+        let account =
+            encode_account_id(field.name, str_of(field.name, map, door)?)?;
+        write_vl_length(field, account.len(), out)?;
+        out.extend(account);
+        let issue = map
+            .get(issue)
+            .ok_or_else(|| invalid(field, format!("missing `{issue}`")))?;
+        write_issue(field.name, issue, out)?;
+    }
+    Ok(())
+}
+
+fn as_str<'a>(field: &Field, value: &'a Value) -> Result<&'a str, CodecError> {
+    value.as_str().ok_or_else(|| invalid(field, "expected string"))
+}
+
+fn hash(
+    field: &Field,
+    value: &Value,
+    len: usize,
+) -> Result<Vec<u8>, CodecError> {
+    super::hex_exact(field.name, as_str(field, value)?, len)
+}
+
+/// Unsigned integers are JSON numbers; a few fields use their symbolic name.
+fn uint(field: &Field, value: &Value, max: u64) -> Result<u64, CodecError> {
+    let named = value.as_str().and_then(|name| match field.name {
+        "TransactionType" => code_of(TRANSACTION_TYPES, name).map(u64::from),
+        "LedgerEntryType" => code_of(LEDGER_ENTRY_TYPES, name).map(u64::from),
+        "TransactionResult" => {
+            code_of(TRANSACTION_RESULTS, name).map(u64::from)
+        }
+        _ => name.parse().ok(),
+    });
+    named
+        .or_else(|| value.as_u64())
+        .filter(|v| *v <= max)
+        .ok_or_else(|| invalid(field, format!("bad unsigned integer {value}")))
+}
+
+/// `UInt64` values are hex strings in JSON, except token quantities.
+fn uint64(field: &Field, value: &Value) -> Result<u64, CodecError> {
+    let text = match value {
+        Value::Number(n) => {
+            return n.as_u64().ok_or_else(|| invalid(field, "expected u64"));
+        }
+        Value::String(text) => text,
+        _ => return Err(invalid(field, "expected string")),
+    };
+    let parsed = if DECIMAL_UINT64.contains(&field.name) {
+        text.parse()
+    } else {
+        u64::from_str_radix(text, 16)
+    };
+    parsed.map_err(|e| invalid(field, e.to_string()))
+}
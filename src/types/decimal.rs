@@ -0,0 +1,411 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Neg;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+const MIN_MANTISSA: u128 = 1_000_000_000_000_000;
+const MAX_MANTISSA: u128 = 9_999_999_999_999_999;
+const MIN_EXPONENT: i32 = -96;
+const MAX_EXPONENT: i32 = 80;
+
+/// Digits kept while parsing before the rest only affect rounding.
+const PARSE_LIMIT: u128 = 10u128.pow(35);
+/// Extra quotient digits computed by [`XrplDecimal::checked_div`].
+const DIVISION_SCALE: u32 = 22;
+/// Exponent gap beyond which the smaller addend cannot affect the sum.
+const ADDITION_GAP: i32 = 18;
+
+/// Errors returned by [`XrplDecimal`] and [`Amount`](super::Amount)
+/// arithmetic.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ArithmeticError {
+    /// The text is not a decimal number.
+    #[error("Invalid number: {0}")]
+    InvalidNumber(String),
+    /// The result exceeds the largest representable value.
+    #[error("Arithmetic overflow")]
+    Overflow,
+    /// The divisor is zero.
+    #[error("Division by zero")]
+    DivisionByZero,
+    /// The operands are amounts of different assets.
+    #[error("Amounts are of different assets")]
+    AssetMismatch,
+}
+
+/// A decimal number with the precision and range of an XRPL token amount.
+///
+/// Values are held as a 16-digit mantissa and an exponent in `-96..=80`,
+/// exactly like issued-currency amounts on the ledger. Every operation rounds
+/// its result to 16 significant digits (half to even); results smaller than
+/// the minimum magnitude become zero and larger ones are an
+/// [`Overflow`](ArithmeticError::Overflow).
+///
+/// [`Display`](fmt::Display) follows `rippled`: plain notation for moderate
+/// exponents and `<mantissa>e<exponent>` otherwise.
+///
+/// # Example
+/// ```rust
+/// use xrpl::types::XrplDecimal;
+///
+/// let a: XrplDecimal = "0.1".parse()?;
+/// let b: XrplDecimal = "0.2".parse()?;
+/// assert_eq!(a.checked_add(b)?.to_string(), "0.3");
+///
+/// let third = XrplDecimal::from(1).checked_div(XrplDecimal::from(3))?;
+/// assert_eq!(third.to_string(), "0.3333333333333333");
+/// assert!(third > a.checked_add(b)?);
+/// # Ok::<(), xrpl::types::ArithmeticError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct XrplDecimal {
+    mantissa: i64,
+    exponent: i32,
+}
+
+impl XrplDecimal {
+    /// Zero.
+    pub const ZERO: Self = Self { mantissa: 0, exponent: 0 };
+
+    /// `mantissa × 10^exponent`, normalized and rounded to 16 digits.
+    pub fn new(mantissa: i64, exponent: i32) -> Result<Self, ArithmeticError> {
+        normalize(mantissa < 0, mantissa.unsigned_abs().into(), exponent, false)
+    }
+
+    /// Normalized mantissa: zero, or 16 digits with the sign of the value.
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// Exponent of the normalized mantissa.
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Whether the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Whether the value is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Absolute value.
+    pub fn abs(self) -> Self {
+        Self { mantissa: self.mantissa.abs(), ..self }
+    }
+
+    /// `self + other`.
+    pub fn checked_add(self, other: Self) -> Result<Self, ArithmeticError> {
+        if other.is_zero() {
+            return Ok(self);
+        }
+        if self.is_zero() {
+            return Ok(other);
+        }
+        let (big, small) = if self.exponent >= other.exponent {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let gap = big.exponent - small.exponent;
+        if gap > ADDITION_GAP {
+            return Ok(big);
+        }
+        let sum = i128::from(big.mantissa) * 10i128.pow(gap.unsigned_abs())
+            + i128::from(small.mantissa);
+        normalize(sum < 0, sum.unsigned_abs(), small.exponent, false)
+    }
+
+    /// `self - other`.
+    pub fn checked_sub(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.checked_add(-other)
+    }
+
+    /// `self × other`.
+    pub fn checked_mul(self, other: Self) -> Result<Self, ArithmeticError> {
+        let product = i128::from(self.mantissa) * i128::from(other.mantissa);
+        normalize(
+            product < 0,
+            product.unsigned_abs(),
+            self.exponent + other.exponent,
+            false,
+        )
+    }
+
+    /// `self ÷ other`.
+    pub fn checked_div(self, other: Self) -> Result<Self, ArithmeticError> {
+        if other.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        let numerator = u128::from(self.mantissa.unsigned_abs())
+            * 10u128.pow(DIVISION_SCALE);
+        let divisor = u128::from(other.mantissa.unsigned_abs());
+        normalize(
+            self.is_negative() != other.is_negative(),
+            numerator / divisor,
+            self.exponent - other.exponent - DIVISION_SCALE as i32,
+            !numerator.is_multiple_of(divisor),
+        )
+    }
+}
+
+/// Rounds `magnitude × 10^exponent` to 16 digits, half to even. `sticky`
+/// marks a non-zero remainder below the last digit of `magnitude`.
+///
+/// The exponent saturates, so any `i32` input stays far enough out of range
+/// to come out as zero or [`Overflow`](ArithmeticError::Overflow).
+fn normalize(
+    negative: bool,
+    mut magnitude: u128,
+    mut exponent: i32,
+    mut sticky: bool,
+) -> Result<XrplDecimal, ArithmeticError> {
+    if magnitude == 0 {
+        return Ok(XrplDecimal::ZERO);
+    }
+    let mut round_digit = 0;
+    while magnitude > MAX_MANTISSA {
+        sticky |= round_digit != 0;
+        round_digit = magnitude % 10;
+        magnitude /= 10;
+        exponent = exponent.saturating_add(1);
+    }
+    if round_digit > 5 || (round_digit == 5 && (sticky || magnitude % 2 == 1)) {
+        magnitude += 1;
+        if magnitude > MAX_MANTISSA {
+            magnitude /= 10;
+            exponent = exponent.saturating_add(1);
+        }
+    }
+    while magnitude < MIN_MANTISSA {
+        magnitude *= 10;
+        exponent = exponent.saturating_sub(1);
+    }
+    if exponent > MAX_EXPONENT {
+        return Err(ArithmeticError::Overflow);
+    }
+    if exponent < MIN_EXPONENT {
+        return Ok(XrplDecimal::ZERO);
+    }
+    let mantissa = magnitude as i64;
+    Ok(XrplDecimal {
+        mantissa: if negative { -mantissa } else { mantissa },
+        exponent,
+    })
+}
+
+impl From<i64> for XrplDecimal {
+    fn from(value: i64) -> Self {
+        Self::new(value, 0).expect("an i64 is within the exponent range")
+    }
+}
+
+impl Neg for XrplDecimal {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { mantissa: -self.mantissa, ..self }
+    }
+}
+
+impl Ord for XrplDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = self.mantissa.signum().cmp(&other.mantissa.signum());
+        if sign != Ordering::Equal {
+            return sign;
+        }
+        let magnitude = (self.exponent, self.mantissa.unsigned_abs())
+            .cmp(&(other.exponent, other.mantissa.unsigned_abs()));
+        if self.is_negative() { magnitude.reverse() } else { magnitude }
+    }
+}
+
+impl PartialOrd for XrplDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Accepts plain and scientific notation (`"-1.5"`, `"25e-3"`). Digits beyond
+/// the 16th are rounded.
+impl FromStr for XrplDecimal {
+    type Err = ArithmeticError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || ArithmeticError::InvalidNumber(text.to_string());
+        let (negative, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((m, e)) => (m, e.parse::<i32>().map_err(|_| invalid())?),
+            None => (unsigned, 0),
+        };
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if int.is_empty() && frac.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut magnitude = 0u128;
+        let mut scale = 0i32;
+        let mut sticky = false;
+        for (i, byte) in int.bytes().chain(frac.bytes()).enumerate() {
+            if !byte.is_ascii_digit() {
+                return Err(invalid());
+            }
+            let fractional = i >= int.len();
+            if magnitude < PARSE_LIMIT {
+                magnitude = magnitude * 10 + u128::from(byte - b'0');
+                scale -= i32::from(fractional);
+            } else {
+                sticky |= byte != b'0';
+                scale += i32::from(!fractional);
+            }
+        }
+        normalize(negative, magnitude, exponent.saturating_add(scale), sticky)
+    }
+}
+
+impl fmt::Display for XrplDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+        let sign = if self.is_negative() { "-" } else { "" };
+        let mut mantissa = self.mantissa.unsigned_abs();
+        let mut exponent = self.exponent;
+
+        if exponent != 0 && !(-25..=-5).contains(&exponent) {
+            while mantissa.is_multiple_of(10) {
+                mantissa /= 10;
+                exponent += 1;
+            }
+            return write!(f, "{sign}{mantissa}e{exponent}");
+        }
+
+        let digits = mantissa.to_string();
+        let point = digits.len() as i32 + exponent;
+        let (int, frac) = if point <= 0 {
+            (
+                "0".to_string(),
+                "0".repeat(point.unsigned_abs() as usize) + &digits,
+            )
+        } else {
+            let (int, frac) = digits.split_at(point as usize);
+            (int.to_string(), frac.to_string())
+        };
+        let frac = frac.trim_end_matches('0');
+        if frac.is_empty() {
+            write!(f, "{sign}{int}")
+        } else {
+            write!(f, "{sign}{int}.{frac}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(text: &str) -> XrplDecimal {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_normalizes() {
+        let value = dec("1.5");
+        assert_eq!(value.mantissa(), 1_500_000_000_000_000);
+        assert_eq!(value.exponent(), -15);
+        assert_eq!(dec("0.00150e3"), value);
+        assert_eq!(dec("-0"), XrplDecimal::ZERO);
+        assert!("1.2.3".parse::<XrplDecimal>().is_err());
+        assert!("".parse::<XrplDecimal>().is_err());
+    }
+
+    #[test]
+    fn test_rounds_to_sixteen_digits() {
+        assert_eq!(dec("12345678901234567").to_string(), "1234567890123457e1");
+        assert_eq!(dec("12345678901234565").to_string(), "1234567890123456e1");
+        assert_eq!(
+            dec("1234567890123456500001").to_string(),
+            "1234567890123457e6"
+        );
+        assert_eq!(dec("99999999999999999").to_string(), "1e17");
+    }
+
+    #[test]
+    fn test_display_matches_rippled() {
+        assert_eq!(dec("100").to_string(), "100");
+        assert_eq!(dec("-0.000001").to_string(), "-0.000001");
+        assert_eq!(dec("1e-20").to_string(), "1e-20");
+        assert_eq!(dec("1e20").to_string(), "1e20");
+        assert_eq!(dec("1234567890123456").to_string(), "1234567890123456");
+    }
+
+    #[test]
+    fn test_exponent_range() {
+        assert_eq!(dec("1e-81"), dec("1e-80").checked_div(dec("10")).unwrap());
+        assert_eq!(dec("1e-82"), XrplDecimal::ZERO);
+        assert_eq!(
+            dec("1e-50").checked_mul(dec("1e-50")).unwrap(),
+            XrplDecimal::ZERO
+        );
+        assert_eq!(
+            dec("9999999999999999e80").checked_mul(dec("10")),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(
+            "1e200".parse::<XrplDecimal>(),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_exponent_extremes_do_not_overflow() {
+        assert_eq!(dec("1e-2147483648"), XrplDecimal::ZERO);
+        assert_eq!(dec("0.001e-2147483648"), XrplDecimal::ZERO);
+        assert_eq!(XrplDecimal::new(1, i32::MIN), Ok(XrplDecimal::ZERO));
+        for text in ["1e2147483647", "12345678901234567890e2147483647"] {
+            assert_eq!(
+                text.parse::<XrplDecimal>(),
+                Err(ArithmeticError::Overflow)
+            );
+        }
+        assert_eq!(
+            XrplDecimal::new(i64::MAX, i32::MAX),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(dec("0.1").checked_add(dec("0.2")).unwrap(), dec("0.3"));
+        assert_eq!(dec("1").checked_sub(dec("1e-20")).unwrap(), dec("1"));
+        assert_eq!(dec("5").checked_sub(dec("7.5")).unwrap(), dec("-2.5"));
+        assert_eq!(dec("1.5").checked_mul(dec("-4")).unwrap(), dec("-6"));
+        assert_eq!(
+            dec("2").checked_div(dec("3")).unwrap().to_string(),
+            "0.6666666666666667"
+        );
+        assert_eq!(
+            dec("1").checked_div(XrplDecimal::ZERO),
+            Err(ArithmeticError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut values =
+            [dec("10"), dec("-1"), dec("0"), dec("-20"), dec("0.5")];
+        values.sort();
+        assert_eq!(
+            values.map(|v| v.to_string()),
+            ["-20", "-1", "0", "0.5", "10"]
+        );
+    }
+}
@@ -3,9 +3,10 @@
 /// in the `Flags` field of `account_info`. Lives in its own module because
 /// per-transaction flag types (e.g. `PaymentFlags`) only have one representation.
 pub mod account_flag;
-/// Ledger-object types returned by `account_objects`.
+/// Ledger-object types returned by `account_objects`, `ledger_data` and
+/// `ledger_entry`.
 pub mod account_object;
-/// AMM pool types returned by `amm_info`.
+/// AMM pool ledger-object types.
 pub mod amm;
 /// `Amount` enum representing XRP drops, issued-currency amounts, or MPT amounts.
 #[macro_use]
@@ -14,6 +15,10 @@ pub mod amount;
 pub mod asset;
 /// Transaction builder types for all XRPL transaction types.
 pub mod builders;
+/// Canonical binary serialization, deserialization, and transaction hashing.
+pub mod codec;
+/// Exact decimal arithmetic with the precision of XRPL token amounts.
+pub mod decimal;
 /// Transaction metadata and delivered-amount types.
 pub mod transaction_meta;
 /// Transaction type definitions for all XRPL transaction kinds.
@@ -24,16 +29,21 @@ pub mod validation;
 pub mod xchain;
 
 pub use account_flag::{AccountFlag, AccountFlags};
-pub use transaction_meta::{HasTransactionMeta, TransactionMeta};
+pub use transaction_meta::{
+    AffectedNode, BalanceChange, CreatedNode, DeletedNode, HasTransactionMeta,
+    LedgerFields, ModifiedNode, TransactionMeta,
+};
 pub use account_object::{
-    AccountObject, Bridge, Check, Common, Credential, Did, Escrow, MPToken,
-    MPTokenIssuance, NFTokenOffer, NFTokenPage, Offer, Oracle, PayChannel,
-    RippleState, SignerEntry, SignerList, Ticket, XChainOwnedClaimID,
+    AccountObject, Bridge, Check, Common, Credential, Delegate, Did,
+    DirectoryNode, Escrow, LedgerHashes, MPToken, MPTokenIssuance, NFToken,
+    NFTokenOffer, NFTokenPage, Offer, Oracle, PayChannel, PermissionedDomain,
+    RippleState, SignerEntry, SignerList, Ticket, Vault, XChainOwnedClaimID,
     XChainOwnedCreateAccountClaimID,
 };
 pub use amm::*;
 pub use amount::Amount;
 pub use asset::Asset;
+pub use decimal::{ArithmeticError, XrplDecimal};
 pub use builders::*;
 pub use transactions::*;
 pub use validation::*;
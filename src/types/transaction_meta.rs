@@ -1,7 +1,9 @@
+use serde::de::{DeserializeOwned, Error as _};
 use serde::{Deserialize, Deserializer, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
 
-use super::Amount;
+use super::{AccountObject, Amount, Asset, XrplDecimal};
 
 /// Implemented by any type that carries [`TransactionMeta`].
 ///
@@ -48,7 +50,7 @@ pub trait HasTransactionMeta {
 #[serde(rename_all = "PascalCase")]
 pub struct TransactionMeta {
     /// Ledger objects created, modified, or deleted by this transaction.
-    pub affected_nodes: Vec<Value>,
+    pub affected_nodes: Vec<AffectedNode>,
     /// Position of this transaction within the ledger (zero-based).
     pub transaction_index: u32,
     /// Final transaction result code (e.g. `"tesSUCCESS"`).
@@ -66,6 +68,334 @@ pub struct TransactionMeta {
     pub delivered_amount: Option<Amount>,
 }
 
+impl TransactionMeta {
+    /// Per-account XRP and token balance deltas, computed exactly from the
+    /// `AccountRoot` and `RippleState` nodes.
+    ///
+    /// XRP deltas are in drops and include the transaction fee burned from the
+    /// sender. A trust-line change is reported once for each side, with the
+    /// counterparty as the token issuer. Entries appear in node order; zero
+    /// deltas are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use xrpl::types::{Asset, TransactionMeta, XrplDecimal};
+    ///
+    /// fn xrp_received(meta: &TransactionMeta, account: &str) -> Option<XrplDecimal> {
+    ///     meta.balance_changes()
+    ///         .into_iter()
+    ///         .find(|c| c.account == account && c.asset == Asset::xrp())
+    ///         .map(|c| c.value)
+    /// }
+    /// ```
+    pub fn balance_changes(&self) -> Vec<BalanceChange> {
+        self.affected_nodes
+            .iter()
+            .flat_map(AffectedNode::balance_changes)
+            .collect()
+    }
+}
+
+/// A ledger entry created, modified, or deleted by a transaction.
+///
+/// Serialized the same way as the `AffectedNodes` array in metadata: an
+/// object with a single `CreatedNode`, `ModifiedNode`, or `DeletedNode` key.
+///
+/// `NewFields` and `FinalFields` deserialize into the [`AccountObject`]
+/// variant named by the node's `LedgerEntryType`. Metadata omits fields that
+/// hold their default value, so an entry that doesn't parse without them
+/// comes back as [`AccountObject::Unknown`] with the raw fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use xrpl::types::{AccountObject, AffectedNode};
+///
+/// fn trust_line_balances(nodes: &[AffectedNode]) -> Vec<String> {
+///     nodes
+///         .iter()
+///         .filter_map(|node| match node.fields()? {
+///             AccountObject::RippleState(line) => {
+///                 Some(line.balance.value().to_string())
+///             }
+///             _ => None,
+///         })
+///         .collect()
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub enum AffectedNode {
+    /// A ledger entry added by the transaction.
+    CreatedNode(CreatedNode),
+    /// A ledger entry whose fields changed.
+    ModifiedNode(ModifiedNode),
+    /// A ledger entry removed by the transaction.
+    DeletedNode(DeletedNode),
+}
+
+/// A ledger entry added by a transaction.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreatedNode {
+    /// Type of the ledger entry (e.g. `"RippleState"`).
+    pub ledger_entry_type: String,
+    /// Ledger object index (hash) of the entry.
+    pub ledger_index: String,
+    /// Contents of the new entry. Fields with default values are omitted.
+    #[serde(default, deserialize_with = "deserialize_fields")]
+    pub new_fields: Option<AccountObject>,
+}
+
+/// A ledger entry whose fields were changed by a transaction.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModifiedNode {
+    /// Type of the ledger entry (e.g. `"AccountRoot"`).
+    pub ledger_entry_type: String,
+    /// Ledger object index (hash) of the entry.
+    pub ledger_index: String,
+    /// Contents of the entry after the transaction.
+    #[serde(default, deserialize_with = "deserialize_fields")]
+    pub final_fields: Option<AccountObject>,
+    /// Prior values of the fields that changed; unchanged fields are absent.
+    pub previous_fields: Option<LedgerFields>,
+    /// Hash of the transaction that previously modified the entry.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Option<String>,
+    /// Ledger sequence of the transaction that previously modified the entry.
+    pub previous_txn_lgr_seq: Option<u32>,
+}
+
+/// A ledger entry removed by a transaction.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeletedNode {
+    /// Type of the ledger entry (e.g. `"Offer"`).
+    pub ledger_entry_type: String,
+    /// Ledger object index (hash) of the entry.
+    pub ledger_index: String,
+    /// Contents of the entry just before it was removed.
+    #[serde(default, deserialize_with = "deserialize_fields")]
+    pub final_fields: Option<AccountObject>,
+    /// Prior values of fields that changed before removal.
+    pub previous_fields: Option<LedgerFields>,
+}
+
+/// The `PreviousFields` of an affected node: the prior values of the fields
+/// that changed.
+///
+/// Kept as the original JSON, since a partial entry rarely parses into one
+/// of the [`account_object`](super::account_object) types.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LedgerFields(pub Map<String, Value>);
+
+impl LedgerFields {
+    /// Returns the raw value of a field by its wire name (e.g. `"Balance"`).
+    pub fn get(&self, field: &str) -> Option<&Value> {
+        self.0.get(field)
+    }
+
+    /// Deserializes the fields into a type that only needs the ones that
+    /// changed.
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(Value::Object(self.0.clone()))
+    }
+
+    fn amount(&self, field: &str) -> Option<Amount> {
+        serde_json::from_value(self.get(field)?.clone()).ok()
+    }
+}
+
+impl<'de> Deserialize<'de> for AffectedNode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] =
+            &["CreatedNode", "ModifiedNode", "DeletedNode"];
+
+        let mut entries = Map::deserialize(deserializer)?.into_iter();
+        let (Some((kind, mut node)), None) = (entries.next(), entries.next())
+        else {
+            return Err(D::Error::custom("expected a single affected node"));
+        };
+        // The fields leave `LedgerEntryType` to the node; copy it in so they
+        // deserialize as the matching `AccountObject` variant.
+        if let Value::Object(node) = &mut node
+            && let Some(ledger_entry_type) =
+                node.get("LedgerEntryType").cloned()
+        {
+            for key in ["NewFields", "FinalFields"] {
+                if let Some(Value::Object(fields)) = node.get_mut(key) {
+                    fields.insert(
+                        "LedgerEntryType".to_string(),
+                        ledger_entry_type.clone(),
+                    );
+                }
+            }
+        }
+        match kind.as_str() {
+            "CreatedNode" => {
+                CreatedNode::deserialize(node).map(Self::CreatedNode)
+            }
+            "ModifiedNode" => {
+                ModifiedNode::deserialize(node).map(Self::ModifiedNode)
+            }
+            "DeletedNode" => {
+                DeletedNode::deserialize(node).map(Self::DeletedNode)
+            }
+            other => return Err(D::Error::unknown_variant(other, VARIANTS)),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+impl AffectedNode {
+    /// Type of the ledger entry (e.g. `"AccountRoot"`).
+    pub fn ledger_entry_type(&self) -> &str {
+        match self {
+            Self::CreatedNode(node) => &node.ledger_entry_type,
+            Self::ModifiedNode(node) => &node.ledger_entry_type,
+            Self::DeletedNode(node) => &node.ledger_entry_type,
+        }
+    }
+
+    /// Ledger object index (hash) of the entry.
+    pub fn ledger_index(&self) -> &str {
+        match self {
+            Self::CreatedNode(node) => &node.ledger_index,
+            Self::ModifiedNode(node) => &node.ledger_index,
+            Self::DeletedNode(node) => &node.ledger_index,
+        }
+    }
+
+    /// Contents of the entry after the transaction: `NewFields` for created
+    /// nodes and `FinalFields` otherwise.
+    pub fn fields(&self) -> Option<&AccountObject> {
+        match self {
+            Self::CreatedNode(node) => node.new_fields.as_ref(),
+            Self::ModifiedNode(node) => node.final_fields.as_ref(),
+            Self::DeletedNode(node) => node.final_fields.as_ref(),
+        }
+    }
+
+    /// `PreviousFields` of a modified or deleted node.
+    pub fn previous_fields(&self) -> Option<&LedgerFields> {
+        match self {
+            Self::CreatedNode(_) => None,
+            Self::ModifiedNode(node) => node.previous_fields.as_ref(),
+            Self::DeletedNode(node) => node.previous_fields.as_ref(),
+        }
+    }
+
+    fn balance_changes(&self) -> Vec<BalanceChange> {
+        let previous = match self {
+            Self::CreatedNode(_) => Some(Amount::default()),
+            _ => self.previous_fields().and_then(|f| f.amount("Balance")),
+        };
+        let delta = |balance: &Amount| {
+            let delta = balance_delta(balance, previous.as_ref()?)?;
+            (!delta.is_zero()).then_some(delta)
+        };
+
+        match self.fields() {
+            Some(AccountObject::AccountRoot(root)) => {
+                let Some(value) = Amount::drops(&root.balance)
+                    .ok()
+                    .and_then(|balance| delta(&balance))
+                else {
+                    return Vec::new();
+                };
+                vec![BalanceChange {
+                    account: root.account.clone(),
+                    asset: Asset::xrp(),
+                    value,
+                }]
+            }
+            Some(AccountObject::RippleState(line)) => {
+                let (Some(delta), Some(low), Some(high)) = (
+                    delta(&line.balance),
+                    line.low_limit.issuer(),
+                    line.high_limit.issuer(),
+                ) else {
+                    return Vec::new();
+                };
+                let token = |issuer: &str| Asset::Token {
+                    currency: line.balance.currency().to_string(),
+                    issuer: issuer.to_string(),
+                };
+                // The balance is held from the low account's point of view.
+                vec![
+                    BalanceChange {
+                        account: low.to_string(),
+                        asset: token(high),
+                        value: delta,
+                    },
+                    BalanceChange {
+                        account: high.to_string(),
+                        asset: token(low),
+                        value: -delta,
+                    },
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A signed balance delta for one account and asset, returned by
+/// [`TransactionMeta::balance_changes`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceChange {
+    /// Account whose balance changed.
+    pub account: String,
+    /// XRP, or a token whose issuer is the trust-line counterparty.
+    pub asset: Asset,
+    /// Signed delta: drops for XRP, token units otherwise. XRP deltas are
+    /// exact below 10^16 drops; token deltas have the ledger's 16-digit
+    /// precision.
+    pub value: XrplDecimal,
+}
+
+/// `balance - previous`: exact integer drops for XRP, rounded to the
+/// ledger's 16 significant digits for tokens.
+fn balance_delta(balance: &Amount, previous: &Amount) -> Option<XrplDecimal> {
+    if let (Some(now), Some(before)) = (balance.to_drops(), previous.to_drops())
+    {
+        let delta = i128::from(now) - i128::from(before);
+        return i64::try_from(delta).ok().map(XrplDecimal::from);
+    }
+    let previous = previous.to_xrpl_decimal().ok()?;
+    balance.to_xrpl_decimal().ok()?.checked_sub(previous).ok()
+}
+
+/// Deserializes `NewFields` or `FinalFields` as an [`AccountObject`], keeping
+/// the raw fields as [`AccountObject::Unknown`] when they don't parse.
+fn deserialize_fields<'de, D>(d: D) -> Result<Option<AccountObject>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(value) = Option::<Value>::deserialize(d)? else {
+        return Ok(None);
+    };
+    let object = AccountObject::deserialize(&value).unwrap_or_else(|_| {
+        AccountObject::Unknown {
+            ledger_entry_type: value
+                .get("LedgerEntryType")
+                .and_then(Value::as_str)
+                .unwrap_or("")
+                .to_string(),
+            value,
+        }
+    });
+    Ok(Some(object))
+}
+
 fn deserialize_delivered_amount<'de, D>(
     d: D,
 ) -> Result<Option<Amount>, D::Error>
@@ -81,3 +411,165 @@ where
             .map_err(serde::de::Error::custom),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENDER: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+    const ISSUER: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+
+    fn meta() -> TransactionMeta {
+        serde_json::from_value(serde_json::json!({
+            "AffectedNodes": [
+                { "ModifiedNode": {
+                    "LedgerEntryType": "AccountRoot",
+                    "LedgerIndex": "AB".repeat(32),
+                    "FinalFields": {
+                        "Account": SENDER,
+                        "Balance": "99999988",
+                        "Flags": 0,
+                        "OwnerCount": 1,
+                        "Sequence": 2,
+                    },
+                    "PreviousFields": { "Balance": "100000000", "Sequence": 1 },
+                }},
+                { "ModifiedNode": {
+                    "LedgerEntryType": "RippleState",
+                    "LedgerIndex": "CD".repeat(32),
+                    "FinalFields": {
+                        "Balance": {
+                            "currency": "USD",
+                            "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                            "value": "-0.3",
+                        },
+                        "Flags": 131072,
+                        "HighLimit": { "currency": "USD", "issuer": SENDER, "value": "0" },
+                        "HighNode": "0",
+                        "LowLimit": { "currency": "USD", "issuer": ISSUER, "value": "0" },
+                        "LowNode": "0",
+                    },
+                    "PreviousFields": {
+                        "Balance": {
+                            "currency": "USD",
+                            "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                            "value": "-0.1",
+                        },
+                    },
+                }},
+                { "CreatedNode": {
+                    "LedgerEntryType": "Ticket",
+                    "LedgerIndex": "EF".repeat(32),
+                    "NewFields": { "Account": SENDER, "TicketSequence": 7 },
+                }},
+            ],
+            "TransactionIndex": 0,
+            "TransactionResult": "tesSUCCESS",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_typed_affected_nodes() {
+        let meta = meta();
+
+        assert_eq!(meta.affected_nodes[0].ledger_entry_type(), "AccountRoot");
+        assert!(matches!(
+            meta.affected_nodes[0].fields(),
+            Some(AccountObject::AccountRoot(root)) if root.sequence == 2
+        ));
+        let previous = meta.affected_nodes[0].previous_fields().unwrap();
+        assert_eq!(previous.get("Sequence"), Some(&1.into()));
+        assert_eq!(meta.affected_nodes[1].ledger_index(), "CD".repeat(32));
+        let Some(AccountObject::RippleState(line)) =
+            meta.affected_nodes[1].fields()
+        else {
+            panic!("expected a RippleState");
+        };
+        assert_eq!(line.balance.value(), "-0.3");
+        assert!(matches!(
+            meta.affected_nodes[2].fields(),
+            Some(AccountObject::Ticket(ticket)) if ticket.ticket_sequence == 7
+        ));
+
+        let json = serde_json::to_value(&meta).unwrap();
+        let node = &json["AffectedNodes"][0]["ModifiedNode"];
+        assert_eq!(node["FinalFields"]["Sequence"], 2);
+        assert_eq!(node["PreviousFields"]["Sequence"], 1);
+    }
+
+    #[test]
+    fn test_unparsable_fields_are_unknown() {
+        let node: AffectedNode = serde_json::from_value(serde_json::json!({
+            "ModifiedNode": {
+                "LedgerEntryType": "AccountRoot",
+                "LedgerIndex": "AB".repeat(32),
+                "FinalFields": { "Balance": "1" },
+            }
+        }))
+        .unwrap();
+
+        let Some(AccountObject::Unknown { ledger_entry_type, value }) =
+            node.fields()
+        else {
+            panic!("expected the raw fields");
+        };
+        assert_eq!(ledger_entry_type, "AccountRoot");
+        assert_eq!(value["Balance"], "1");
+    }
+
+    #[test]
+    fn test_balance_changes() {
+        let changes = meta().balance_changes();
+
+        let usd = |issuer: &str| Asset::Token {
+            currency: "USD".to_string(),
+            issuer: issuer.to_string(),
+        };
+        assert_eq!(
+            changes,
+            vec![
+                BalanceChange {
+                    account: SENDER.to_string(),
+                    asset: Asset::xrp(),
+                    value: XrplDecimal::from(-12),
+                },
+                BalanceChange {
+                    account: ISSUER.to_string(),
+                    asset: usd(SENDER),
+                    value: "-0.2".parse().unwrap(),
+                },
+                BalanceChange {
+                    account: SENDER.to_string(),
+                    asset: usd(ISSUER),
+                    value: "0.2".parse().unwrap(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_balance_delta() {
+        let xrp = |drops: &str| Amount::drops(drops).unwrap();
+        let usd = |value: &str| -> Amount {
+            serde_json::from_value(serde_json::json!({
+                "currency": "USD", "issuer": ISSUER, "value": value,
+            }))
+            .unwrap()
+        };
+        let dec = |text: &str| text.parse::<XrplDecimal>().unwrap();
+
+        // 17-digit balances keep every drop of the fee.
+        assert_eq!(
+            balance_delta(&xrp("12345678901234567"), &xrp("12345678901234579")),
+            Some(XrplDecimal::from(-12))
+        );
+        assert_eq!(balance_delta(&usd("0.3"), &usd("0.1")), Some(dec("0.2")));
+        assert_eq!(
+            balance_delta(&usd("-5"), &Amount::default()),
+            Some(dec("-5"))
+        );
+        assert_eq!(balance_delta(&usd("1"), &usd("1e-20")), Some(dec("1")));
+        assert_eq!(balance_delta(&usd("abc"), &usd("1")), None);
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::address::AccountId;
 use crate::types::SignerEntry;
 
 /// Account management transaction types (AccountSet, AccountDelete, etc.).
@@ -272,6 +273,21 @@ macro_rules! impl_tx_accessors {
                 }
             )+
         }
+
+        $(
+            impl TryFrom<TransactionType> for $ty {
+                type Error = TransactionType;
+
+                /// Unwraps the type-specific fields, or hands the value back
+                /// if it is another transaction type.
+                fn try_from(value: TransactionType) -> Result<Self, Self::Error> {
+                    match value {
+                        TransactionType::$variant(fields) => Ok(fields),
+                        other => Err(other),
+                    }
+                }
+            }
+        )+
     };
 }
 
@@ -492,7 +508,11 @@ impl<'de> Deserialize<'de> for Transaction {
 
         // Common fields
         let account = extract!("Account", String);
-        let fee = extract!("Fee", String);
+        // `noripple_check` suggests transactions with a numeric `Fee`.
+        let fee = match map.get("Fee") {
+            Some(serde_json::Value::Number(drops)) => drops.to_string(),
+            _ => extract!("Fee", String),
+        };
         let sequence = extract!("Sequence", u32);
         let account_txn_id = extract_opt!("AccountTxnID", String);
         let flags = extract_opt!("Flags", u32);
@@ -792,11 +812,12 @@ impl From<SignerEntry> for SignerEntryWrapper {
 /// Trait for transaction signing.
 ///
 /// Implement this on your wallet type to bridge XRPL transaction signing to a
-/// signing crate of your choice (e.g. `ripple-keypairs`, `xrpl-mithril`).
+/// signing crate of your choice (e.g. `ripple-keypairs`). The bytes to sign
+/// come from [`codec::signing_data`](crate::types::codec::signing_data).
 ///
 /// See the [crate-level documentation](crate) for a complete example, covering
-/// binary serialization, the `HASH_PREFIX_TRANSACTION_SIGN` prefix, and
-/// attaching the resulting signature.
+/// binary serialization, the `STX` signing prefix, and attaching the resulting
+/// signature.
 pub trait SigningContext {
     /// Error type returned when signing fails.
     type Error;
@@ -812,8 +833,7 @@ pub trait SigningContext {
 /// ```rust,no_run
 /// use anyhow::Context;
 /// use ripple_keypairs::{PrivateKey, PublicKey};
-/// use xrpl_mithril::codec::signing::multi_signing_data;
-/// use xrpl::types::{Transaction, MultiSigningContext, SignerWrapper, Signer};
+/// use xrpl::types::{Transaction, MultiSigningContext, SignerWrapper, Signer, codec};
 ///
 /// struct Wallet {
 ///     pub public_key: PublicKey,
@@ -828,15 +848,9 @@ pub trait SigningContext {
 ///             .context("failed to convert transaction to JSON")?;
 ///         tx_json["SigningPubKey"] = "".into();
 ///
-///         let map = tx_json.as_object()
-///             .context("transaction serialized to a non-object JSON value")?;
-///
-///         // derive the 20-byte account ID from the base58 address
 ///         let address = self.public_key.derive_address();
-///         let account_id: xrpl_mithril::types::AccountId =
-///             address.parse().context("failed to parse account address")?;
-///
-///         let signing_bytes = multi_signing_data(map, account_id.as_bytes())?;
+///         let signing_bytes = codec::multisigning_data(&tx_json, &address)
+///             .context("failed to serialize transaction")?;
 ///         let signature = self.private_key.sign(&signing_bytes);
 ///
 ///         Ok(SignerWrapper {
@@ -924,7 +938,8 @@ impl Transaction {
         })
     }
 
-    /// Appends a single signature and keeps the signer list sorted by account address.
+    /// Appends a single signature and keeps the signer list sorted by
+    /// AccountID, as the protocol requires.
     ///
     /// Accepts a [`Signer`] (or any type implementing `Into<Signer>`) and wraps
     /// it before insertion.
@@ -936,13 +951,14 @@ impl Transaction {
     pub fn add_signature(&mut self, signer: impl Into<Signer>) {
         let wrapper = SignerWrapper { signer: signer.into() };
         let signers = self.signers.get_or_insert_with(Vec::new);
-        let pos = signers
-            .partition_point(|s| s.signer.account < wrapper.signer.account);
+        let key = signer_key(&wrapper.signer);
+        let pos = signers.partition_point(|s| signer_key(&s.signer) < key);
         signers.insert(pos, wrapper);
         self.signing_pub_key = Some("".to_string());
     }
 
-    /// Attaches all signatures at once and keeps the signer list sorted by account address.
+    /// Attaches all signatures at once and keeps the signer list sorted by
+    /// AccountID, as the protocol requires.
     ///
     /// Accepts any iterable of items convertible into [`Signer`].
     pub fn add_signatures<I, S>(&mut self, signers: I)
@@ -954,8 +970,15 @@ impl Transaction {
             .into_iter()
             .map(|s| SignerWrapper { signer: s.into() })
             .collect();
-        wrapped.sort_by(|a, b| a.signer.account.cmp(&b.signer.account));
+        wrapped.sort_by_key(|s| signer_key(&s.signer));
         self.signers = Some(wrapped);
         self.signing_pub_key = Some("".to_string());
     }
 }
+
+/// Sort key for `Signers`: the numeric AccountID, which differs from the
+/// order of the base58 addresses. Unparseable addresses sort first and are
+/// left for the server to reject.
+fn signer_key(signer: &Signer) -> Option<AccountId> {
+    signer.account.parse().ok()
+}
@@ -1,5 +1,6 @@
 use thiserror::Error;
 use super::Amount;
+use crate::address::{AccountId, XAddress};
 
 /// Errors returned by the input-validation helpers in this module.
 ///
@@ -38,10 +39,11 @@ pub enum ValidationError {
     InvalidMessageKey(String),
 }
 
-/// Checks that `address` is a syntactically valid XRPL classic or X-address.
+/// Checks that `address` is a valid XRPL classic or X-address.
 ///
-/// Classic addresses start with `r` and are 25-35 alphanumeric characters.
-/// X-addresses start with `X` (mainnet) or `T` (testnet) and are exactly 47 characters.
+/// Decodes the address with the Ripple base58 alphabet and verifies its
+/// checksum, so typos are caught before a transaction is signed. See
+/// [`crate::address`] for conversions.
 pub fn validate_address(address: &str) -> Result<(), ValidationError> {
     if address.is_empty() {
         return Err(ValidationError::InvalidAddress(
@@ -49,37 +51,17 @@ pub fn validate_address(address: &str) -> Result<(), ValidationError> {
         ));
     }
 
-    let first_char = address.chars().next();
-    match first_char {
-        Some('r') => {
-            if !(25..=35).contains(&address.len()) {
-                return Err(ValidationError::InvalidAddress(
-                    "Classic address must be between 25 and 35 characters"
-                        .into(),
-                ));
-            }
-        }
-        Some('X') | Some('T') => {
-            if address.len() != 47 {
-                return Err(ValidationError::InvalidAddress(
-                    "X-address must be exactly 47 characters".into(),
-                ));
-            }
-        }
+    let result = match address.chars().next() {
+        Some('r') => address.parse::<AccountId>().map(drop),
+        Some('X') | Some('T') => address.parse::<XAddress>().map(drop),
         _ => {
             return Err(ValidationError::InvalidAddress(
                 "Address must start with 'r', 'X', or 'T'".into(),
             ));
         }
-    }
-
-    if !address.chars().all(|c| c.is_ascii_alphanumeric()) {
-        return Err(ValidationError::InvalidAddress(
-            "Address contains invalid characters".into(),
-        ));
-    }
-
-    Ok(())
+    };
+    result
+        .map_err(|e| ValidationError::InvalidAddress(format!("{address}: {e}")))
 }
 
 /// Validates currency codes
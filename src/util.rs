@@ -2,10 +2,11 @@
 
 use crate::{
     request::{
-        server_state::ServerStateRequest,
         account_info::{AccountInfoRequest, AccountInfoResponse},
+        deposit_authorized::DepositAuthorizedRequest,
+        server_state::ServerStateRequest,
     },
-    types::AccountFlags,
+    types::{AccountFlag, AccountFlags, builders::split_x_address},
     Client, XrplError,
 };
 
@@ -43,8 +44,8 @@ async fn fetch_account_info(
         ledger_index: Some(serde_json::json!("validated")),
         ..Default::default()
     };
-    match client.request(&req).await {
-        Ok(resp) => Ok(Some(resp.result()?)),
+    match client.request(&req).await.and_then(|resp| resp.result()) {
+        Ok(info) => Ok(Some(info)),
         Err(XrplError::ApiError { ref error, .. })
             if error == "actNotFound" =>
         {
@@ -329,3 +330,108 @@ pub async fn account_flags(
         .account_data
         .flags)
 }
+
+/// Whether a payment from one account can reach another, from
+/// [`can_deliver_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// Nothing on the destination blocks the payment.
+    Allowed,
+    /// The destination is not funded; only an XRP payment that meets the
+    /// base reserve can create it (`tecNO_DST`).
+    Unfunded,
+    /// The destination has `RequireDest` set and no tag was given
+    /// (`tecDST_TAG_NEEDED`).
+    DestinationTagRequired,
+    /// The destination has `DepositAuth` set and has not preauthorized the
+    /// source or the credentials (`tecNO_PERMISSION`).
+    NotAuthorized,
+    /// A credential does not exist, is not accepted, has expired or is not
+    /// held by the source (`tecBAD_CREDENTIALS`).
+    BadCredentials,
+    /// The destination is an X-address for another network: a test-network
+    /// address on mainnet, or the reverse.
+    WrongNetwork,
+}
+
+impl Delivery {
+    /// Whether the payment can be delivered.
+    pub fn is_allowed(self) -> bool {
+        self == Self::Allowed
+    }
+}
+
+/// Whether a server's `network_id` is a test network: anything but
+/// mainnet's `0`, matching an X-address's test-network flag.
+pub(crate) fn is_test_network(network_id: u64) -> bool {
+    network_id != 0
+}
+
+/// Checks the destination's `RequireDest` and `DepositAuth` settings, and
+/// the credentials the payment would carry, against the validated ledger.
+///
+/// `destination` may be an X-address; its tag satisfies `RequireDest`, and
+/// its test-network flag is checked against the server's `network_id`.
+/// `credentials` are `Credential` entry IDs, as in the payment's
+/// `CredentialIDs`.
+///
+/// # Example
+/// ```no_run
+/// use xrpl::{Client, util::{can_deliver_to, Delivery}};
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let client = Client::new("wss://xrplcluster.com");
+/// match can_deliver_to(&client, "rSource...", "XVDestination...", &[]).await? {
+///     Delivery::Allowed => println!("safe to send"),
+///     blocked => println!("payment would fail: {blocked:?}"),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn can_deliver_to(
+    client: &Client,
+    source: &str,
+    destination: &str,
+    credentials: &[&str],
+) -> Result<Delivery, XrplError> {
+    let (destination, tag, network) = split_x_address(destination);
+    if let Some(test_network) = network {
+        let req = ServerStateRequest::new();
+        let state = client.request(&req).await?.result()?.state;
+        if state
+            .network_id
+            .map(is_test_network)
+            .is_some_and(|test| test != test_network)
+        {
+            return Ok(Delivery::WrongNetwork);
+        }
+    }
+    let Some(info) = fetch_account_info(client, &destination).await? else {
+        return Ok(Delivery::Unfunded);
+    };
+    let flags = info.account_data.flags;
+    if flags.has(AccountFlag::RequireDest) && tag.is_none() {
+        return Ok(Delivery::DestinationTagRequired);
+    }
+    // Without DepositAuth only the credentials themselves can fail.
+    if !flags.has(AccountFlag::DepositAuth) && credentials.is_empty() {
+        return Ok(Delivery::Allowed);
+    }
+
+    let mut req = DepositAuthorizedRequest::new(source, &destination)
+        .with_ledger_index("validated");
+    for credential in credentials {
+        req = req.with_credential(credential);
+    }
+    match client.request(&req).await.and_then(|resp| resp.result()) {
+        Ok(resp) if resp.deposit_authorized => Ok(Delivery::Allowed),
+        Ok(_) => Ok(Delivery::NotAuthorized),
+        Err(XrplError::ApiError { ref error, .. })
+            if error == "badCredentials" =>
+        {
+            Ok(Delivery::BadCredentials)
+        }
+        Err(e) => Err(e),
+    }
+}
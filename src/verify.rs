@@ -0,0 +1,409 @@
+use k256::ecdsa::signature::hazmat::PrehashVerifier;
+use thiserror::Error;
+
+use crate::address::AccountId;
+use crate::request::account_info::AccountInfoRequest;
+use crate::types::{AccountFlag, Transaction};
+use crate::types::codec::{self, CodecError, sha512_half};
+use crate::{Client, XrplError};
+
+const ED25519_KEY_PREFIX: u8 = 0xED;
+
+/// Why a signature did not verify.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    /// The transaction carries neither `TxnSignature` nor `Signers`.
+    #[error("Transaction is not signed")]
+    Unsigned,
+    /// `SigningPubKey` is not a valid secp256k1 or `ED`-prefixed Ed25519 key.
+    #[error("Malformed public key")]
+    MalformedPublicKey,
+    /// The signature is not valid hex, DER (secp256k1) or 64 bytes (Ed25519).
+    #[error("Malformed signature")]
+    MalformedSignature,
+    /// The signature does not match the key and signing data. High-S
+    /// secp256k1 signatures are rejected, as rippled does.
+    #[error("Signature does not match")]
+    BadSignature,
+    /// The key derives to neither the account nor its regular key, or is a
+    /// master key the account has disabled.
+    #[error("Key is not authorized to sign for {0}")]
+    KeyNotAuthorized(String),
+    /// A multi-signed transaction lists the same signer twice.
+    #[error("{0} signed more than once")]
+    DuplicateSigner(String),
+    /// The signing data could not be serialized.
+    #[error(transparent)]
+    Codec(#[from] CodecError),
+}
+
+/// Which of the account's keys produced a valid signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyUsed {
+    /// The key pair the account address was derived from.
+    Master,
+    /// The account's regular key pair.
+    Regular,
+}
+
+/// Result of checking one signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureCheck {
+    /// Account the signature is for: the transaction's `Account`, or the
+    /// `Signer`'s account.
+    pub account: String,
+    /// Hex public key the signature claims.
+    pub signing_pub_key: String,
+    /// The key that signed, or why the signature is not valid.
+    pub outcome: Result<KeyUsed, VerifyError>,
+}
+
+impl SignatureCheck {
+    /// Whether the signature is valid and the key may sign for the account.
+    pub fn is_valid(&self) -> bool {
+        self.outcome.is_ok()
+    }
+}
+
+/// Result of [`verify`]: one [`SignatureCheck`] for a single-signed
+/// transaction, or one per `Signer` for a multi-signed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Checks in the order the signatures appear in the transaction.
+    pub checks: Vec<SignatureCheck>,
+}
+
+impl VerificationReport {
+    /// Whether there is at least one signature and every one is valid.
+    ///
+    /// For a multi-signed transaction this does not check the quorum; see
+    /// [`MultisigSession`](crate::multisig::MultisigSession).
+    pub fn is_valid(&self) -> bool {
+        !self.checks.is_empty() && self.checks.iter().all(|c| c.is_valid())
+    }
+
+    /// The checks that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &SignatureCheck> {
+        self.checks.iter().filter(|c| !c.is_valid())
+    }
+}
+
+/// Verifies every signature on `tx`.
+///
+/// A single-signed transaction is checked against `TxnSignature` and the
+/// `STX` signing data; a multi-signed one checks each `Signer` against the
+/// `SMT` multi-signing data for that signer. Each key must derive to the
+/// signing account, or to the address `regular_key(account)` returns.
+/// Signer-list membership and quorum are not checked here.
+///
+/// Requires the `wallet` cargo feature.
+///
+/// # Example
+/// ```rust
+/// use xrpl::types::{Signable, Transaction};
+/// use xrpl::verify::{KeyUsed, verify};
+/// use xrpl::wallet::Wallet;
+///
+/// let wallet = Wallet::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r")?;
+/// let tx: Transaction = serde_json::from_value(serde_json::json!({
+///     "TransactionType": "Payment",
+///     "Account": wallet.address(),
+///     "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+///     "Amount": "1000000",
+///     "Fee": "12",
+///     "Sequence": 1,
+/// }))?;
+/// let signed = Transaction::from_blob(tx.sign_with(&wallet)?)?;
+///
+/// let report = verify(&signed, |_| None);
+/// assert!(report.is_valid());
+/// assert_eq!(report.checks[0].outcome, Ok(KeyUsed::Master));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn verify(
+    tx: &Transaction,
+    regular_key: impl Fn(&str) -> Option<String>,
+) -> VerificationReport {
+    let check = |account: &str, key: &str, outcome| SignatureCheck {
+        account: account.to_string(),
+        signing_pub_key: key.to_string(),
+        outcome,
+    };
+
+    let mut json = match serde_json::to_value(tx) {
+        Ok(json) => json,
+        Err(e) => {
+            let err = VerifyError::Codec(CodecError::Json(e.to_string()));
+            let key = tx.signing_pub_key.as_deref().unwrap_or_default();
+            return VerificationReport {
+                checks: vec![check(&tx.account, key, Err(err))],
+            };
+        }
+    };
+
+    let checks = match (&tx.signers, &tx.txn_signature) {
+        (Some(signers), _) if !signers.is_empty() => {
+            json["SigningPubKey"] = "".into();
+            let mut seen = Vec::new();
+            signers
+                .iter()
+                .map(|wrapper| {
+                    let signer = &wrapper.signer;
+                    let outcome = if seen.contains(&&signer.account) {
+                        Err(VerifyError::DuplicateSigner(
+                            signer.account.clone(),
+                        ))
+                    } else {
+                        seen.push(&signer.account);
+                        codec::multisigning_data(&json, &signer.account)
+                            .map_err(VerifyError::from)
+                            .and_then(|data| {
+                                verify_signature(
+                                    &signer.signing_pub_key,
+                                    &data,
+                                    &signer.txn_signature,
+                                )
+                            })
+                            .and_then(|()| {
+                                key_used(
+                                    &signer.account,
+                                    &signer.signing_pub_key,
+                                    &regular_key,
+                                )
+                            })
+                    };
+                    check(&signer.account, &signer.signing_pub_key, outcome)
+                })
+                .collect()
+        }
+        (_, Some(signature)) => {
+            let key = tx.signing_pub_key.as_deref().unwrap_or_default();
+            let outcome = codec::signing_data(&json)
+                .map_err(VerifyError::from)
+                .and_then(|data| verify_signature(key, &data, signature))
+                .and_then(|()| key_used(&tx.account, key, &regular_key));
+            vec![check(&tx.account, key, outcome)]
+        }
+        _ => {
+            let key = tx.signing_pub_key.as_deref().unwrap_or_default();
+            vec![check(&tx.account, key, Err(VerifyError::Unsigned))]
+        }
+    };
+    VerificationReport { checks }
+}
+
+/// Like [`verify`], reading each signing account's regular key from the
+/// validated ledger. Accounts that do not exist have no regular key.
+///
+/// Master-key signatures for accounts with `lsfDisableMaster` set are
+/// reported as [`VerifyError::KeyNotAuthorized`].
+pub async fn verify_on_ledger(
+    client: &Client,
+    tx: &Transaction,
+) -> Result<VerificationReport, XrplError> {
+    let accounts: Vec<&str> = match &tx.signers {
+        Some(signers) if !signers.is_empty() => {
+            signers.iter().map(|s| s.signer.account.as_str()).collect()
+        }
+        _ => vec![tx.account.as_str()],
+    };
+
+    let mut keys = Vec::with_capacity(accounts.len());
+    for account in accounts {
+        let req =
+            AccountInfoRequest::new(account).with_ledger_index("validated");
+        let (regular_key, master_disabled) =
+            match client.request(&req).await?.result() {
+                Ok(info) => (
+                    info.account_data.regular_key,
+                    info.account_data.flags.has(AccountFlag::DisableMaster),
+                ),
+                Err(XrplError::ApiError { ref error, .. })
+                    if error == "actNotFound" =>
+                {
+                    (None, false)
+                }
+                Err(e) => return Err(e),
+            };
+        keys.push((account.to_string(), regular_key, master_disabled));
+    }
+    let find = |account: &str| keys.iter().find(|(a, ..)| a == account);
+
+    let report = verify(tx, |account| find(account)?.1.clone());
+    Ok(reject_disabled_master(report, |account| {
+        find(account).is_some_and(|(.., disabled)| *disabled)
+    }))
+}
+
+/// Turns master-key signatures of accounts whose master key is disabled
+/// into [`VerifyError::KeyNotAuthorized`].
+fn reject_disabled_master(
+    mut report: VerificationReport,
+    master_disabled: impl Fn(&str) -> bool,
+) -> VerificationReport {
+    for check in &mut report.checks {
+        if check.outcome == Ok(KeyUsed::Master)
+            && master_disabled(&check.account)
+        {
+            check.outcome =
+                Err(VerifyError::KeyNotAuthorized(check.account.clone()));
+        }
+    }
+    report
+}
+
+/// Checks a hex `signature` of `message` by the hex `public_key`.
+///
+/// secp256k1 keys verify a canonical DER signature of the SHA-512Half of
+/// `message`; `ED`-prefixed Ed25519 keys verify `message` itself. This is
+/// the counterpart of [`Wallet::sign`](crate::wallet::Wallet::sign).
+pub fn verify_signature(
+    public_key: &str,
+    message: &[u8],
+    signature: &str,
+) -> Result<(), VerifyError> {
+    let key =
+        hex::decode(public_key).map_err(|_| VerifyError::MalformedPublicKey)?;
+    let signature =
+        hex::decode(signature).map_err(|_| VerifyError::MalformedSignature)?;
+
+    match key.split_first() {
+        Some((&ED25519_KEY_PREFIX, key)) => {
+            let key: &[u8; 32] =
+                key.try_into().map_err(|_| VerifyError::MalformedPublicKey)?;
+            let key = ed25519_dalek::VerifyingKey::from_bytes(key)
+                .map_err(|_| VerifyError::MalformedPublicKey)?;
+            let signature = ed25519_dalek::Signature::from_slice(&signature)
+                .map_err(|_| VerifyError::MalformedSignature)?;
+            key.verify_strict(message, &signature)
+                .map_err(|_| VerifyError::BadSignature)
+        }
+        _ => {
+            let key = k256::ecdsa::VerifyingKey::from_sec1_bytes(&key)
+                .map_err(|_| VerifyError::MalformedPublicKey)?;
+            let signature = k256::ecdsa::Signature::from_der(&signature)
+                .map_err(|_| VerifyError::MalformedSignature)?;
+            key.verify_prehash(&sha512_half(message), &signature)
+                .map_err(|_| VerifyError::BadSignature)
+        }
+    }
+}
+
+/// Which of `account`'s keys `public_key` is, if either.
+fn key_used(
+    account: &str,
+    public_key: &str,
+    regular_key: &impl Fn(&str) -> Option<String>,
+) -> Result<KeyUsed, VerifyError> {
+    let key =
+        hex::decode(public_key).map_err(|_| VerifyError::MalformedPublicKey)?;
+    let derived = AccountId::from_public_key(&key).to_string();
+    if derived == account {
+        Ok(KeyUsed::Master)
+    } else if regular_key(account).is_some_and(|regular| regular == derived) {
+        Ok(KeyUsed::Regular)
+    } else {
+        Err(VerifyError::KeyNotAuthorized(account.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::{MultiSignable, Signable, Signer};
+    use crate::wallet::Wallet;
+
+    const SECP_SEED: &str = "sp5fghtJtpUorTwvof1NpDXAzNwf5";
+    const ED_SEED: &str = "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r";
+
+    fn payment(account: &str) -> Transaction {
+        serde_json::from_value(json!({
+            "TransactionType": "Payment",
+            "Account": account,
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": "1000000",
+            "Fee": "36",
+            "Sequence": 7,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_single_signed_master_and_regular_key() {
+        let master = Wallet::from_seed(SECP_SEED).unwrap();
+        let regular = Wallet::from_seed(ED_SEED).unwrap();
+        let tx = payment(master.address());
+
+        let signed = Transaction::from_blob(tx.sign_with(&master).unwrap());
+        let report = verify(&signed.unwrap(), |_| None);
+        assert_eq!(report.checks[0].outcome, Ok(KeyUsed::Master));
+        // With lsfDisableMaster set, only the regular key may sign.
+        let report = reject_disabled_master(report, |_| true);
+        assert_eq!(
+            report.checks[0].outcome,
+            Err(VerifyError::KeyNotAuthorized(master.address().to_string()))
+        );
+
+        let signed =
+            Transaction::from_blob(tx.sign_with(&regular).unwrap()).unwrap();
+        assert_eq!(
+            verify(&signed, |_| None).checks[0].outcome,
+            Err(VerifyError::KeyNotAuthorized(master.address().to_string()))
+        );
+        let report = verify(&signed, |_| Some(regular.address().to_string()));
+        assert_eq!(report.checks[0].outcome, Ok(KeyUsed::Regular));
+
+        let report = verify(&signed, |_| Some(regular.address().into()));
+        let report = reject_disabled_master(report, |_| true);
+        assert_eq!(report.checks[0].outcome, Ok(KeyUsed::Regular));
+
+        let mut tampered = signed;
+        tampered.sequence += 1;
+        let report = verify(&tampered, |_| Some(regular.address().into()));
+        assert_eq!(report.checks[0].outcome, Err(VerifyError::BadSignature));
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_multi_signed_report_per_signer() {
+        let (a, b) = (
+            Wallet::from_seed(SECP_SEED).unwrap(),
+            Wallet::from_seed(ED_SEED).unwrap(),
+        );
+        let mut tx = payment("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        let sig_a = tx.sign_as(&a).unwrap();
+        let mut sig_b: Signer = tx.sign_as(&b).unwrap().into();
+        tx.add_signatures([sig_a.clone().into(), sig_b.clone()]);
+
+        let report = verify(&tx, |_| None);
+        assert!(report.is_valid());
+        assert_eq!(report.checks.len(), 2);
+
+        // b's signature attributed to a different account.
+        sig_b.account = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".to_string();
+        tx.add_signatures([Signer::from(sig_a.clone()), sig_b, sig_a.into()]);
+        let report = verify(&tx, |_| None);
+        let outcomes: Vec<_> =
+            report.checks.iter().map(|c| c.outcome.clone()).collect();
+        assert!(outcomes.contains(&Err(VerifyError::BadSignature)));
+        assert!(outcomes.contains(&Err(VerifyError::DuplicateSigner(
+            a.address().to_string()
+        ))));
+        assert_eq!(report.failures().count(), 2);
+    }
+
+    #[test]
+    fn test_unsigned_and_malformed() {
+        let tx = payment("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        assert_eq!(
+            verify(&tx, |_| None).checks[0].outcome,
+            Err(VerifyError::Unsigned)
+        );
+        assert_eq!(
+            verify_signature("ED00", b"message", "00"),
+            Err(VerifyError::MalformedPublicKey)
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt;
+
+use ed25519_dalek::Signer as _;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::elliptic_curve::PrimeField;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::{ProjectivePoint, Scalar};
+use thiserror::Error;
+
+use crate::address::{AccountId, base58check_decode};
+use crate::types::codec::{self, CodecError, sha512_half};
+use crate::types::{
+    MultiSigningContext, Signer, SignerWrapper, SigningContext, Transaction,
+};
+
+const SECP256K1_SEED_PREFIX: &[u8] = &[0x21];
+const ED25519_SEED_PREFIX: &[u8] = &[0x01, 0xE1, 0x4B];
+const ED25519_KEY_PREFIX: u8 = 0xED;
+
+/// Signature algorithm of a [`Wallet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyType {
+    /// ECDSA over secp256k1. Family seeds start with `s` (but not `sEd`).
+    Secp256k1,
+    /// Ed25519. Family seeds start with `sEd`.
+    Ed25519,
+}
+
+/// Errors returned by [`Wallet`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum WalletError {
+    /// The family seed is not valid base58check or has an unknown prefix.
+    #[error("Invalid family seed")]
+    InvalidSeed,
+    /// The secp256k1 signer rejected the message.
+    #[error("Failed to sign: {0}")]
+    Signing(String),
+    /// The transaction could not be serialized.
+    #[error(transparent)]
+    Codec(#[from] CodecError),
+}
+
+#[derive(Clone)]
+enum PrivateKey {
+    Secp256k1(k256::ecdsa::SigningKey),
+    Ed25519(ed25519_dalek::SigningKey),
+}
+
+/// Keypair derived from a family seed, with its classic address.
+///
+/// Implements [`SigningContext`] and [`MultiSigningContext`], so it can be
+/// passed straight to [`SubmitRequestBuilder`] or
+/// [`sign_as`](crate::types::MultiSignable::sign_as). Derivation follows
+/// `ripple-keypairs`: the first account keypair (index 0) for secp256k1
+/// seeds, and SHA-512Half of the seed for Ed25519 seeds.
+///
+/// Requires the `wallet` cargo feature.
+///
+/// [`SubmitRequestBuilder`]: crate::types::builders::SubmitRequestBuilder
+///
+/// # Example
+/// ```rust
+/// use xrpl::wallet::{KeyType, Wallet};
+///
+/// let wallet = Wallet::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r")?;
+/// assert_eq!(wallet.key_type(), KeyType::Ed25519);
+/// assert_eq!(wallet.address(), "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD");
+/// # Ok::<(), xrpl::wallet::WalletError>(())
+/// ```
+#[derive(Clone)]
+pub struct Wallet {
+    private_key: PrivateKey,
+    public_key: String,
+    address: String,
+}
+
+impl Wallet {
+    /// Derives the wallet for a base58 family seed (`s…` or `sEd…`).
+    pub fn from_seed(seed: impl AsRef<str>) -> Result<Self, WalletError> {
+        let payload = base58check_decode(seed.as_ref())
+            .map_err(|_| WalletError::InvalidSeed)?;
+        let (key_type, entropy) =
+            if let Some(entropy) = payload.strip_prefix(ED25519_SEED_PREFIX) {
+                (KeyType::Ed25519, entropy)
+            } else if let Some(entropy) =
+                payload.strip_prefix(SECP256K1_SEED_PREFIX)
+            {
+                (KeyType::Secp256k1, entropy)
+            } else {
+                return Err(WalletError::InvalidSeed);
+            };
+        let entropy =
+            entropy.try_into().map_err(|_| WalletError::InvalidSeed)?;
+        Ok(Self::from_entropy(entropy, key_type))
+    }
+
+    /// Derives the wallet for 16 bytes of raw seed entropy.
+    pub fn from_entropy(entropy: [u8; 16], key_type: KeyType) -> Self {
+        let (private_key, public_key) = match key_type {
+            KeyType::Secp256k1 => derive_secp256k1(&entropy),
+            KeyType::Ed25519 => derive_ed25519(&entropy),
+        };
+        Self {
+            private_key,
+            address: AccountId::from_public_key(&public_key).to_string(),
+            public_key: hex::encode_upper(&public_key),
+        }
+    }
+
+    /// Classic `r…` address of the keypair.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Hex public key, as used in `SigningPubKey`. Ed25519 keys carry an
+    /// `ED` prefix.
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    /// Signature algorithm of the keypair.
+    pub fn key_type(&self) -> KeyType {
+        match self.private_key {
+            PrivateKey::Secp256k1(_) => KeyType::Secp256k1,
+            PrivateKey::Ed25519(_) => KeyType::Ed25519,
+        }
+    }
+
+    /// Signs `message` and returns the hex signature.
+    ///
+    /// secp256k1 signs the SHA-512Half of `message` and returns a canonical
+    /// (low-S) DER signature; Ed25519 signs `message` itself.
+    pub fn sign(&self, message: &[u8]) -> Result<String, WalletError> {
+        match &self.private_key {
+            PrivateKey::Secp256k1(key) => {
+                let signature: k256::ecdsa::Signature = key
+                    .sign_prehash(&sha512_half(message))
+                    .map_err(|e| WalletError::Signing(e.to_string()))?;
+                let signature = signature.normalize_s().unwrap_or(signature);
+                Ok(hex::encode_upper(signature.to_der()))
+            }
+            PrivateKey::Ed25519(key) => {
+                Ok(hex::encode_upper(key.sign(message).to_bytes()))
+            }
+        }
+    }
+}
+
+/// Only the address and public key are shown; the private key stays hidden.
+impl fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wallet")
+            .field("address", &self.address)
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// First valid secp256k1 scalar from SHA-512Half of `input || counter`.
+fn derive_scalar(input: &[u8]) -> Scalar {
+    (0u32..)
+        .find_map(|counter| {
+            let hash = sha512_half(&[input, &counter.to_be_bytes()].concat());
+            Option::<Scalar>::from(Scalar::from_repr(hash.into()))
+                .filter(|scalar| !bool::from(scalar.is_zero()))
+        })
+        .expect("a valid scalar is found within a few attempts")
+}
+
+fn compressed(scalar: &Scalar) -> Vec<u8> {
+    let point = (ProjectivePoint::GENERATOR * scalar).to_affine();
+    point.to_encoded_point(true).as_bytes().to_vec()
+}
+
+fn derive_secp256k1(entropy: &[u8; 16]) -> (PrivateKey, Vec<u8>) {
+    let root = derive_scalar(entropy);
+    const ACCOUNT_INDEX: u32 = 0;
+    let tweak_input = [compressed(&root), ACCOUNT_INDEX.to_be_bytes().to_vec()];
+    let private = root + derive_scalar(&tweak_input.concat());
+    let key = k256::ecdsa::SigningKey::from_bytes(&private.to_repr())
+        .expect("a non-zero scalar is a valid signing key");
+    (PrivateKey::Secp256k1(key), compressed(&private))
+}
+
+fn derive_ed25519(entropy: &[u8; 16]) -> (PrivateKey, Vec<u8>) {
+    let key = ed25519_dalek::SigningKey::from_bytes(&sha512_half(entropy));
+    let mut public_key = vec![ED25519_KEY_PREFIX];
+    public_key.extend_from_slice(key.verifying_key().as_bytes());
+    (PrivateKey::Ed25519(key), public_key)
+}
+
+fn to_json(tx: &Transaction) -> Result<serde_json::Value, WalletError> {
+    serde_json::to_value(tx).map_err(|e| CodecError::Json(e.to_string()).into())
+}
+
+impl SigningContext for Wallet {
+    type Error = WalletError;
+
+    fn sign_transaction(
+        &self,
+        tx: &Transaction,
+    ) -> Result<String, WalletError> {
+        let mut tx_json = to_json(tx)?;
+        tx_json["SigningPubKey"] = self.public_key.clone().into();
+        let signature = self.sign(&codec::signing_data(&tx_json)?)?;
+        tx_json["TxnSignature"] = signature.into();
+        Ok(hex::encode_upper(codec::encode(&tx_json)?))
+    }
+}
+
+impl MultiSigningContext for Wallet {
+    type Error = WalletError;
+
+    fn sign_as_signer(
+        &self,
+        tx: &Transaction,
+    ) -> Result<SignerWrapper, WalletError> {
+        let mut tx_json = to_json(tx)?;
+        tx_json["SigningPubKey"] = "".into();
+        let data = codec::multisigning_data(&tx_json, &self.address)?;
+        let signature = self.sign(&data)?;
+        Ok(Signer::new(&self.address, signature, &self.public_key).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixtures from the `ripple-keypairs` reference implementation.
+    const SECP_SEED: &str = "sp5fghtJtpUorTwvof1NpDXAzNwf5";
+    const ED_SEED: &str = "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r";
+    const MESSAGE: &[u8] = b"test message";
+
+    #[test]
+    fn test_secp256k1_derivation_and_signature() {
+        let wallet = Wallet::from_seed(SECP_SEED).unwrap();
+
+        assert_eq!(wallet.key_type(), KeyType::Secp256k1);
+        assert_eq!(
+            wallet.public_key(),
+            "030D58EB48B4420B1F7B9DF55087E0E29FEF0E8468F9A6825B01CA2C361042D435"
+        );
+        assert_eq!(wallet.address(), "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1");
+        assert_eq!(
+            wallet.sign(MESSAGE).unwrap(),
+            "30440220583A91C95E54E6A651C47BEC22744E0B101E2C4060E7B08F6341657DAD9BC3EE02207D1489C7395DB0188D3A56A977ECBA54B36FA9371B40319655B1B4429E33EF2D"
+        );
+    }
+
+    #[test]
+    fn test_ed25519_derivation_and_signature() {
+        let wallet = Wallet::from_seed(ED_SEED).unwrap();
+
+        assert_eq!(wallet.key_type(), KeyType::Ed25519);
+        assert_eq!(
+            wallet.public_key(),
+            "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63"
+        );
+        assert_eq!(wallet.address(), "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD");
+        assert_eq!(
+            wallet.sign(MESSAGE).unwrap(),
+            "CB199E1BFD4E3DAA105E4832EEDFA36413E1F44205E4EFB9E27E826044C21E3E2E848BBC8195E8959BADF887599B7310AD1B7047EF11B682E0D068F73749750E"
+        );
+    }
+
+    #[test]
+    fn test_sign_transaction_and_as_signer() {
+        let wallet = Wallet::from_seed(ED_SEED).unwrap();
+        let tx: Transaction = serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": wallet.address(),
+            "Destination": "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1",
+            "Amount": "1000000",
+            "Fee": "12",
+            "Sequence": 1,
+        }))
+        .unwrap();
+
+        let blob = wallet.sign_transaction(&tx).unwrap();
+        let signed = Transaction::from_blob(&blob).unwrap();
+        assert_eq!(
+            signed.signing_pub_key.as_deref(),
+            Some(wallet.public_key())
+        );
+        assert_eq!(signed.txn_signature.as_deref().map(str::len), Some(128));
+
+        let signer = wallet.sign_as_signer(&tx).unwrap().signer;
+        assert_eq!(signer.account, wallet.address());
+        assert_ne!(signer.txn_signature, signed.txn_signature.unwrap());
+    }
+
+    #[test]
+    fn test_invalid_seed() {
+        assert_eq!(
+            Wallet::from_seed("sp5fghtJtpUorTwvof1NpDXAzNwf6").unwrap_err(),
+            WalletError::InvalidSeed
+        );
+        assert!(
+            Wallet::from_seed("rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1").is_err()
+        );
+    }
+}
@@ -36,3 +36,196 @@ test_account_object_type!(paychannel, AccountObjectType::PayChannel);
 test_account_object_type!(state, AccountObjectType::RippleState);
 test_account_object_type!(signer_list, AccountObjectType::SignerList);
 test_account_object_type!(ticket, AccountObjectType::Ticket);
+test_account_object_type!(delegate, AccountObjectType::Delegate);
+test_account_object_type!(domain, AccountObjectType::PermissionedDomain);
+
+// Wire-format tests - no network required
+
+use serde_json::json;
+use xrpl::request::account_objects::AccountObjectsResponse;
+use xrpl::types::AccountObject;
+
+const OWNER: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+
+fn objects(objects: serde_json::Value) -> Vec<AccountObject> {
+    let resp: AccountObjectsResponse = serde_json::from_value(json!({
+        "account": OWNER,
+        "account_objects": objects,
+        "ledger_index": 5,
+        "validated": true
+    }))
+    .expect("response should deserialize");
+    resp.account_objects
+}
+
+#[test]
+fn test_unknown_entry_type_keeps_raw_json() {
+    let future = json!({
+        "LedgerEntryType": "FutureEntry",
+        "Account": OWNER,
+        "index": "AB".repeat(32)
+    });
+    let objects = objects(json!([
+        future,
+        { "LedgerEntryType": "Ticket", "Account": OWNER, "TicketSequence": 7, "Flags": 0 }
+    ]));
+
+    let AccountObject::Unknown { ledger_entry_type, value } = &objects[0]
+    else {
+        panic!("expected Unknown, got {:?}", objects[0]);
+    };
+    assert_eq!(ledger_entry_type, "FutureEntry");
+    assert_eq!(value, &future);
+    assert_eq!(objects[0].ledger_entry_type(), "FutureEntry");
+    assert!(matches!(objects[1], AccountObject::Ticket(_)));
+}
+
+#[test]
+fn test_known_entry_type_with_malformed_fields_errors() {
+    let resp: Result<AccountObjectsResponse, _> = serde_json::from_value(
+        json!({
+            "account": OWNER,
+            "account_objects": [{ "LedgerEntryType": "Ticket", "Account": OWNER }],
+            "ledger_index": 5
+        }),
+    );
+    assert!(resp.is_err());
+}
+
+#[test]
+fn test_typed_amm_directory_and_nftoken_page() {
+    let objects = objects(json!([
+        {
+            "LedgerEntryType": "AMM",
+            "Account": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+            "Asset": { "currency": "XRP" },
+            "Asset2": { "currency": "TST", "issuer": OWNER },
+            "AuctionSlot": {
+                "Account": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+                "AuthAccounts": [{ "AuthAccount": { "Account": OWNER } }],
+                "DiscountedFee": 0,
+                "Expiration": 721870180,
+                "Price": { "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2", "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S", "value": "0" }
+            },
+            "Flags": 0,
+            "LPTokenBalance": { "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2", "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S", "value": "71150.53584131501" },
+            "TradingFee": 600,
+            "VoteSlots": [{ "VoteEntry": { "Account": OWNER, "TradingFee": 600, "VoteWeight": 100000 } }]
+        },
+        {
+            "LedgerEntryType": "DirectoryNode",
+            "Flags": 0,
+            "Indexes": ["AD7EAE148287EF12D213A251015F86E6D4BD34B3C4A0A1ED9A17198373F908AD"],
+            "Owner": OWNER,
+            "RootIndex": "193C591BF62482468422313F9D3274B5927CA80B4DD3707E42015DD609E39C94",
+            "index": "193C591BF62482468422313F9D3274B5927CA80B4DD3707E42015DD609E39C94"
+        },
+        {
+            "LedgerEntryType": "NFTokenPage",
+            "Flags": 0,
+            "NFTokens": [
+                { "NFToken": { "NFTokenID": "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65", "URI": "697066733A2F2F" } },
+                { "NFToken": { "NFTokenID": "000B013A95F14B0044F78A264E41713C64B5F89242540EE2B4B2A35500000D66" } }
+            ],
+            "PreviousTxnID": "95C8761B22894E328646F7A70035E9FFBF5E1C8B5A5A1F8E1C0C1A0D1B2C3D4E",
+            "PreviousTxnLgrSeq": 42891441
+        }
+    ]));
+
+    let AccountObject::Amm(amm) = &objects[0] else {
+        panic!("expected AMM, got {:?}", objects[0]);
+    };
+    assert_eq!(amm.trading_fee, 600);
+    let slot = amm.auction_slot.as_ref().unwrap();
+    assert_eq!(
+        slot.auth_accounts.as_ref().unwrap()[0].auth_account.account,
+        OWNER
+    );
+
+    let AccountObject::DirectoryNode(dir) = &objects[1] else {
+        panic!("expected DirectoryNode, got {:?}", objects[1]);
+    };
+    assert_eq!(dir.owner.as_deref(), Some(OWNER));
+    assert_eq!(dir.indexes.len(), 1);
+    assert_eq!(dir.index_next, None);
+
+    let AccountObject::NFTokenPage(page) = &objects[2] else {
+        panic!("expected NFTokenPage, got {:?}", objects[2]);
+    };
+    assert_eq!(page.nftokens.len(), 2);
+    assert_eq!(page.nftokens[0].nftoken.uri.as_deref(), Some("697066733A2F2F"));
+    assert_eq!(page.nftokens[1].nftoken.uri, None);
+}
+
+#[test]
+fn test_typed_vault_domain_delegate_and_hashes() {
+    let objects = objects(json!([
+        {
+            "LedgerEntryType": "Vault",
+            "Account": "rKpNC9e1Jhf4Su4yJ58iC2Hq9WoGzFJmAJ",
+            "Asset": { "currency": "XRP" },
+            "AssetsAvailable": "1000",
+            "AssetsTotal": "1000",
+            "Flags": 0,
+            "Owner": OWNER,
+            "OwnerNode": "0",
+            "Sequence": 12,
+            "ShareMPTID": "0000000169F415C9F1AB6796AB9224CE635818AFD74F8175",
+            "WithdrawalPolicy": 1
+        },
+        {
+            "LedgerEntryType": "PermissionedDomain",
+            "AcceptedCredentials": [
+                { "Credential": { "CredentialType": "6D795F63726564656E7469616C", "Issuer": OWNER } }
+            ],
+            "Flags": 0,
+            "Owner": OWNER,
+            "OwnerNode": "0",
+            "Sequence": 390
+        },
+        {
+            "LedgerEntryType": "Delegate",
+            "Account": OWNER,
+            "Authorize": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Flags": 0,
+            "OwnerNode": "0",
+            "Permissions": [
+                { "Permission": { "PermissionValue": "Payment" } },
+                { "Permission": { "PermissionValue": "TrustlineAuthorize" } }
+            ]
+        },
+        {
+            "LedgerEntryType": "LedgerHashes",
+            "Flags": 0,
+            "Hashes": ["D638208ADBD04CBB10DE7B645D3AB4BA31489379411A3A347151702B6401AA78"],
+            "LastLedgerSequence": 38129,
+            "index": "B4979A36CDC7F3D3D5C31A4EAE2AC7D7209DDA877588B9AFC66799692AB0D66B"
+        }
+    ]));
+
+    let AccountObject::Vault(vault) = &objects[0] else {
+        panic!("expected Vault, got {:?}", objects[0]);
+    };
+    assert_eq!(vault.assets_total.as_deref(), Some("1000"));
+    assert_eq!(vault.loss_unrealized, None);
+    assert_eq!(vault.withdrawal_policy, 1);
+
+    let AccountObject::PermissionedDomain(domain) = &objects[1] else {
+        panic!("expected PermissionedDomain, got {:?}", objects[1]);
+    };
+    assert_eq!(domain.accepted_credentials[0].credential.issuer, OWNER);
+
+    let AccountObject::Delegate(delegate) = &objects[2] else {
+        panic!("expected Delegate, got {:?}", objects[2]);
+    };
+    assert_eq!(
+        delegate.permissions[1].permission.permission_value,
+        "TrustlineAuthorize"
+    );
+
+    let AccountObject::LedgerHashes(hashes) = &objects[3] else {
+        panic!("expected LedgerHashes, got {:?}", objects[3]);
+    };
+    assert_eq!(hashes.last_ledger_sequence, Some(38129));
+    assert_eq!(hashes.first_ledger_sequence, None);
+}
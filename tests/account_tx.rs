@@ -1,5 +1,6 @@
 mod common;
 
+use futures_util::StreamExt;
 use xrpl::Client;
 use xrpl::request::account_tx::AccountTxRequest;
 use xrpl::types::{HasTransactionMeta, Transaction, TransactionType};
@@ -45,3 +46,24 @@ async fn test_account_tx() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_account_tx_paginate() {
+    let client = Client::new(server_url());
+    let request = AccountTxRequest::new(sender_address()).with_limit(2);
+
+    let transactions: Vec<_> =
+        client.paginate(request).with_max_items(5).collect().await;
+
+    assert!(!transactions.is_empty());
+    assert!(transactions.len() <= 5);
+
+    let mut hashes: Vec<_> = transactions
+        .into_iter()
+        .map(|tx| tx.expect("Failed to fetch page").hash)
+        .collect();
+    let count = hashes.len();
+    hashes.sort();
+    hashes.dedup();
+    assert_eq!(hashes.len(), count, "pages must not repeat transactions");
+}
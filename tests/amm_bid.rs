@@ -16,13 +16,13 @@ fn test_amm_bid_builder_simple() -> Result<()> {
     let asset2 = Asset::token("USD", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")?;
 
     let amm_bid =
-        AMMBidBuilder::new("rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
+        AMMBidBuilder::new("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
             .build()?;
 
     let tx_json = serde_json::to_value(&amm_bid)?;
 
     assert_eq!(tx_json["TransactionType"], "AMMBid");
-    assert_eq!(tx_json["Account"], "rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
+    assert_eq!(tx_json["Account"], "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
 
     Ok(())
 }
@@ -35,7 +35,7 @@ fn test_amm_bid_builder_with_bids() -> Result<()> {
     let bid_max = Amount::xrp("20")?;
 
     let amm_bid =
-        AMMBidBuilder::new("rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
+        AMMBidBuilder::new("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
             .with_bid_min(bid_min.clone())
             .with_bid_max(bid_max.clone())
             .build()?;
@@ -43,7 +43,7 @@ fn test_amm_bid_builder_with_bids() -> Result<()> {
     let tx_json = serde_json::to_value(&amm_bid)?;
 
     assert_eq!(tx_json["TransactionType"], "AMMBid");
-    assert_eq!(tx_json["Account"], "rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
+    assert_eq!(tx_json["Account"], "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
     assert_eq!(tx_json["BidMin"], serde_json::to_value(bid_min)?);
     assert_eq!(tx_json["BidMax"], serde_json::to_value(bid_max)?);
 
@@ -58,14 +58,14 @@ fn test_amm_bid_builder_with_bid_range() -> Result<()> {
     let bid_max = Amount::xrp("20")?;
 
     let amm_bid =
-        AMMBidBuilder::new("rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
+        AMMBidBuilder::new("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
             .with_bid_range(bid_min.clone(), bid_max.clone())
             .build()?;
 
     let tx_json = serde_json::to_value(&amm_bid)?;
 
     assert_eq!(tx_json["TransactionType"], "AMMBid");
-    assert_eq!(tx_json["Account"], "rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
+    assert_eq!(tx_json["Account"], "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
     assert_eq!(tx_json["BidMin"], serde_json::to_value(bid_min)?);
     assert_eq!(tx_json["BidMax"], serde_json::to_value(bid_max)?);
 
@@ -77,19 +77,19 @@ fn test_amm_bid_builder_with_auth_accounts() -> Result<()> {
     let asset = Asset::xrp();
     let asset2 = Asset::token("USD", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")?;
     let accounts = [
-        "rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+        "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
         "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
     ];
 
     let amm_bid =
-        AMMBidBuilder::new("rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
+        AMMBidBuilder::new("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", asset, asset2)
             .with_auth_accounts(accounts)
             .build()?;
 
     let tx_json = serde_json::to_value(&amm_bid)?;
 
     assert_eq!(tx_json["TransactionType"], "AMMBid");
-    assert_eq!(tx_json["Account"], "rPT0Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
+    assert_eq!(tx_json["Account"], "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
     assert!(tx_json["AuthAccounts"].is_array());
     assert_eq!(tx_json["AuthAccounts"].as_array().unwrap().len(), 2);
 
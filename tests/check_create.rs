@@ -23,7 +23,7 @@ const EXPIRATION_OFFSET_24H: u32 = 86_400;
 ///
 /// SHA512Half(uint16_t(0x0043) || account_id || sequence_u32_be)
 fn check_id(account: &str, sequence: u32) -> String {
-    let account_id: xrpl_mithril::types::AccountId =
+    let account_id: xrpl::address::AccountId =
         account.parse().expect("invalid address");
     let mut data = Vec::with_capacity(26);
     data.extend_from_slice(&0x0043u16.to_be_bytes());
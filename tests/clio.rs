@@ -0,0 +1,127 @@
+use serde_json::json;
+use xrpl::request::XrplRequest;
+use xrpl::request::mpt_holders::{MptHoldersRequest, MptHoldersResponse};
+use xrpl::request::nft_history::{NftHistoryRequest, NftHistoryResponse};
+use xrpl::request::nft_info::{NftInfoRequest, NftInfoResponse};
+use xrpl::request::nfts_by_issuer::{NftsByIssuerRequest, NftsByIssuerResponse};
+
+const NFT_ID: &str =
+    "00080000B4F4AFC5FBCBD76873F18006173D2193467D3EE70000099B00000000";
+const ISSUER: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+const MPT_ID: &str = "0000012FFD9EE5DA93AC614B4DB94D7E0FCE415CA51BED47";
+
+// The test server runs rippled, which does not serve these commands, so
+// these tests only check the wire format against sample responses.
+
+fn nft_json() -> serde_json::Value {
+    json!({
+        "nft_id": NFT_ID,
+        "ledger_index": 270,
+        "owner": "rG9gdNygQ6npA9JvDFWBoeXbiUcTYJnEnk",
+        "is_burned": true,
+        "flags": 8,
+        "transfer_fee": 0,
+        "issuer": ISSUER,
+        "nft_taxon": 0,
+        "nft_serial": 0,
+        "uri": ""
+    })
+}
+
+#[test]
+fn test_nft_info_round_trip() {
+    let json = NftInfoRequest::new(NFT_ID)
+        .with_ledger_index("validated")
+        .to_value()
+        .expect("Failed to serialize request");
+    assert_eq!(json["command"], "nft_info");
+    assert_eq!(json["nft_id"], NFT_ID);
+    assert_eq!(json["ledger_index"], "validated");
+
+    let mut result = nft_json();
+    result["validated"] = json!(true);
+    let resp: NftInfoResponse = serde_json::from_value(result).unwrap();
+    assert!(resp.nft.is_burned);
+    assert_eq!(resp.nft.issuer, ISSUER);
+    assert_eq!(resp.nft.flags, 8);
+    assert_eq!(resp.validated, Some(true));
+}
+
+#[test]
+fn test_nft_history_round_trip() {
+    let json = NftHistoryRequest::new(NFT_ID)
+        .with_forward(true)
+        .with_limit(10)
+        .to_value()
+        .expect("Failed to serialize request");
+    assert_eq!(json["command"], "nft_history");
+    assert_eq!(json["forward"], true);
+    assert!(json["marker"].is_null());
+
+    let resp: NftHistoryResponse = serde_json::from_value(json!({
+        "nft_id": NFT_ID,
+        "ledger_index_min": 32570,
+        "ledger_index_max": 91000000,
+        "limit": 10,
+        "marker": { "ledger": 88000000, "seq": 12 },
+        "transactions": [{
+            "hash": "E5E66AA4B31B8F1A1F8C2B5D1B6DBE3B1C0B1A6D7E7B6C7D9A1B0C4F1E7A6D3C",
+            "ledger_index": 88000000,
+            "tx_json": { "TransactionType": "NFTokenMint", "Flags": 8 },
+            "validated": true
+        }],
+        "validated": true
+    }))
+    .unwrap();
+    assert_eq!(resp.transactions.len(), 1);
+    assert_eq!(resp.transactions[0].flags(), 8);
+    assert!(resp.marker.is_some());
+}
+
+#[test]
+fn test_nfts_by_issuer_round_trip() {
+    let json = NftsByIssuerRequest::new(ISSUER)
+        .with_nft_taxon(0)
+        .to_value()
+        .expect("Failed to serialize request");
+    assert_eq!(json["command"], "nfts_by_issuer");
+    assert_eq!(json["issuer"], ISSUER);
+    assert_eq!(json["nft_taxon"], 0);
+
+    let resp: NftsByIssuerResponse = serde_json::from_value(json!({
+        "issuer": ISSUER,
+        "nfts": [nft_json()],
+        "ledger_index": 270,
+        "validated": true
+    }))
+    .unwrap();
+    assert_eq!(resp.nfts[0].nft_id, NFT_ID);
+    assert_eq!(resp.nft_taxon, None);
+}
+
+#[test]
+fn test_mpt_holders_round_trip() {
+    let json = MptHoldersRequest::new(MPT_ID)
+        .with_limit(1)
+        .to_value()
+        .expect("Failed to serialize request");
+    assert_eq!(json["command"], "mpt_holders");
+    assert_eq!(json["mpt_issuance_id"], MPT_ID);
+
+    let resp: MptHoldersResponse = serde_json::from_value(json!({
+        "mpt_issuance_id": MPT_ID,
+        "limit": 1,
+        "ledger_index": 1266,
+        "mptokens": [{
+            "account": "rLqVfMYpgQMGnNTgdkDUoMXAnYyGYBTcDr",
+            "flags": 0,
+            "mpt_amount": "20",
+            "mptoken_index": "36D91DEE5EFE4A93119A8B84C944A528F2B444329F3846E49FE921040DE17E65"
+        }],
+        "marker": "36D91DEE5EFE4A93119A8B84C944A528F2B444329F3846E49FE921040DE17E65",
+        "validated": true
+    }))
+    .unwrap();
+    assert_eq!(resp.mptokens[0].mpt_amount, "20");
+    assert_eq!(resp.mptokens[0].locked_amount, None);
+}
@@ -2,11 +2,8 @@
 
 use anyhow::Context;
 use ripple_keypairs::Seed;
-use xrpl_mithril::codec::serializer;
-use xrpl_mithril::codec::signing::{
-    HASH_PREFIX_TRANSACTION_SIGN, multi_signing_data,
-};
 use xrpl::request::submit::SubmitResponse;
+use xrpl::types::codec;
 use xrpl::types::{MultiSigningContext, Signer, SignerWrapper, SigningContext};
 
 pub use xrpl::time::ripple_now;
@@ -83,28 +80,11 @@ impl SigningContext for Wallet {
             .context("Failed to serialize transaction to JSON")?;
         tx_json["SigningPubKey"] = self.public_key.to_string().into();
 
-        let buf = {
-            let map = tx_json
-                .as_object()
-                .context("Transaction JSON is not an object")?;
-            let mut buf = Vec::new();
-            serializer::serialize_json_object(map, &mut buf, true)?;
-            buf
-        };
-
-        let mut signing_bytes = Vec::with_capacity(4 + buf.len());
-        signing_bytes.extend_from_slice(&HASH_PREFIX_TRANSACTION_SIGN);
-        signing_bytes.extend_from_slice(&buf);
-
+        let signing_bytes = codec::signing_data(&tx_json)?;
         let signature = self.private_key.sign(&signing_bytes);
         tx_json["TxnSignature"] = signature.to_string().into();
 
-        let map =
-            tx_json.as_object().context("Transaction JSON is not an object")?;
-        let mut buf = Vec::new();
-        serializer::serialize_json_object(map, &mut buf, false)?;
-
-        Ok(hex::encode(buf).to_uppercase())
+        Ok(hex::encode_upper(codec::encode(&tx_json)?))
     }
 }
 
@@ -120,16 +100,7 @@ impl MultiSigningContext for Wallet {
         tx_json["SigningPubKey"] = "".into();
 
         let address = self.public_key.derive_address();
-        let account_id: xrpl_mithril::types::AccountId =
-            address.parse().context("Failed to parse account address")?;
-
-        let signing_bytes = {
-            let map = tx_json
-                .as_object()
-                .context("Transaction JSON is not an object")?;
-            multi_signing_data(map, account_id.as_bytes())?
-        };
-
+        let signing_bytes = codec::multisigning_data(&tx_json, &address)?;
         let signature = self.private_key.sign(&signing_bytes);
 
         Ok(SignerWrapper {
@@ -0,0 +1,70 @@
+mod common;
+
+use serde_json::json;
+use xrpl::Client;
+use xrpl::request::XrplRequest;
+use xrpl::request::gateway_balances::{
+    GatewayBalance, GatewayBalancesRequest, GatewayBalancesResponse,
+};
+use common::*;
+
+#[tokio::test]
+async fn test_gateway_balances() {
+    let client = Client::new(server_url());
+    let request = GatewayBalancesRequest::new(sender_address())
+        .with_hotwallet(receiver_address())
+        .with_ledger_index("validated");
+
+    let result = client
+        .request(&request)
+        .await
+        .expect("Failed to request gateway_balances")
+        .result()
+        .expect("Failed to get gateway_balances result");
+
+    assert_eq!(result.account, sender_address());
+    for balances in result.balances.values() {
+        assert!(balances.iter().all(|b| !b.currency.is_empty()));
+    }
+}
+
+#[test]
+fn test_gateway_balances_wire_format() {
+    let json =
+        GatewayBalancesRequest::new("rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q")
+            .with_hotwallet("rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ")
+            .with_hotwallet("ra7JkEzrgeKHdzKgo4EUUVBnxggY4z37kt")
+            .with_strict(true)
+            .to_value()
+            .expect("Failed to serialize request");
+    assert_eq!(json["command"], "gateway_balances");
+    assert_eq!(json["hotwallet"].as_array().unwrap().len(), 2);
+
+    let resp: GatewayBalancesResponse = serde_json::from_value(json!({
+        "account": "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q",
+        "assets": {
+            "r9F6wk8HkXrgYWoJ7fsv4VrUBVoqDVtzkH": [
+                { "currency": "BTC", "value": "5444166510000000e-26" }
+            ]
+        },
+        "balances": {
+            "rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ": [
+                { "currency": "EUR", "value": "29826.1965999999" }
+            ]
+        },
+        "ledger_hash": "61DDBF304AF6E8101576BF161D447CA8E4F0170DDFBEAFFD993DC9383D443388",
+        "ledger_index": 14483195,
+        "obligations": { "EUR": "5599.716599999999", "USD": "12345.9" },
+        "validated": true
+    }))
+    .unwrap();
+    assert_eq!(resp.obligations["USD"], "12345.9");
+    assert_eq!(
+        resp.balances["rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ"],
+        [GatewayBalance {
+            currency: "EUR".to_string(),
+            value: "29826.1965999999".to_string()
+        }]
+    );
+    assert!(resp.frozen_balances.is_empty());
+}
@@ -86,3 +86,29 @@ async fn test_ledger_data_first_page() {
     // There are millions of entries so there will always be a next page marker
     assert!(result.marker.is_some());
 }
+
+#[test]
+fn test_ledger_data_state_is_typed() {
+    use xrpl::request::ledger_data::LedgerDataResponse;
+    use xrpl::types::AccountObject;
+
+    let resp: LedgerDataResponse = serde_json::from_value(serde_json::json!({
+        "ledger_hash": "AB".repeat(32),
+        "ledger_index": 5,
+        "state": [
+            {
+                "LedgerEntryType": "LedgerHashes",
+                "Flags": 0,
+                "Hashes": ["CD".repeat(32)],
+                "LastLedgerSequence": 4,
+                "index": "B4979A36CDC7F3D3D5C31A4EAE2AC7D7209DDA877588B9AFC66799692AB0D66B"
+            },
+            { "data": "1100612200000000", "index": "EF".repeat(32) }
+        ]
+    }))
+    .expect("response should deserialize");
+
+    assert!(matches!(resp.state[0], AccountObject::LedgerHashes(_)));
+    // Binary entries carry no LedgerEntryType.
+    assert_eq!(resp.state[1].ledger_entry_type(), "");
+}
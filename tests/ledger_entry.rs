@@ -3,7 +3,7 @@ mod common;
 use xrpl::Client;
 use xrpl::request::ledger_entry::LedgerEntryRequest;
 use xrpl::request::XrplRequest;
-use xrpl::types::Asset;
+use xrpl::types::{AccountObject, Asset};
 use common::*;
 
 #[tokio::test]
@@ -20,8 +20,11 @@ async fn test_ledger_entry_account_root() {
 
     assert_eq!(result.index.len(), 64);
     let node = result.node.expect("node should be present");
-    assert_eq!(node["LedgerEntryType"], "AccountRoot");
-    assert_eq!(node["Account"], sender_address());
+    assert_eq!(node.ledger_entry_type(), "AccountRoot");
+    let AccountObject::AccountRoot(root) = node else {
+        panic!("expected an AccountRoot, got {node:?}");
+    };
+    assert_eq!(root.account, sender_address());
 }
 
 #[tokio::test]
@@ -52,7 +55,7 @@ async fn test_ledger_entry_by_index() {
 
     assert_eq!(result.index, index);
     let node = result.node.expect("node should be present");
-    assert_eq!(node["LedgerEntryType"], "AccountRoot");
+    assert_eq!(node.ledger_entry_type(), "AccountRoot");
 }
 
 // Serialization tests - no network required
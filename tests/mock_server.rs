@@ -0,0 +1,270 @@
+//! Offline tests against the in-process mock server.
+//!
+//! Run with `cargo test --features testing --test mock_server`.
+#![cfg(feature = "testing")]
+
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use serde_json::{Value, json};
+use xrpl::request::account_lines::AccountLinesRequest;
+use xrpl::request::ledger_current::LedgerCurrentRequest;
+use xrpl::subscriptions::LedgerSubscription;
+use xrpl::testing::{MockReply, MockServer};
+use xrpl::util::{Delivery, can_deliver_to};
+use xrpl::{Client, ClientConfig, XrplError};
+
+const ACCOUNT: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+
+fn fast_config() -> ClientConfig {
+    ClientConfig::new()
+        .with_request_timeout(Duration::from_millis(300))
+        .with_initial_backoff(Duration::from_millis(10))
+        .with_max_backoff(Duration::from_millis(50))
+}
+
+/// Ledger fields shared by the `subscribe` reply and `ledgerClosed` pushes.
+fn ledger(ledger_index: i64) -> Value {
+    json!({
+        "fee_base": 10,
+        "ledger_hash": "AB".repeat(32),
+        "ledger_index": ledger_index,
+        "ledger_time": 800_000_000,
+        "reserve_base": 1_000_000,
+        "reserve_inc": 200_000,
+        "txn_count": 0,
+    })
+}
+
+fn ledger_closed(ledger_index: i64) -> Value {
+    let mut msg = ledger(ledger_index);
+    msg["type"] = "ledgerClosed".into();
+    msg
+}
+
+fn lines_page(currency: &str, marker: Option<&str>) -> MockReply {
+    MockReply::result(json!({
+        "account": ACCOUNT,
+        "ledger_index": 1000,
+        "marker": marker,
+        "lines": [{
+            "account": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "balance": "10",
+            "currency": currency,
+            "limit": "100",
+            "limit_peer": "0",
+            "quality_in": 0,
+            "quality_out": 0,
+        }],
+    }))
+}
+
+#[tokio::test]
+async fn test_mock_request_round_trip() {
+    let server = MockServer::start().await.unwrap();
+    server.on(
+        "ledger_current",
+        MockReply::result(json!({ "ledger_current_index": 7 })),
+    );
+
+    let client = Client::new(server.url());
+    let resp = client.request(&LedgerCurrentRequest).await.unwrap();
+
+    assert_eq!(resp.result().unwrap().ledger_current_index, 7);
+    assert_eq!(server.requests_for("ledger_current")[0]["api_version"], 2);
+}
+
+#[tokio::test]
+async fn test_mock_api_error() {
+    let server = MockServer::start().await.unwrap();
+    server.once(
+        "ledger_current",
+        MockReply::error("noNetwork", 17, "Not synced to the network."),
+    );
+
+    let client = Client::new(server.url());
+    let resp = client.request(&LedgerCurrentRequest).await.unwrap();
+
+    match resp.result() {
+        Err(XrplError::ApiError { error, .. }) => {
+            assert_eq!(error, "noNetwork")
+        }
+        other => panic!("expected ApiError, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_mock_silent_reply_times_out() {
+    let server = MockServer::start().await.unwrap();
+    server.on("ledger_current", MockReply::silent());
+
+    let client = Client::with_config(server.url(), fast_config());
+    let err = client.request(&LedgerCurrentRequest).await.unwrap_err();
+
+    assert!(matches!(err, XrplError::Timeout(300)), "got {err:?}");
+}
+
+/// A delayed reply does not hold up a later request on the same connection.
+#[tokio::test]
+async fn test_mock_delayed_reply_is_multiplexed() {
+    let server = MockServer::start().await.unwrap();
+    let index = |i: u32| json!({ "ledger_current_index": i });
+    server.once(
+        "ledger_current",
+        MockReply::result(index(1)).with_delay(Duration::from_millis(200)),
+    );
+    server.once("ledger_current", MockReply::result(index(2)));
+
+    let client = Client::new(server.url());
+    let slow = client.request(&LedgerCurrentRequest);
+    let fast = async {
+        server.wait_for_requests("ledger_current", 1).await;
+        client.request(&LedgerCurrentRequest).await
+    };
+    let (slow, fast) = tokio::join!(slow, fast);
+
+    assert_eq!(slow.unwrap().result().unwrap().ledger_current_index, 1);
+    assert_eq!(fast.unwrap().result().unwrap().ledger_current_index, 2);
+}
+
+#[tokio::test]
+async fn test_mock_subscription_push() {
+    let server = MockServer::start().await.unwrap();
+    server.on("subscribe", MockReply::result(ledger(99)));
+    let client = Client::new(server.url());
+
+    let mut session = client.subscription().await.unwrap();
+    let (_, mut stream) =
+        session.subscribe(&LedgerSubscription::new()).await.unwrap();
+    server.push(ledger_closed(100));
+
+    assert_eq!(stream.recv().await.unwrap().ledger_index, 100);
+}
+
+/// After a dropped connection the client reconnects and replays its
+/// subscriptions, and pushes keep arriving on the same stream.
+#[tokio::test]
+async fn test_mock_reconnect_replays_subscription() {
+    let server = MockServer::start().await.unwrap();
+    server.on("subscribe", MockReply::result(ledger(99)));
+    let client = Client::with_config(server.url(), fast_config());
+
+    let mut session = client.subscription().await.unwrap();
+    let (_, mut stream) =
+        session.subscribe(&LedgerSubscription::new()).await.unwrap();
+
+    server.disconnect();
+    server.wait_for_requests("subscribe", 2).await;
+    server.push(ledger_closed(101));
+
+    assert_eq!(stream.recv().await.unwrap().ledger_index, 101);
+    let replayed = server.requests_for("subscribe");
+    assert_eq!(replayed[0]["streams"], replayed[1]["streams"]);
+    // A single endpoint has no health checks; reconnecting restores it.
+    assert!(client.endpoint_health()[0].healthy);
+}
+
+/// Pages are followed by marker and pinned to the first page's ledger.
+#[tokio::test]
+async fn test_mock_paginate_follows_marker() {
+    let server = MockServer::start().await.unwrap();
+    server.once("account_lines", lines_page("USD", Some("m1")));
+    server.once("account_lines", lines_page("EUR", Some("m2")));
+    server.once("account_lines", lines_page("JPY", None));
+
+    let client = Client::new(server.url());
+    let lines: Vec<_> =
+        client.paginate(AccountLinesRequest::new(ACCOUNT)).collect().await;
+
+    let currencies: Vec<_> =
+        lines.into_iter().map(|line| line.unwrap().currency).collect();
+    assert_eq!(currencies, ["USD", "EUR", "JPY"]);
+
+    let requests = server.requests_for("account_lines");
+    assert_eq!(requests[0].get("marker"), None);
+    assert_eq!(requests[1]["marker"], "m1");
+    assert_eq!(requests[1]["ledger_index"], 1000);
+    assert_eq!(requests[2]["marker"], "m2");
+}
+
+fn account_root(flags: u32) -> MockReply {
+    MockReply::result(json!({
+        "account_data": {
+            "Account": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Balance": "100000000",
+            "Flags": flags,
+            "LedgerEntryType": "AccountRoot",
+            "OwnerCount": 0,
+            "PreviousTxnID": "AB".repeat(32),
+            "PreviousTxnLgrSeq": 1,
+            "Sequence": 1,
+            "index": "CD".repeat(32),
+        },
+        "ledger_index": 1000,
+        "validated": true,
+    }))
+}
+
+/// Destination settings are checked before `deposit_authorized` is asked.
+#[tokio::test]
+async fn test_mock_can_deliver_to() {
+    const DESTINATION: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+    const REQUIRE_DEST: u32 = 0x0002_0000;
+    const DEPOSIT_AUTH: u32 = 0x0100_0000;
+    let server = MockServer::start().await.unwrap();
+    let client = Client::new(server.url());
+
+    server.once(
+        "account_info",
+        MockReply::error("actNotFound", 19, "Account not found."),
+    );
+    let delivery = can_deliver_to(&client, ACCOUNT, DESTINATION, &[]).await;
+    assert_eq!(delivery.unwrap(), Delivery::Unfunded);
+
+    server.once("account_info", account_root(REQUIRE_DEST));
+    let delivery = can_deliver_to(&client, ACCOUNT, DESTINATION, &[]).await;
+    assert_eq!(delivery.unwrap(), Delivery::DestinationTagRequired);
+
+    let tagged =
+        xrpl::address::classic_to_x_address(DESTINATION, Some(7), false)
+            .unwrap();
+    server.on(
+        "server_state",
+        MockReply::result(json!({ "state": { "network_id": 0 } })),
+    );
+    server.once("account_info", account_root(REQUIRE_DEST));
+    let delivery = can_deliver_to(&client, ACCOUNT, &tagged, &[]).await;
+    assert_eq!(delivery.unwrap(), Delivery::Allowed);
+    assert!(server.requests_for("deposit_authorized").is_empty());
+
+    let testnet =
+        xrpl::address::classic_to_x_address(DESTINATION, Some(7), true)
+            .unwrap();
+    let delivery = can_deliver_to(&client, ACCOUNT, &testnet, &[]).await;
+    assert_eq!(delivery.unwrap(), Delivery::WrongNetwork);
+
+    server.on("account_info", account_root(DEPOSIT_AUTH));
+    server.once(
+        "deposit_authorized",
+        MockReply::result(json!({
+            "deposit_authorized": false,
+            "source_account": ACCOUNT,
+            "destination_account": DESTINATION,
+        })),
+    );
+    let delivery = can_deliver_to(&client, ACCOUNT, DESTINATION, &[]).await;
+    assert_eq!(delivery.unwrap(), Delivery::NotAuthorized);
+
+    let credential = "EF".repeat(32);
+    server.once(
+        "deposit_authorized",
+        MockReply::error("badCredentials", 69, "Credentials aren't accepted."),
+    );
+    let delivery =
+        can_deliver_to(&client, ACCOUNT, DESTINATION, &[&credential]).await;
+    assert_eq!(delivery.unwrap(), Delivery::BadCredentials);
+
+    let requests = server.requests_for("deposit_authorized");
+    assert_eq!(requests[1]["credentials"][0], credential.as_str());
+    assert_eq!(requests[1]["ledger_index"], "validated");
+}
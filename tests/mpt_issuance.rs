@@ -1,7 +1,7 @@
 mod common;
 
 use std::time::Duration;
-use xrpl_mithril::types::AccountId;
+use xrpl::address::AccountId;
 use ripple_keypairs::Seed;
 use serial_test::serial;
 use tokio::time::timeout;
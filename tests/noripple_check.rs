@@ -0,0 +1,91 @@
+mod common;
+
+use serde_json::json;
+use xrpl::Client;
+use xrpl::request::XrplRequest;
+use xrpl::request::noripple_check::{
+    NoRippleCheckRequest, NoRippleCheckResponse, NoRippleRole,
+};
+use xrpl::types::builders::{AccountSetBuilder, TrustSetBuilder};
+use xrpl::types::transactions::trust_set::TrustSetFlags;
+use common::*;
+
+#[tokio::test]
+async fn test_noripple_check() {
+    let client = Client::new(server_url());
+    let request =
+        NoRippleCheckRequest::new(sender_address(), NoRippleRole::User)
+            .with_transactions(true)
+            .with_ledger_index("current");
+
+    let result = client
+        .request(&request)
+        .await
+        .expect("Failed to request noripple_check")
+        .result()
+        .expect("Failed to get noripple_check result");
+
+    // One suggested fix per trust line problem, at most one AccountSet more.
+    assert!(result.transactions.len() <= result.problems.len());
+    for tx in &result.transactions {
+        assert_eq!(tx.account, sender_address());
+    }
+}
+
+#[test]
+fn test_noripple_check_suggestions_become_builders() {
+    let json = NoRippleCheckRequest::new(
+        "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+        NoRippleRole::Gateway,
+    )
+    .with_transactions(true)
+    .to_value()
+    .expect("Failed to serialize request");
+    assert_eq!(json["command"], "noripple_check");
+    assert_eq!(json["role"], "gateway");
+    assert_eq!(json["transactions"], true);
+
+    let resp: NoRippleCheckResponse = serde_json::from_value(json!({
+        "ledger_current_index": 14342939,
+        "problems": [
+            "You should immediately set your default ripple flag",
+            "You should clear the no ripple flag on your USD line to rrrrrrrrrrrrrrrrrrrrBZbvji"
+        ],
+        "transactions": [
+            {
+                "Account": "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+                "Fee": 10,
+                "Sequence": 1406,
+                "SetFlag": 8,
+                "TransactionType": "AccountSet"
+            },
+            {
+                "Account": "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+                "Fee": 10,
+                "Flags": 262144,
+                "LimitAmount": {
+                    "currency": "USD",
+                    "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                    "value": "5"
+                },
+                "Sequence": 1407,
+                "TransactionType": "TrustSet"
+            }
+        ],
+        "validated": false
+    }))
+    .unwrap();
+    assert_eq!(resp.transactions[0].fee, "10");
+
+    let mut suggestions = resp.transactions.into_iter();
+    let account_set = suggestions.next().unwrap();
+    assert!(TrustSetBuilder::from_transaction(account_set.clone()).is_none());
+    assert!(AccountSetBuilder::from_transaction(account_set).is_some());
+
+    let tx = TrustSetBuilder::from_transaction(suggestions.next().unwrap())
+        .expect("TrustSet suggestion")
+        .build()
+        .expect("Failed to build TrustSet");
+    assert_eq!(tx.flags, Some(TrustSetFlags::CLEAR_NO_RIPPLE.0));
+    assert_eq!(tx.sequence, 0);
+}
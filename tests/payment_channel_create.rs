@@ -29,9 +29,9 @@ const TEARDOWN_WAIT_SECS: u64 = 17;
 ///
 /// SHA512Half(uint16_t(0x0078) || src_account_id || dst_account_id || sequence_u32_be)
 fn channel_id(src: &str, dst: &str, sequence: u32) -> String {
-    let src_id: xrpl_mithril::types::AccountId =
+    let src_id: xrpl::address::AccountId =
         src.parse().expect("invalid src address");
-    let dst_id: xrpl_mithril::types::AccountId =
+    let dst_id: xrpl::address::AccountId =
         dst.parse().expect("invalid dst address");
     let mut data = Vec::with_capacity(46);
     data.extend_from_slice(&0x0078u16.to_be_bytes());
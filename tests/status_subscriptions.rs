@@ -0,0 +1,47 @@
+mod common;
+
+use serial_test::serial;
+use tokio::time::{timeout, Duration};
+use xrpl::subscriptions::{ConsensusSubscription, ValidationsSubscription};
+use xrpl::Client;
+use common::*;
+
+#[serial]
+#[tokio::test]
+async fn test_validations_subscription() {
+    let client = Client::new(server_url());
+    let mut conn = client
+        .subscription()
+        .await
+        .expect("Failed to open subscription connection");
+    let (_resp, mut stream) = conn
+        .subscribe(&ValidationsSubscription::new())
+        .await
+        .expect("Validations subscription failed");
+
+    let msg = timeout(Duration::from_secs(30), stream.recv())
+        .await
+        .expect("Timed out waiting for a validation")
+        .expect("Validations stream closed unexpectedly");
+    assert!(msg.validation_public_key.starts_with('n'));
+    assert_eq!(msg.ledger_hash.len(), 64);
+}
+
+#[serial]
+#[tokio::test]
+async fn test_consensus_subscription() {
+    let client = Client::new(server_url());
+    let mut conn = client
+        .subscription()
+        .await
+        .expect("Failed to open subscription connection");
+    let (_resp, mut stream) = conn
+        .subscribe(&ConsensusSubscription::new())
+        .await
+        .expect("Consensus subscription failed");
+
+    timeout(Duration::from_secs(30), stream.recv())
+        .await
+        .expect("Timed out waiting for a consensus phase")
+        .expect("Consensus stream closed unexpectedly");
+}
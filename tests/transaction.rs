@@ -175,3 +175,32 @@ async fn test_transaction_with_ticket() {
         }
     }
 }
+
+#[serial]
+#[tokio::test]
+async fn test_submit_and_wait() {
+    let wallet = sender_wallet();
+    let client = Client::new(server_url());
+
+    let payment = PaymentBuilder::new(
+        wallet.public_key.derive_address(),
+        receiver_address(),
+        xrp!(PAYMENT_XRP),
+    )
+    .fill(&client)
+    .await
+    .expect("fill failed")
+    .build()
+    .expect("Payment build failed");
+
+    let outcome = client
+        .submit_and_wait(&payment, &wallet)
+        .await
+        .expect("submit_and_wait failed");
+
+    assert!(outcome.is_success(), "unexpected outcome: {outcome:?}");
+    assert_eq!(
+        outcome.meta().map(|m| m.transaction_result.as_str()),
+        Some("tesSUCCESS")
+    );
+}